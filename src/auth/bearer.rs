@@ -4,6 +4,13 @@ use crate::auth::AuthError;
 
 const BEARER_PREFIX: &str = "Bearer ";
 
+/// Validated Bearer token of the current request
+///
+/// Inserted into request extensions by the bearer auth middleware so handlers
+/// can call the Miro API on behalf of the caller.
+#[derive(Clone)]
+pub struct BearerToken(pub String);
+
 /// Extract Bearer token from Authorization header
 ///
 /// Parses the standard `Authorization: Bearer <token>` header format.
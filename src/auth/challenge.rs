@@ -0,0 +1,172 @@
+use axum::http::StatusCode;
+
+use crate::auth::AuthError;
+
+/// Path of the Protected Resource Metadata document (RFC 9728)
+pub const RESOURCE_METADATA_PATH: &str = "/.well-known/oauth-protected-resource";
+
+/// `WWW-Authenticate: Bearer` challenge per RFC 6750 §3 and RFC 9728 §5.1
+///
+/// The `resource_metadata` parameter lets OAuth clients (like Claude.ai)
+/// discover the authorization server after a 401.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BearerChallenge {
+    /// Absolute URL of our Protected Resource Metadata document
+    pub resource_metadata: String,
+    /// RFC 6750 error code (absent when the request carried no credentials)
+    pub error: Option<&'static str>,
+    /// Human-readable explanation of the error
+    pub error_description: Option<String>,
+    /// Space-separated scopes needed (only for `insufficient_scope`)
+    pub scope: Option<String>,
+}
+
+impl BearerChallenge {
+    /// Build the challenge matching an authentication failure
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_url` - Our MCP server URL (metadata path is appended)
+    /// * `error` - Why the request was rejected
+    pub fn from_auth_error(resource_url: &str, error: &AuthError) -> Self {
+        let resource_metadata = format!(
+            "{}{}",
+            resource_url.trim_end_matches('/'),
+            RESOURCE_METADATA_PATH
+        );
+
+        let (code, description, scope) = match error {
+            // RFC 6750 §3.1: no error code when the request lacks credentials
+            AuthError::NoToken => (None, None, None),
+            AuthError::InvalidTokenFormat => (
+                Some("invalid_token"),
+                Some("Malformed Bearer token".to_string()),
+                None,
+            ),
+            AuthError::TokenExpired => (
+                Some("invalid_token"),
+                Some("The access token expired".to_string()),
+                None,
+            ),
+            AuthError::TokenInvalid | AuthError::JsonError(_) => (
+                Some("invalid_token"),
+                Some("The access token is invalid".to_string()),
+                None,
+            ),
+            AuthError::TokenValidationFailed(reason) => {
+                (Some("invalid_token"), Some(reason.clone()), None)
+            }
+            AuthError::InsufficientScope(required) => (
+                Some("insufficient_scope"),
+                Some(format!("Token lacks required scope: {}", required)),
+                Some(required.clone()),
+            ),
+        };
+
+        Self {
+            resource_metadata,
+            error: code,
+            error_description: description,
+            scope,
+        }
+    }
+
+    /// HTTP status for this challenge (403 for insufficient scope, else 401)
+    pub fn status_code(&self) -> StatusCode {
+        match self.error {
+            Some("insufficient_scope") => StatusCode::FORBIDDEN,
+            _ => StatusCode::UNAUTHORIZED,
+        }
+    }
+
+    /// Render as a `WWW-Authenticate` header value
+    pub fn to_header_value(&self) -> String {
+        let mut params = vec![format!(
+            "resource_metadata=\"{}\"",
+            escape(&self.resource_metadata)
+        )];
+        if let Some(error) = self.error {
+            params.push(format!("error=\"{}\"", error));
+        }
+        if let Some(description) = &self.error_description {
+            params.push(format!("error_description=\"{}\"", escape(description)));
+        }
+        if let Some(scope) = &self.scope {
+            params.push(format!("scope=\"{}\"", escape(scope)));
+        }
+        format!("Bearer {}", params.join(", "))
+    }
+}
+
+/// Escape a value for use inside an RFC 7235 quoted-string
+fn escape(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !c.is_control())
+        .flat_map(|c| match c {
+            '"' | '\\' => vec!['\\', c],
+            _ => vec![c],
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESOURCE: &str = "https://miro-mcp.fly-agile.com";
+
+    #[test]
+    fn test_no_token_has_no_error_code() {
+        let challenge = BearerChallenge::from_auth_error(RESOURCE, &AuthError::NoToken);
+        assert_eq!(challenge.status_code(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            challenge.to_header_value(),
+            "Bearer resource_metadata=\"https://miro-mcp.fly-agile.com/.well-known/oauth-protected-resource\""
+        );
+    }
+
+    #[test]
+    fn test_expired_token_challenge() {
+        let challenge = BearerChallenge::from_auth_error(RESOURCE, &AuthError::TokenExpired);
+        let header = challenge.to_header_value();
+        assert_eq!(challenge.status_code(), StatusCode::UNAUTHORIZED);
+        assert!(header.contains("error=\"invalid_token\""));
+        assert!(header.contains("error_description=\"The access token expired\""));
+    }
+
+    #[test]
+    fn test_insufficient_scope_is_forbidden() {
+        let challenge = BearerChallenge::from_auth_error(
+            RESOURCE,
+            &AuthError::InsufficientScope("boards:write".to_string()),
+        );
+        let header = challenge.to_header_value();
+        assert_eq!(challenge.status_code(), StatusCode::FORBIDDEN);
+        assert!(header.contains("error=\"insufficient_scope\""));
+        assert!(header.contains("scope=\"boards:write\""));
+    }
+
+    #[test]
+    fn test_trailing_slash_in_resource_url() {
+        let challenge = BearerChallenge::from_auth_error(
+            "https://miro-mcp.fly-agile.com/",
+            &AuthError::NoToken,
+        );
+        assert_eq!(
+            challenge.resource_metadata,
+            "https://miro-mcp.fly-agile.com/.well-known/oauth-protected-resource"
+        );
+    }
+
+    #[test]
+    fn test_description_quotes_are_escaped() {
+        let challenge = BearerChallenge::from_auth_error(
+            RESOURCE,
+            &AuthError::TokenValidationFailed("bad \"aud\"\nclaim".to_string()),
+        );
+        assert!(challenge
+            .to_header_value()
+            .contains("error_description=\"bad \\\"aud\\\"claim\""));
+    }
+}
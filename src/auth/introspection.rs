@@ -0,0 +1,258 @@
+use crate::auth::token_validator::UserInfo;
+use crate::auth::types::AuthError;
+use serde::Deserialize;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::{debug, warn};
+
+/// Introspection response fields we rely on (RFC 7662 §2.2)
+#[derive(Debug, Deserialize)]
+struct IntrospectionResponse {
+    /// Whether the token is currently active
+    active: bool,
+    /// Space-separated scopes granted to the token
+    #[serde(default)]
+    scope: Option<String>,
+    /// Subject (user ID)
+    #[serde(default)]
+    sub: Option<String>,
+    /// Miro user ID (fallback when `sub` is absent)
+    #[serde(default)]
+    user_id: Option<String>,
+    /// Team ID (Miro-specific)
+    #[serde(default)]
+    team_id: Option<String>,
+    /// Expiration time (Unix timestamp)
+    #[serde(default)]
+    exp: Option<u64>,
+}
+
+/// RFC 7662 token introspection client for opaque access tokens
+///
+/// POSTs the token to the authorization server's introspection endpoint and
+/// maps the response into [`UserInfo`]. The resource server authenticates
+/// with HTTP Basic when client credentials are configured.
+pub struct IntrospectionClient {
+    endpoint: String,
+    client_id: Option<String>,
+    client_secret: Option<String>,
+    http_client: reqwest::Client,
+}
+
+impl IntrospectionClient {
+    /// Create a client for the given introspection endpoint
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        let http_client = reqwest::Client::builder()
+            .user_agent(concat!("miro-mcp-server/", env!("CARGO_PKG_VERSION")))
+            .timeout(Duration::from_secs(10))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            endpoint: endpoint.into(),
+            client_id: None,
+            client_secret: None,
+            http_client,
+        }
+    }
+
+    /// Authenticate introspection requests with HTTP Basic client credentials
+    pub fn with_credentials(
+        mut self,
+        client_id: impl Into<String>,
+        client_secret: Option<String>,
+    ) -> Self {
+        self.client_id = Some(client_id.into());
+        self.client_secret = client_secret;
+        self
+    }
+
+    /// Introspection endpoint URL
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Introspect an access token
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(UserInfo)` - Token is active; carries its `exp` when provided
+    /// * `Err(AuthError::TokenInvalid)` - Token is inactive (revoked, unknown, ...)
+    /// * `Err(AuthError::TokenExpired)` - Token reported active but past `exp`
+    /// * `Err(AuthError::TokenValidationFailed)` - Endpoint unreachable or bad response
+    pub async fn introspect(&self, token: &str) -> Result<UserInfo, AuthError> {
+        let mut request = self
+            .http_client
+            .post(&self.endpoint)
+            .header("Accept", "application/json")
+            .form(&[("token", token), ("token_type_hint", "access_token")]);
+        if let Some(client_id) = &self.client_id {
+            request = request.basic_auth(client_id, self.client_secret.as_ref());
+        }
+
+        let response = request.send().await.map_err(|e| {
+            warn!(error = %e, endpoint = %self.endpoint, "Introspection request failed");
+            AuthError::TokenValidationFailed(format!("Introspection request failed: {}", e))
+        })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            warn!(status = %status, endpoint = %self.endpoint, "Introspection endpoint error");
+            return Err(AuthError::TokenValidationFailed(format!(
+                "Introspection endpoint returned {}",
+                status
+            )));
+        }
+
+        let body: IntrospectionResponse = response.json().await.map_err(|e| {
+            warn!(error = %e, "Invalid introspection response");
+            AuthError::TokenValidationFailed(format!("Invalid introspection response: {}", e))
+        })?;
+
+        user_info_from_response(body)
+    }
+}
+
+fn user_info_from_response(body: IntrospectionResponse) -> Result<UserInfo, AuthError> {
+    if !body.active {
+        debug!("Introspection reports token inactive");
+        return Err(AuthError::TokenInvalid);
+    }
+
+    if let Some(exp) = body.exp {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs();
+        if exp <= now {
+            warn!(expiry = exp, now = now, "Introspected token expired");
+            return Err(AuthError::TokenExpired);
+        }
+    }
+
+    let user_id = body.sub.or(body.user_id).ok_or_else(|| {
+        AuthError::TokenValidationFailed("Introspection response has no subject".to_string())
+    })?;
+
+    let scopes = body
+        .scope
+        .map(|s| s.split_whitespace().map(String::from).collect())
+        .unwrap_or_default();
+
+    let user_info = UserInfo::new(user_id, body.team_id, scopes);
+    Ok(match body.exp {
+        Some(exp) => user_info.with_expires_at(exp),
+        None => user_info,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::{body_string_contains, header_exists, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn in_one_hour() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 3600
+    }
+
+    async fn server_responding(body: serde_json::Value) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/introspect"))
+            .and(body_string_contains("token=opaque-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn test_active_token_maps_to_user_info() {
+        let server = server_responding(json!({
+            "active": true,
+            "sub": "3458764517517819000",
+            "team_id": "3458764517517852417",
+            "scope": "boards:read boards:write",
+            "exp": in_one_hour(),
+        }))
+        .await;
+
+        let client = IntrospectionClient::new(format!("{}/introspect", server.uri()));
+        let user_info = client.introspect("opaque-token").await.unwrap();
+
+        assert_eq!(user_info.user_id, "3458764517517819000");
+        assert_eq!(user_info.team_id.as_deref(), Some("3458764517517852417"));
+        assert_eq!(user_info.scopes, vec!["boards:read", "boards:write"]);
+        assert!(!user_info.is_expired());
+    }
+
+    #[tokio::test]
+    async fn test_inactive_token_rejected() {
+        let server = server_responding(json!({ "active": false })).await;
+        let client = IntrospectionClient::new(format!("{}/introspect", server.uri()));
+
+        let result = client.introspect("opaque-token").await;
+        assert!(matches!(result, Err(AuthError::TokenInvalid)));
+    }
+
+    #[tokio::test]
+    async fn test_active_but_past_exp_rejected() {
+        let server = server_responding(json!({
+            "active": true,
+            "sub": "user123",
+            "exp": 1_000_000_000u64,
+        }))
+        .await;
+        let client = IntrospectionClient::new(format!("{}/introspect", server.uri()));
+
+        let result = client.introspect("opaque-token").await;
+        assert!(matches!(result, Err(AuthError::TokenExpired)));
+    }
+
+    #[tokio::test]
+    async fn test_missing_subject_rejected() {
+        let server = server_responding(json!({ "active": true })).await;
+        let client = IntrospectionClient::new(format!("{}/introspect", server.uri()));
+
+        let result = client.introspect("opaque-token").await;
+        assert!(matches!(result, Err(AuthError::TokenValidationFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_sends_client_credentials() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/introspect"))
+            .and(header_exists("authorization"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "active": true,
+                "user_id": "user123",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = IntrospectionClient::new(format!("{}/introspect", server.uri()))
+            .with_credentials("client", Some("secret".to_string()));
+        let user_info = client.introspect("opaque-token").await.unwrap();
+        assert_eq!(user_info.user_id, "user123");
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_error_fails_validation() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+
+        let client = IntrospectionClient::new(format!("{}/introspect", server.uri()));
+        let result = client.introspect("opaque-token").await;
+        assert!(matches!(result, Err(AuthError::TokenValidationFailed(_))));
+    }
+}
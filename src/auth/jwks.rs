@@ -0,0 +1,430 @@
+use crate::auth::types::AuthError;
+use jsonwebtoken::jwk::{
+    AlgorithmParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm, PublicKeyUse,
+};
+use jsonwebtoken::{Algorithm, DecodingKey};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tracing::{debug, info, warn};
+
+/// Signature algorithms accepted for access tokens
+///
+/// HMAC and `none` are never accepted: a resource server must not share
+/// secrets with the authorization server.
+pub const SUPPORTED_ALGORITHMS: &[Algorithm] =
+    &[Algorithm::RS256, Algorithm::ES256, Algorithm::EdDSA];
+
+/// Where the JSON Web Key Set is loaded from
+#[derive(Debug, Clone)]
+pub enum JwksSource {
+    /// Remote JWKS endpoint (e.g., https://auth.example.com/.well-known/jwks.json)
+    Url(String),
+    /// Local JWKS file (pinned keys, air-gapped deployments)
+    File(PathBuf),
+    /// In-memory key set (tests, keys provisioned at startup)
+    Static(JwkSet),
+}
+
+/// A verification key with the single algorithm it may be used with
+#[derive(Clone)]
+struct VerificationKey {
+    kid: Option<String>,
+    algorithm: Algorithm,
+    key: DecodingKey,
+}
+
+/// Mutable cache state, guarded by one async mutex so concurrent cache
+/// misses trigger a single fetch
+struct JwksState {
+    keys: Vec<VerificationKey>,
+    fetched_at: Option<Instant>,
+    last_failure: Option<Instant>,
+    consecutive_failures: u32,
+}
+
+/// JWKS key provider with caching, `kid` lookup and fetch backoff
+///
+/// - Keys are cached for `cache_ttl` (default 1 hour)
+/// - An unknown `kid` triggers one refetch (key rotation), at most once per
+///   `refresh_cooldown` (default 30 seconds) so random `kid`s can't hammer the source
+/// - Failed fetches back off exponentially from `initial_backoff` up to `max_backoff`;
+///   stale keys keep being served while the source is unavailable
+pub struct JwksProvider {
+    source: JwksSource,
+    http_client: reqwest::Client,
+    state: Mutex<JwksState>,
+    cache_ttl: Duration,
+    refresh_cooldown: Duration,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl JwksProvider {
+    /// Create a provider for the given key source with default timings
+    pub fn new(source: JwksSource) -> Self {
+        let http_client = reqwest::Client::builder()
+            .user_agent(concat!("miro-mcp-server/", env!("CARGO_PKG_VERSION")))
+            .timeout(Duration::from_secs(10))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            source,
+            http_client,
+            state: Mutex::new(JwksState {
+                keys: Vec::new(),
+                fetched_at: None,
+                last_failure: None,
+                consecutive_failures: 0,
+            }),
+            cache_ttl: Duration::from_secs(60 * 60),
+            refresh_cooldown: Duration::from_secs(30),
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(5 * 60),
+        }
+    }
+
+    /// Set how long fetched keys are trusted before a refresh
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// Set the minimum delay between refetches triggered by an unknown `kid`
+    pub fn with_refresh_cooldown(mut self, cooldown: Duration) -> Self {
+        self.refresh_cooldown = cooldown;
+        self
+    }
+
+    /// Set the exponential backoff bounds applied after fetch failures
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Find the verification key for a token header
+    ///
+    /// # Arguments
+    ///
+    /// * `kid` - Key ID from the JWT header (optional when the set has one key)
+    /// * `algorithm` - Algorithm from the JWT header; must match the key's algorithm
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DecodingKey)` - Key to verify the signature with
+    /// * `Err(AuthError::TokenValidationFailed)` - No matching key or JWKS unavailable
+    pub async fn key_for(
+        &self,
+        kid: Option<&str>,
+        algorithm: Algorithm,
+    ) -> Result<DecodingKey, AuthError> {
+        let mut state = self.state.lock().await;
+        let mut refreshed = false;
+
+        let stale = state
+            .fetched_at
+            .is_none_or(|fetched| fetched.elapsed() >= self.cache_ttl);
+        if stale {
+            refreshed = true;
+            if let Err(e) = self.refresh(&mut state).await {
+                if state.keys.is_empty() {
+                    return Err(e);
+                }
+                warn!(error = %e, "JWKS refresh failed - serving cached keys");
+            }
+        }
+
+        if let Some(key) = find_key(&state.keys, kid, algorithm) {
+            return Ok(key);
+        }
+
+        // Unknown kid: the authorization server may have rotated its keys
+        let cooled_down = state
+            .fetched_at
+            .is_none_or(|fetched| fetched.elapsed() >= self.refresh_cooldown);
+        if kid.is_some() && !refreshed && cooled_down {
+            debug!(kid = ?kid, "Unknown kid - refetching JWKS");
+            self.refresh(&mut state).await?;
+            if let Some(key) = find_key(&state.keys, kid, algorithm) {
+                return Ok(key);
+            }
+        }
+
+        warn!(kid = ?kid, algorithm = ?algorithm, "No matching JWKS key");
+        Err(AuthError::TokenValidationFailed(format!(
+            "No {:?} signing key found for kid {}",
+            algorithm,
+            kid.unwrap_or("<none>")
+        )))
+    }
+
+    /// Reload keys from the source, honoring the failure backoff
+    async fn refresh(&self, state: &mut JwksState) -> Result<(), AuthError> {
+        if let Some(retry_in) = self.backoff_remaining(state) {
+            return Err(AuthError::TokenValidationFailed(format!(
+                "JWKS unavailable, retrying in {}s",
+                retry_in.as_secs().max(1)
+            )));
+        }
+
+        match self.load().await {
+            Ok(set) => {
+                state.keys = set.keys.iter().filter_map(verification_key).collect();
+                state.fetched_at = Some(Instant::now());
+                state.last_failure = None;
+                state.consecutive_failures = 0;
+                info!(keys = state.keys.len(), "JWKS loaded");
+                Ok(())
+            }
+            Err(reason) => {
+                state.last_failure = Some(Instant::now());
+                state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+                warn!(
+                    error = %reason,
+                    failures = state.consecutive_failures,
+                    "JWKS fetch failed"
+                );
+                Err(AuthError::TokenValidationFailed(format!(
+                    "JWKS fetch failed: {}",
+                    reason
+                )))
+            }
+        }
+    }
+
+    /// Time left before another fetch may be attempted, if backing off
+    fn backoff_remaining(&self, state: &JwksState) -> Option<Duration> {
+        let last_failure = state.last_failure?;
+        let exponent = state.consecutive_failures.saturating_sub(1).min(16);
+        let delay = self
+            .initial_backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff);
+        delay.checked_sub(last_failure.elapsed())
+    }
+
+    async fn load(&self) -> Result<JwkSet, String> {
+        match &self.source {
+            JwksSource::Url(url) => {
+                let response = self
+                    .http_client
+                    .get(url)
+                    .send()
+                    .await
+                    .and_then(|r| r.error_for_status())
+                    .map_err(|e| e.to_string())?;
+                response.json::<JwkSet>().await.map_err(|e| e.to_string())
+            }
+            JwksSource::File(path) => {
+                let contents = tokio::fs::read_to_string(path)
+                    .await
+                    .map_err(|e| format!("{}: {}", path.display(), e))?;
+                serde_json::from_str(&contents).map_err(|e| e.to_string())
+            }
+            JwksSource::Static(set) => Ok(set.clone()),
+        }
+    }
+}
+
+/// Select a key by `kid` (or the only key of that algorithm when `kid` is absent)
+fn find_key(
+    keys: &[VerificationKey],
+    kid: Option<&str>,
+    algorithm: Algorithm,
+) -> Option<DecodingKey> {
+    let mut candidates = keys.iter().filter(|k| k.algorithm == algorithm);
+    match kid {
+        Some(kid) => candidates
+            .find(|k| k.kid.as_deref() == Some(kid))
+            .map(|k| k.key.clone()),
+        None => {
+            let first = candidates.next()?;
+            // Ambiguous without a kid - refuse rather than guess
+            candidates.next().is_none().then(|| first.key.clone())
+        }
+    }
+}
+
+/// Convert a JWK into a verification key, skipping unsupported or encryption keys
+fn verification_key(jwk: &Jwk) -> Option<VerificationKey> {
+    if jwk.common.public_key_use == Some(PublicKeyUse::Encryption) {
+        return None;
+    }
+
+    let Some(algorithm) = jwk_algorithm(jwk) else {
+        debug!(kid = ?jwk.common.key_id, "Skipping JWK with unsupported algorithm");
+        return None;
+    };
+
+    match DecodingKey::from_jwk(jwk) {
+        Ok(key) => Some(VerificationKey {
+            kid: jwk.common.key_id.clone(),
+            algorithm,
+            key,
+        }),
+        Err(e) => {
+            warn!(kid = ?jwk.common.key_id, error = %e, "Skipping malformed JWK");
+            None
+        }
+    }
+}
+
+/// Algorithm a JWK may verify: explicit `alg` if present, else inferred from key type
+fn jwk_algorithm(jwk: &Jwk) -> Option<Algorithm> {
+    match (&jwk.common.key_algorithm, &jwk.algorithm) {
+        (Some(KeyAlgorithm::RS256) | None, AlgorithmParameters::RSA(_)) => Some(Algorithm::RS256),
+        (Some(KeyAlgorithm::ES256) | None, AlgorithmParameters::EllipticCurve(params))
+            if params.curve == EllipticCurve::P256 =>
+        {
+            Some(Algorithm::ES256)
+        }
+        (Some(KeyAlgorithm::EdDSA) | None, AlgorithmParameters::OctetKeyPair(params))
+            if params.curve == EllipticCurve::Ed25519 =>
+        {
+            Some(Algorithm::EdDSA)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const ED25519_X: &str = "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo";
+
+    fn ed_jwk(kid: &str) -> serde_json::Value {
+        json!({ "kty": "OKP", "crv": "Ed25519", "x": ED25519_X, "kid": kid, "use": "sig" })
+    }
+
+    fn key_set(keys: Vec<serde_json::Value>) -> JwkSet {
+        serde_json::from_value(json!({ "keys": keys })).unwrap()
+    }
+
+    #[test]
+    fn test_algorithm_inferred_from_key_type() {
+        let set = key_set(vec![ed_jwk("ed-1")]);
+        assert_eq!(jwk_algorithm(&set.keys[0]), Some(Algorithm::EdDSA));
+    }
+
+    #[test]
+    fn test_encryption_and_hmac_keys_skipped() {
+        let set = key_set(vec![
+            json!({ "kty": "OKP", "crv": "Ed25519", "x": ED25519_X, "kid": "enc", "use": "enc" }),
+            json!({ "kty": "oct", "k": "c2VjcmV0", "kid": "hmac", "alg": "HS256" }),
+        ]);
+        assert!(set
+            .keys
+            .iter()
+            .filter_map(verification_key)
+            .next()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_lookup_by_kid_and_algorithm() {
+        let provider = JwksProvider::new(JwksSource::Static(key_set(vec![ed_jwk("ed-1")])));
+
+        assert!(provider
+            .key_for(Some("ed-1"), Algorithm::EdDSA)
+            .await
+            .is_ok());
+        // Same kid, different algorithm: algorithm confusion must fail
+        assert!(provider
+            .key_for(Some("ed-1"), Algorithm::RS256)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_missing_kid_requires_unambiguous_key() {
+        let single = JwksProvider::new(JwksSource::Static(key_set(vec![ed_jwk("a")])));
+        assert!(single.key_for(None, Algorithm::EdDSA).await.is_ok());
+
+        let double = JwksProvider::new(JwksSource::Static(key_set(vec![ed_jwk("a"), ed_jwk("b")])));
+        assert!(double.key_for(None, Algorithm::EdDSA).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_kid_refetches_once() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/jwks.json"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "keys": [ed_jwk("old")] })),
+            )
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/jwks.json"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "keys": [ed_jwk("new")] })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let provider = JwksProvider::new(JwksSource::Url(format!("{}/jwks.json", server.uri())))
+            .with_refresh_cooldown(Duration::ZERO);
+
+        assert!(provider
+            .key_for(Some("old"), Algorithm::EdDSA)
+            .await
+            .is_ok());
+        // Rotated key is picked up by a single refetch
+        assert!(provider
+            .key_for(Some("new"), Algorithm::EdDSA)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_kid_refetch_respects_cooldown() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/jwks.json"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "keys": [ed_jwk("a")] })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let provider = JwksProvider::new(JwksSource::Url(format!("{}/jwks.json", server.uri())));
+
+        assert!(provider.key_for(Some("a"), Algorithm::EdDSA).await.is_ok());
+        assert!(provider.key_for(Some("x"), Algorithm::EdDSA).await.is_err());
+        assert!(provider.key_for(Some("y"), Algorithm::EdDSA).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_failure_backs_off() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/jwks.json"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let provider = JwksProvider::new(JwksSource::Url(format!("{}/jwks.json", server.uri())))
+            .with_backoff(Duration::from_secs(60), Duration::from_secs(300));
+
+        let first = provider.key_for(Some("a"), Algorithm::EdDSA).await;
+        assert!(
+            matches!(first, Err(AuthError::TokenValidationFailed(msg)) if msg.contains("fetch failed"))
+        );
+
+        // Second attempt within the backoff window does not hit the server
+        let second = provider.key_for(Some("a"), Algorithm::EdDSA).await;
+        assert!(
+            matches!(second, Err(AuthError::TokenValidationFailed(msg)) if msg.contains("retrying"))
+        );
+    }
+}
@@ -1,5 +1,9 @@
+use crate::auth::scopes::SUPPORTED_SCOPES;
 use serde::{Deserialize, Serialize};
 
+/// Miro's OAuth token introspection endpoint (RFC 7662)
+pub const MIRO_INTROSPECTION_ENDPOINT: &str = "https://api.miro.com/v2/oauth/token/introspect";
+
 /// Protected Resource Metadata per RFC 9728
 ///
 /// This metadata tells OAuth clients (like Claude.ai) where to find the authorization server
@@ -45,11 +49,9 @@ impl ProtectedResourceMetadata {
             // Miro's OAuth authorization server
             authorization_servers: vec!["https://miro.com".to_string()],
             // Miro scopes for board access
-            scopes_supported: Some(vec!["boards:read".to_string(), "boards:write".to_string()]),
+            scopes_supported: Some(SUPPORTED_SCOPES.iter().map(|s| s.to_string()).collect()),
             // Miro's introspection endpoint
-            introspection_endpoint: Some(
-                "https://api.miro.com/v2/oauth/token/introspect".to_string(),
-            ),
+            introspection_endpoint: Some(MIRO_INTROSPECTION_ENDPOINT.to_string()),
             revocation_endpoint: None, // Miro doesn't provide public revocation endpoint
         }
     }
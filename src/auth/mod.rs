@@ -1,9 +1,16 @@
 pub mod bearer;
+pub mod challenge;
+pub mod introspection;
+pub mod jwks;
 pub mod metadata;
+pub mod scopes;
 pub mod token_validator;
 pub mod types;
 
-pub use bearer::extract_bearer_token;
+pub use bearer::{extract_bearer_token, BearerToken};
+pub use challenge::BearerChallenge;
+pub use introspection::IntrospectionClient;
+pub use jwks::{JwksProvider, JwksSource};
 pub use metadata::ProtectedResourceMetadata;
 pub use token_validator::{TokenValidator, UserInfo};
 pub use types::AuthError;
@@ -0,0 +1,10 @@
+//! Miro OAuth scopes understood by this server
+
+/// Read boards and their items
+pub const BOARDS_READ: &str = "boards:read";
+
+/// Create, update and delete boards and their items
+pub const BOARDS_WRITE: &str = "boards:write";
+
+/// All scopes advertised in Protected Resource Metadata
+pub const SUPPORTED_SCOPES: &[&str] = &[BOARDS_READ, BOARDS_WRITE];
@@ -1,5 +1,9 @@
+use crate::auth::introspection::IntrospectionClient;
+use crate::auth::jwks::{JwksProvider, JwksSource, SUPPORTED_ALGORITHMS};
+use crate::auth::metadata::MIRO_INTROSPECTION_ENDPOINT;
 use crate::auth::types::AuthError;
-use jsonwebtoken::{decode, decode_header, DecodingKey, TokenData, Validation};
+use crate::config::{TokenValidationConfig, TokenValidationMode};
+use jsonwebtoken::{decode, decode_header, TokenData, Validation};
 use lru::LruCache;
 use serde::{Deserialize, Serialize};
 use std::num::NonZeroUsize;
@@ -7,7 +11,7 @@ use std::sync::Mutex;
 use std::time::{SystemTime, UNIX_EPOCH};
 use tracing::{debug, info, warn};
 
-/// User information extracted from JWT claims
+/// User information extracted from JWT claims or an introspection response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserInfo {
     /// User ID from token (sub claim)
@@ -19,6 +23,9 @@ pub struct UserInfo {
     /// Timestamp when this cache entry was created
     #[serde(skip)]
     cached_at: u64,
+    /// Token expiry (Unix timestamp), when known
+    #[serde(skip)]
+    expires_at: Option<u64>,
 }
 
 impl UserInfo {
@@ -34,10 +41,31 @@ impl UserInfo {
             team_id,
             scopes,
             cached_at: now,
+            expires_at: None,
+        }
+    }
+
+    /// Whether the token was granted `scope`
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|s| s == scope)
+    }
+
+    /// Require `scope`, failing with `InsufficientScope` naming it
+    pub fn require_scope(&self, scope: &str) -> Result<(), AuthError> {
+        if self.has_scope(scope) {
+            Ok(())
+        } else {
+            Err(AuthError::InsufficientScope(scope.to_string()))
         }
     }
 
-    /// Check if this cache entry is expired (5 minute TTL)
+    /// Record when the underlying token expires
+    pub fn with_expires_at(mut self, expires_at: u64) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// Check if this cache entry is expired (5 minute TTL, or token expiry if sooner)
     pub fn is_expired(&self) -> bool {
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -45,7 +73,7 @@ impl UserInfo {
             .as_secs();
 
         const TTL_SECONDS: u64 = 5 * 60; // 5 minutes
-        now - self.cached_at > TTL_SECONDS
+        now - self.cached_at > TTL_SECONDS || self.expires_at.is_some_and(|exp| exp <= now)
     }
 }
 
@@ -94,18 +122,27 @@ impl StringOrVec {
 
 /// Token validator with LRU caching for Resource Server pattern
 ///
-/// Validates JWT tokens from Claude.ai:
-/// - Decodes JWT (without signature verification - trusts Claude's validation)
-/// - Verifies audience claim matches our server
-/// - Verifies token not expired
-/// - Caches validation results for performance
+/// Validates access tokens according to its [`TokenValidationMode`]:
+/// - `Jwt`: verifies signature (RS256/ES256/EdDSA) against keys from a JWKS source,
+///   audience (and issuer, if configured) and expiry
+/// - `Introspection`: asks the authorization server (RFC 7662), for opaque tokens
+/// - `JwtThenIntrospection`: JWT first, introspection when that fails
 ///
-/// For production, consider adding JWT signature verification using Miro's JWKS.
+/// Results are cached for performance. Without a JWKS source or introspection
+/// endpoint every token is rejected (fail closed).
 pub struct TokenValidator {
     /// LRU cache for validated tokens (capacity: 100)
     cache: Mutex<LruCache<String, UserInfo>>,
     /// Our server URL (expected in audience claim)
     resource_url: String,
+    /// Signing keys of the authorization server
+    jwks: Option<JwksProvider>,
+    /// Expected issuer (iss claim), if enforced
+    issuer: Option<String>,
+    /// Validation strategy
+    mode: TokenValidationMode,
+    /// RFC 7662 introspection client for opaque tokens
+    introspection: Option<IntrospectionClient>,
 }
 
 impl TokenValidator {
@@ -119,14 +156,86 @@ impl TokenValidator {
         Self {
             cache: Mutex::new(LruCache::new(NonZeroUsize::new(100).unwrap())),
             resource_url,
+            jwks: None,
+            issuer: None,
+            mode: TokenValidationMode::Jwt,
+            introspection: None,
+        }
+    }
+
+    /// Create a validator from configuration
+    ///
+    /// Uses `jwks_url` when set, otherwise `jwks_file`. With neither, the
+    /// validator rejects every JWT. Introspection modes use `introspection_url`,
+    /// defaulting to Miro's endpoint.
+    pub fn from_config(resource_url: String, config: &TokenValidationConfig) -> Self {
+        let mut validator = Self::new(resource_url).with_mode(config.mode);
+
+        if let Some(url) = &config.jwks_url {
+            validator = validator.with_jwks(JwksProvider::new(JwksSource::Url(url.clone())));
+        } else if let Some(path) = &config.jwks_file {
+            validator = validator.with_jwks(JwksProvider::new(JwksSource::File(path.clone())));
+        }
+
+        if let Some(issuer) = &config.issuer {
+            validator = validator.with_issuer(issuer.clone());
+        }
+
+        if config.mode != TokenValidationMode::Jwt {
+            let endpoint = config
+                .introspection_url
+                .as_deref()
+                .unwrap_or(MIRO_INTROSPECTION_ENDPOINT);
+            let mut client = IntrospectionClient::new(endpoint);
+            if let Some(client_id) = &config.introspection_client_id {
+                client =
+                    client.with_credentials(client_id, config.introspection_client_secret.clone());
+            }
+            validator = validator.with_introspection(client);
         }
+
+        validator
+    }
+
+    /// Set the validation strategy
+    pub fn with_mode(mut self, mode: TokenValidationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Introspect opaque tokens with this client
+    pub fn with_introspection(mut self, client: IntrospectionClient) -> Self {
+        self.introspection = Some(client);
+        self
+    }
+
+    /// Verify JWT signatures with keys from this provider
+    pub fn with_jwks(mut self, jwks: JwksProvider) -> Self {
+        self.jwks = Some(jwks);
+        self
+    }
+
+    /// Require the `iss` claim to equal `issuer`
+    pub fn with_issuer(mut self, issuer: impl Into<String>) -> Self {
+        self.issuer = Some(issuer.into());
+        self
+    }
+
+    /// Whether signature verification keys are configured
+    pub fn has_jwks(&self) -> bool {
+        self.jwks.is_some()
+    }
+
+    /// Configured validation strategy
+    pub fn mode(&self) -> TokenValidationMode {
+        self.mode
     }
 
-    /// Validate Bearer token (JWT from Claude.ai)
+    /// Validate Bearer token (JWT or opaque, depending on the mode)
     ///
     /// # Arguments
     ///
-    /// * `token` - JWT access token (without "Bearer " prefix)
+    /// * `token` - Access token (without "Bearer " prefix)
     ///
     /// # Returns
     ///
@@ -155,8 +264,22 @@ impl TokenValidator {
         }
 
         // Validate token
-        debug!("Validating JWT token");
-        let user_info = self.validate_jwt(token)?;
+        let user_info = match self.mode {
+            TokenValidationMode::Jwt => {
+                debug!("Validating JWT token");
+                self.validate_jwt(token).await?
+            }
+            TokenValidationMode::Introspection => self.introspect(token).await?,
+            TokenValidationMode::JwtThenIntrospection => match self.validate_jwt(token).await {
+                Ok(user_info) => user_info,
+                // A verified-but-expired JWT is definitive
+                Err(AuthError::TokenExpired) => return Err(AuthError::TokenExpired),
+                Err(e) => {
+                    debug!(error = %e, "JWT validation failed - falling back to introspection");
+                    self.introspect(token).await?
+                }
+            },
+        };
 
         // Cache result
         {
@@ -173,48 +296,75 @@ impl TokenValidator {
         Ok(user_info)
     }
 
+    /// Validate an opaque token with the introspection endpoint
+    async fn introspect(&self, token: &str) -> Result<UserInfo, AuthError> {
+        let client = self.introspection.as_ref().ok_or_else(|| {
+            warn!("No introspection endpoint configured");
+            AuthError::TokenValidationFailed("Token introspection not configured".into())
+        })?;
+
+        debug!(endpoint = %client.endpoint(), "Introspecting token");
+        client.introspect(token).await
+    }
+
     /// Validate JWT token
     ///
     /// Performs:
-    /// 1. JWT decoding (without signature verification for now)
-    /// 2. Expiry check
-    /// 3. Audience verification
-    ///
-    /// For production: Add signature verification using Miro's JWKS endpoint
-    fn validate_jwt(&self, token: &str) -> Result<UserInfo, AuthError> {
-        // Decode JWT header to check algorithm
+    /// 1. Algorithm check (RS256/ES256/EdDSA only)
+    /// 2. Signature verification with the JWKS key matching `kid`
+    /// 3. Expiry check
+    /// 4. Audience (and optional issuer) verification
+    async fn validate_jwt(&self, token: &str) -> Result<UserInfo, AuthError> {
+        // Decode JWT header to select algorithm and key
         let header = decode_header(token).map_err(|e| {
             warn!(error = %e, "Failed to decode JWT header");
             AuthError::InvalidTokenFormat
         })?;
 
-        debug!(algorithm = ?header.alg, "JWT header decoded");
+        debug!(algorithm = ?header.alg, kid = ?header.kid, "JWT header decoded");
 
-        // For MVP: Decode without signature verification
-        // TODO: Fetch Miro's JWKS and verify signature in production
-        let mut validation = Validation::default();
-        validation.insecure_disable_signature_validation();
-        validation.validate_exp = true; // Still check expiry
+        if !SUPPORTED_ALGORITHMS.contains(&header.alg) {
+            warn!(algorithm = ?header.alg, "Rejected JWT signing algorithm");
+            return Err(AuthError::TokenValidationFailed(format!(
+                "Unsupported signing algorithm {:?}",
+                header.alg
+            )));
+        }
+
+        let jwks = self.jwks.as_ref().ok_or_else(|| {
+            warn!("No JWKS configured - cannot verify token signature");
+            AuthError::TokenValidationFailed("Signature verification keys not configured".into())
+        })?;
+        let key = jwks.key_for(header.kid.as_deref(), header.alg).await?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.validate_exp = true;
 
         // Audience validation (if we have it)
         if !self.resource_url.is_empty() {
             validation.set_audience(&[&self.resource_url]);
         }
 
-        let token_data: TokenData<Claims> =
-            decode(token, &DecodingKey::from_secret(&[]), &validation).map_err(|e| {
-                warn!(error = %e, "JWT validation failed");
-                match e.kind() {
-                    jsonwebtoken::errors::ErrorKind::ExpiredSignature => AuthError::TokenExpired,
-                    jsonwebtoken::errors::ErrorKind::InvalidAudience => {
-                        AuthError::TokenValidationFailed(format!(
-                            "Invalid audience - expected {}",
-                            self.resource_url
-                        ))
-                    }
-                    _ => AuthError::TokenInvalid,
+        if let Some(issuer) = &self.issuer {
+            validation.set_issuer(&[issuer]);
+        }
+
+        let token_data: TokenData<Claims> = decode(token, &key, &validation).map_err(|e| {
+            warn!(error = %e, "JWT validation failed");
+            match e.kind() {
+                jsonwebtoken::errors::ErrorKind::ExpiredSignature => AuthError::TokenExpired,
+                jsonwebtoken::errors::ErrorKind::InvalidAudience => {
+                    AuthError::TokenValidationFailed(format!(
+                        "Invalid audience - expected {}",
+                        self.resource_url
+                    ))
+                }
+                jsonwebtoken::errors::ErrorKind::InvalidIssuer => {
+                    AuthError::TokenValidationFailed("Invalid issuer".to_string())
                 }
-            })?;
+                _ => AuthError::TokenInvalid,
+            }
+        })?;
 
         let claims = token_data.claims;
 
@@ -261,7 +411,7 @@ impl TokenValidator {
             "JWT claims extracted"
         );
 
-        Ok(UserInfo::new(claims.sub, claims.team_id, scopes))
+        Ok(UserInfo::new(claims.sub, claims.team_id, scopes).with_expires_at(claims.exp))
     }
 
     /// Clear validation cache (useful for testing)
@@ -281,94 +431,220 @@ impl Default for TokenValidator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    use jsonwebtoken::jwk::JwkSet;
+    use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+    use ring::rand::SystemRandom;
+    use ring::signature::{EcdsaKeyPair, Ed25519KeyPair, KeyPair, ECDSA_P256_SHA256_FIXED_SIGNING};
+
+    const RESOURCE: &str = "https://test.example.com";
+
+    /// Locally generated signing key with its public JWK
+    struct TestKey {
+        kid: String,
+        algorithm: Algorithm,
+        encoding_key: EncodingKey,
+        jwk: serde_json::Value,
+    }
 
-    // Helper to create a test JWT (unsigned, for testing only)
-    fn create_test_jwt(sub: &str, aud: &str, exp: u64, scope: Option<&str>) -> String {
-        use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    impl TestKey {
+        fn ed25519(kid: &str) -> Self {
+            let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+            let pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+            Self {
+                kid: kid.to_string(),
+                algorithm: Algorithm::EdDSA,
+                encoding_key: EncodingKey::from_ed_der(pkcs8.as_ref()),
+                jwk: serde_json::json!({
+                    "kty": "OKP",
+                    "crv": "Ed25519",
+                    "kid": kid,
+                    "x": URL_SAFE_NO_PAD.encode(pair.public_key().as_ref()),
+                }),
+            }
+        }
 
-        let header = serde_json::json!({
-            "alg": "HS256",
-            "typ": "JWT"
-        });
+        fn es256(kid: &str) -> Self {
+            let rng = SystemRandom::new();
+            let pkcs8 =
+                EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, &rng).unwrap();
+            let pair =
+                EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, pkcs8.as_ref(), &rng)
+                    .unwrap();
+            // Uncompressed point: 0x04 || X || Y
+            let point = pair.public_key().as_ref();
+            Self {
+                kid: kid.to_string(),
+                algorithm: Algorithm::ES256,
+                encoding_key: EncodingKey::from_ec_der(pkcs8.as_ref()),
+                jwk: serde_json::json!({
+                    "kty": "EC",
+                    "crv": "P-256",
+                    "kid": kid,
+                    "x": URL_SAFE_NO_PAD.encode(&point[1..33]),
+                    "y": URL_SAFE_NO_PAD.encode(&point[33..65]),
+                }),
+            }
+        }
+
+        fn sign(&self, claims: &serde_json::Value) -> String {
+            let mut header = Header::new(self.algorithm);
+            header.kid = Some(self.kid.clone());
+            encode(&header, claims, &self.encoding_key).unwrap()
+        }
+    }
+
+    fn validator_trusting(keys: &[&TestKey]) -> TokenValidator {
+        let set: JwkSet = serde_json::from_value(serde_json::json!({
+            "keys": keys.iter().map(|k| k.jwk.clone()).collect::<Vec<_>>()
+        }))
+        .unwrap();
+        TokenValidator::new(RESOURCE.to_string())
+            .with_jwks(JwksProvider::new(JwksSource::Static(set)))
+    }
+
+    fn in_one_hour() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 3600
+    }
 
+    fn claims(sub: &str, aud: &str, exp: u64, scope: Option<&str>) -> serde_json::Value {
         let mut claims = serde_json::json!({
             "sub": sub,
             "aud": aud,
             "exp": exp,
             "iat": exp.saturating_sub(3600)
         });
-
         if let Some(s) = scope {
             claims["scope"] = serde_json::json!(s);
         }
+        claims
+    }
 
-        let header_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_string(&header).unwrap());
-        let claims_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_string(&claims).unwrap());
+    #[tokio::test]
+    async fn test_validate_valid_eddsa_token() {
+        let key = TestKey::ed25519("ed-1");
+        let validator = validator_trusting(&[&key]);
+        let token = key.sign(&claims(
+            "user123",
+            RESOURCE,
+            in_one_hour(),
+            Some("boards:read"),
+        ));
 
-        format!("{}.{}.fake_signature", header_b64, claims_b64)
+        let user_info = validator.validate(&token).await.unwrap();
+        assert_eq!(user_info.user_id, "user123");
+        assert_eq!(user_info.scopes, vec!["boards:read"]);
     }
 
     #[tokio::test]
-    async fn test_validate_valid_token() {
-        let validator = TokenValidator::new("https://test.example.com".to_string());
-        let future_exp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs()
-            + 3600;
+    async fn test_validate_valid_es256_token() {
+        let key = TestKey::es256("ec-1");
+        let validator = validator_trusting(&[&key]);
+        let token = key.sign(&claims("user456", RESOURCE, in_one_hour(), None));
 
-        let token = create_test_jwt(
-            "user123",
-            "https://test.example.com",
-            future_exp,
-            Some("boards:read"),
-        );
+        let user_info = validator.validate(&token).await.unwrap();
+        assert_eq!(user_info.user_id, "user456");
+    }
+
+    #[tokio::test]
+    async fn test_forged_signature_rejected() {
+        let trusted = TestKey::ed25519("ed-1");
+        // Attacker key reusing the trusted kid
+        let attacker = TestKey::ed25519("ed-1");
+        let validator = validator_trusting(&[&trusted]);
+        let token = attacker.sign(&claims("admin", RESOURCE, in_one_hour(), None));
 
         let result = validator.validate(&token).await;
-        assert!(result.is_ok());
+        assert!(matches!(result, Err(AuthError::TokenInvalid)));
+    }
 
-        let user_info = result.unwrap();
-        assert_eq!(user_info.user_id, "user123");
-        assert_eq!(user_info.scopes, vec!["boards:read"]);
+    #[tokio::test]
+    async fn test_unsigned_token_rejected() {
+        let key = TestKey::ed25519("ed-1");
+        let validator = validator_trusting(&[&key]);
+
+        let header = URL_SAFE_NO_PAD.encode(r#"{"alg":"none","typ":"JWT"}"#);
+        let body =
+            URL_SAFE_NO_PAD.encode(claims("user123", RESOURCE, in_one_hour(), None).to_string());
+        let token = format!("{}.{}.", header, body);
+
+        assert!(validator.validate(&token).await.is_err());
     }
 
     #[tokio::test]
-    async fn test_validate_expired_token() {
-        let validator = TokenValidator::new("https://test.example.com".to_string());
-        let past_exp = 1000; // Way in the past
+    async fn test_hmac_token_rejected() {
+        let key = TestKey::ed25519("ed-1");
+        let validator = validator_trusting(&[&key]);
+        let token = encode(
+            &Header::new(Algorithm::HS256),
+            &claims("user123", RESOURCE, in_one_hour(), None),
+            &EncodingKey::from_secret(b"guessable"),
+        )
+        .unwrap();
+
+        let result = validator.validate(&token).await;
+        assert!(
+            matches!(result, Err(AuthError::TokenValidationFailed(msg)) if msg.contains("Unsupported"))
+        );
+    }
 
-        let token = create_test_jwt("user123", "https://test.example.com", past_exp, None);
+    #[tokio::test]
+    async fn test_validator_without_jwks_fails_closed() {
+        let key = TestKey::ed25519("ed-1");
+        let validator = TokenValidator::new(RESOURCE.to_string());
+        let token = key.sign(&claims("user123", RESOURCE, in_one_hour(), None));
+
+        assert!(validator.validate(&token).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_validate_expired_token() {
+        let key = TestKey::ed25519("ed-1");
+        let validator = validator_trusting(&[&key]);
+        let token = key.sign(&claims("user123", RESOURCE, 1000, None));
 
         let result = validator.validate(&token).await;
-        assert!(result.is_err());
-        matches!(result.unwrap_err(), AuthError::TokenExpired);
+        assert!(matches!(result, Err(AuthError::TokenExpired)));
     }
 
     #[tokio::test]
     async fn test_validate_wrong_audience() {
-        let validator = TokenValidator::new("https://test.example.com".to_string());
-        let future_exp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs()
-            + 3600;
-
-        let token = create_test_jwt("user123", "https://wrong.example.com", future_exp, None);
+        let key = TestKey::ed25519("ed-1");
+        let validator = validator_trusting(&[&key]);
+        let token = key.sign(&claims(
+            "user123",
+            "https://wrong.example.com",
+            in_one_hour(),
+            None,
+        ));
 
         let result = validator.validate(&token).await;
         assert!(result.is_err());
     }
 
     #[tokio::test]
-    async fn test_cache_hit() {
-        let validator = TokenValidator::new("https://test.example.com".to_string());
-        let future_exp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs()
-            + 3600;
+    async fn test_validate_issuer() {
+        let key = TestKey::ed25519("ed-1");
+        let validator = validator_trusting(&[&key]).with_issuer("https://miro.com");
+
+        let mut good = claims("user123", RESOURCE, in_one_hour(), None);
+        good["iss"] = serde_json::json!("https://miro.com");
+        assert!(validator.validate(&key.sign(&good)).await.is_ok());
+
+        let mut bad = claims("user123", RESOURCE, in_one_hour(), None);
+        bad["iss"] = serde_json::json!("https://evil.example.com");
+        assert!(validator.validate(&key.sign(&bad)).await.is_err());
+    }
 
-        let token = create_test_jwt("user123", "https://test.example.com", future_exp, None);
+    #[tokio::test]
+    async fn test_cache_hit() {
+        let key = TestKey::ed25519("ed-1");
+        let validator = validator_trusting(&[&key]);
+        let token = key.sign(&claims("user123", RESOURCE, in_one_hour(), None));
 
         // First validation
         let result1 = validator.validate(&token).await;
@@ -380,6 +656,13 @@ mod tests {
         assert_eq!(result1.unwrap().user_id, result2.unwrap().user_id);
     }
 
+    #[test]
+    fn test_from_config_without_keys() {
+        let validator =
+            TokenValidator::from_config(RESOURCE.to_string(), &TokenValidationConfig::default());
+        assert!(!validator.has_jwks());
+    }
+
     #[test]
     fn test_user_info_expiry() {
         let user_info = UserInfo {
@@ -387,9 +670,28 @@ mod tests {
             team_id: None,
             scopes: vec![],
             cached_at: 1000,
+            expires_at: None,
         };
 
         // Should be expired if cached_at is way in the past
         assert!(user_info.is_expired());
     }
+
+    #[test]
+    fn test_user_info_require_scope() {
+        let user_info = UserInfo::new("test".to_string(), None, vec!["boards:read".to_string()]);
+        assert!(user_info.require_scope("boards:read").is_ok());
+        assert!(matches!(
+            user_info.require_scope("boards:write"),
+            Err(AuthError::InsufficientScope(scope)) if scope == "boards:write"
+        ));
+    }
+
+    #[test]
+    fn test_user_info_expires_with_token() {
+        let user_info = UserInfo::new("test".to_string(), None, vec![]);
+        assert!(!user_info.is_expired());
+        assert!(user_info.clone().with_expires_at(1000).is_expired());
+        assert!(!user_info.with_expires_at(in_one_hour()).is_expired());
+    }
 }
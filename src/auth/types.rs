@@ -17,6 +17,9 @@ pub enum AuthError {
     #[error("No token available")]
     NoToken,
 
+    #[error("Insufficient scope: {0} required")]
+    InsufficientScope(String),
+
     #[error("JSON error: {0}")]
     JsonError(#[from] serde_json::Error),
 }
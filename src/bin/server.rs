@@ -7,10 +7,12 @@
 //!
 //! OAuth is handled by Claude.ai - we only validate JWT tokens
 
+use miro_mcp_server::config::TokenValidationMode;
+use miro_mcp_server::mcp::logging;
 use miro_mcp_server::{Config, TokenValidator};
 use std::sync::Arc;
 use tracing::{info, warn};
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, Layer};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -22,19 +24,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let env_filter = tracing_subscriber::EnvFilter::try_from_default_env()
         .unwrap_or_else(|_| "miro_mcp_server=info".into());
 
+    // RUST_LOG filters the server's own output only; MCP clients choose
+    // their level with logging/setLevel
     match log_format.as_str() {
         "json" => {
             // JSON format for production (structured logs for Scaleway Cockpit)
             tracing_subscriber::registry()
-                .with(env_filter)
-                .with(tracing_subscriber::fmt::layer().json())
+                .with(
+                    tracing_subscriber::fmt::layer()
+                        .json()
+                        .with_filter(env_filter),
+                )
+                .with(logging::layer())
                 .init();
         }
         _ => {
             // Pretty format for development (human-readable)
             tracing_subscriber::registry()
-                .with(env_filter)
-                .with(tracing_subscriber::fmt::layer())
+                .with(tracing_subscriber::fmt::layer().with_filter(env_filter))
+                .with(logging::layer())
                 .init();
         }
     }
@@ -53,8 +61,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         warn!("base_url not configured - using fallback");
         "https://miro-mcp.example.com".to_string()
     });
-    let token_validator = Arc::new(TokenValidator::new(resource_url.clone()));
-    info!(resource_url = %resource_url, "Token validator initialized with JWT validation");
+    let token_validator = Arc::new(TokenValidator::from_config(
+        resource_url.clone(),
+        &config.token_validation,
+    ));
+    if token_validator.mode() == TokenValidationMode::Jwt && !token_validator.has_jwks() {
+        warn!("Neither JWKS_URL nor JWKS_FILE configured - all JWTs will be rejected");
+    }
+    info!(
+        resource_url = %resource_url,
+        mode = ?token_validator.mode(),
+        "Token validator initialized"
+    );
 
     // Get port from environment or use config default
     let port = std::env::var("PORT")
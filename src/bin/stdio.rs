@@ -0,0 +1,61 @@
+//! Stdio MCP binary for desktop clients
+//!
+//! Runs the same tools as the HTTP server over stdin/stdout (newline-delimited
+//! JSON-RPC). The Miro access token comes from the environment instead of an
+//! HTTP bearer header:
+//! - MIRO_ACCESS_TOKEN=<token>, or
+//! - MIRO_TOKEN_FILE=/path/to/token
+//!
+//! Logs go to stderr; stdout carries protocol messages only. Clients can also
+//! receive them as `notifications/message` after `logging/setLevel`.
+//! MCP_RESOURCE_POLL_INTERVAL_SECS sets how often subscribed boards are polled;
+//! MCP_PROMPTS_DIR adds prompt templates.
+
+use miro_mcp_server::config::McpConfig;
+use miro_mcp_server::mcp::logging;
+use miro_mcp_server::mcp::prompts::PromptLibrary;
+use miro_mcp_server::mcp::stdio;
+use miro_mcp_server::mcp::McpHandler;
+use miro_mcp_server::miro::MiroHttpClient;
+use std::time::Duration;
+use tracing::info;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, Layer};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let env_filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| "miro_mcp_server=info".into());
+    // The filter applies to stderr only; clients pick their level with logging/setLevel
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(std::io::stderr)
+                .with_filter(env_filter),
+        )
+        .with(logging::layer())
+        .init();
+
+    let token = stdio::load_token()?;
+    let poll_interval_secs = match std::env::var("MCP_RESOURCE_POLL_INTERVAL_SECS") {
+        Ok(secs) => secs.trim().parse()?,
+        Err(_) => McpConfig::default().resource_poll_interval_secs,
+    };
+    let mut prompts = PromptLibrary::builtin();
+    if let Some(dir) = std::env::var_os("MCP_PROMPTS_DIR") {
+        prompts = prompts.with_dir(dir.as_ref())?;
+    }
+    let handler = McpHandler::with_miro_http(MiroHttpClient::default()).with_prompts(prompts);
+
+    info!("Starting MCP stdio server");
+    stdio::serve(
+        &handler,
+        &token,
+        &stdio::local_user(),
+        Duration::from_secs(poll_interval_secs),
+        tokio::io::stdin(),
+        tokio::io::stdout(),
+    )
+    .await?;
+
+    Ok(())
+}
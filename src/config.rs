@@ -47,6 +47,159 @@ struct ConfigFile {
     /// Base URL for OAuth endpoints (e.g., https://your-server.com)
     #[serde(skip_serializing_if = "Option::is_none")]
     base_url: Option<String>,
+
+    /// JWKS endpoint for JWT signature verification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    jwks_url: Option<String>,
+
+    /// Local JWKS file for JWT signature verification (alternative to jwks_url)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    jwks_file: Option<PathBuf>,
+
+    /// Expected JWT issuer (iss claim)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    jwt_issuer: Option<String>,
+
+    /// Token validation strategy (jwt, introspection, jwt_then_introspection)
+    #[serde(default)]
+    token_validation_mode: TokenValidationMode,
+
+    /// RFC 7662 introspection endpoint (defaults to Miro's)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    introspection_url: Option<String>,
+
+    /// Client ID used to authenticate introspection requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    introspection_client_id: Option<String>,
+
+    /// Client secret used to authenticate introspection requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    introspection_client_secret: Option<String>,
+
+    /// Outbound Miro API connection settings
+    #[serde(default)]
+    miro_http: MiroHttpConfig,
+
+    /// MCP protocol settings
+    #[serde(default)]
+    mcp: McpConfig,
+}
+
+/// How access tokens are validated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenValidationMode {
+    /// Verify tokens locally as signed JWTs
+    #[default]
+    Jwt,
+    /// Ask the authorization server via RFC 7662 introspection (opaque tokens)
+    Introspection,
+    /// Try JWT validation first, then fall back to introspection
+    JwtThenIntrospection,
+}
+
+impl std::str::FromStr for TokenValidationMode {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "jwt" => Ok(Self::Jwt),
+            "introspection" => Ok(Self::Introspection),
+            "jwt_then_introspection" => Ok(Self::JwtThenIntrospection),
+            other => Err(ConfigError::ParseError(format!(
+                "Unknown token validation mode '{}' (expected jwt, introspection or jwt_then_introspection)",
+                other
+            ))),
+        }
+    }
+}
+
+/// Access token validation settings
+#[derive(Debug, Clone, Default)]
+pub struct TokenValidationConfig {
+    /// JWKS endpoint URL (e.g., https://auth.example.com/.well-known/jwks.json)
+    pub jwks_url: Option<String>,
+
+    /// Local JWKS file path, used when `jwks_url` is not set
+    pub jwks_file: Option<PathBuf>,
+
+    /// Expected issuer (iss claim); not checked when unset
+    pub issuer: Option<String>,
+
+    /// Validation strategy
+    pub mode: TokenValidationMode,
+
+    /// Introspection endpoint; Miro's endpoint is used when unset
+    pub introspection_url: Option<String>,
+
+    /// Client ID for introspection requests (HTTP Basic), if required
+    pub introspection_client_id: Option<String>,
+
+    /// Client secret for introspection requests
+    pub introspection_client_secret: Option<String>,
+}
+
+/// Outbound HTTP settings for Miro API calls
+///
+/// One connection pool is shared by all requests; these control its
+/// timeouts and keep-alive behavior.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MiroHttpConfig {
+    /// Total time allowed per Miro API request, in seconds
+    pub timeout_secs: u64,
+
+    /// Time allowed to establish a connection, in seconds
+    pub connect_timeout_secs: u64,
+
+    /// How long idle pooled connections stay open, in seconds
+    pub pool_idle_timeout_secs: u64,
+
+    /// Maximum idle connections kept per host
+    pub pool_max_idle_per_host: usize,
+
+    /// TCP and HTTP/2 keep-alive interval, in seconds
+    pub tcp_keepalive_secs: u64,
+}
+
+impl Default for MiroHttpConfig {
+    fn default() -> Self {
+        Self {
+            timeout_secs: 30,
+            connect_timeout_secs: 10,
+            pool_idle_timeout_secs: 90,
+            pool_max_idle_per_host: 32,
+            tcp_keepalive_secs: 60,
+        }
+    }
+}
+
+/// MCP protocol behavior
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct McpConfig {
+    /// How often subscribed boards are polled for changes, in seconds
+    pub resource_poll_interval_secs: u64,
+
+    /// Directory of extra prompt templates (`*.md`), loaded at startup
+    pub prompts_dir: Option<PathBuf>,
+
+    /// HTTP sessions without requests or an open stream expire after this, in seconds
+    pub session_idle_timeout_secs: u64,
+
+    /// Notifications kept per session for `Last-Event-ID` replay
+    pub event_buffer_size: usize,
+}
+
+impl Default for McpConfig {
+    fn default() -> Self {
+        Self {
+            resource_poll_interval_secs: 15,
+            prompts_dir: None,
+            session_idle_timeout_secs: 1800,
+            event_buffer_size: 100,
+        }
+    }
 }
 
 /// Configuration for Miro MCP Server
@@ -73,6 +226,15 @@ pub struct Config {
     /// Base URL for OAuth proxy endpoints (e.g., https://your-server.com)
     /// Used to construct authorization_endpoint and token_endpoint in metadata
     pub base_url: Option<String>,
+
+    /// Access token validation (JWKS source, issuer)
+    pub token_validation: TokenValidationConfig,
+
+    /// Miro API connection pool settings
+    pub miro_http: MiroHttpConfig,
+
+    /// MCP protocol settings (resource subscriptions)
+    pub mcp: McpConfig,
 }
 
 impl Config {
@@ -97,6 +259,14 @@ impl Config {
         // Validate redirect URI
         let _ = url::Url::parse(&config_file.redirect_uri)?;
 
+        if let Some(jwks_url) = &config_file.jwks_url {
+            let _ = url::Url::parse(jwks_url)?;
+        }
+
+        if let Some(introspection_url) = &config_file.introspection_url {
+            let _ = url::Url::parse(introspection_url)?;
+        }
+
         // Parse encryption key from hex (use dummy value if not provided for ADR-005)
         let encryption_key = match config_file.encryption_key {
             Some(key_hex) => Self::parse_encryption_key(&key_hex)?,
@@ -110,6 +280,17 @@ impl Config {
             encryption_key,
             port: config_file.port,
             base_url: config_file.base_url,
+            token_validation: TokenValidationConfig {
+                jwks_url: config_file.jwks_url,
+                jwks_file: config_file.jwks_file,
+                issuer: config_file.jwt_issuer,
+                mode: config_file.token_validation_mode,
+                introspection_url: config_file.introspection_url,
+                introspection_client_id: config_file.introspection_client_id,
+                introspection_client_secret: config_file.introspection_client_secret,
+            },
+            miro_http: config_file.miro_http,
+            mcp: config_file.mcp,
         })
     }
 
@@ -140,6 +321,13 @@ impl Config {
 
     /// Load configuration from environment variables
     /// Reads: MIRO_CLIENT_ID, MIRO_REDIRECT_URI, MCP_SERVER_PORT, BASE_URL
+    /// Token validation: JWKS_URL or JWKS_FILE, JWT_ISSUER, TOKEN_VALIDATION_MODE,
+    /// INTROSPECTION_URL, INTROSPECTION_CLIENT_ID, INTROSPECTION_CLIENT_SECRET
+    /// Miro HTTP pool: MIRO_HTTP_TIMEOUT_SECS, MIRO_HTTP_CONNECT_TIMEOUT_SECS,
+    /// MIRO_HTTP_POOL_IDLE_TIMEOUT_SECS, MIRO_HTTP_POOL_MAX_IDLE_PER_HOST,
+    /// MIRO_HTTP_TCP_KEEPALIVE_SECS
+    /// MCP: MCP_RESOURCE_POLL_INTERVAL_SECS, MCP_PROMPTS_DIR, MCP_SESSION_IDLE_TIMEOUT_SECS,
+    /// MCP_EVENT_BUFFER_SIZE
     /// Optional (for ADR-004 OAuth Proxy): MIRO_CLIENT_SECRET, MIRO_ENCRYPTION_KEY
     pub fn from_env_vars() -> Result<Self, ConfigError> {
         let client_id = std::env::var("MIRO_CLIENT_ID").map_err(|_| ConfigError::FileNotFound {
@@ -172,6 +360,68 @@ impl Config {
 
         let base_url = std::env::var("BASE_URL").ok();
 
+        let jwks_url = std::env::var("JWKS_URL").ok();
+        if let Some(url) = &jwks_url {
+            let _ = url::Url::parse(url)?;
+        }
+
+        let introspection_url = std::env::var("INTROSPECTION_URL").ok();
+        if let Some(url) = &introspection_url {
+            let _ = url::Url::parse(url)?;
+        }
+
+        let mode = match std::env::var("TOKEN_VALIDATION_MODE") {
+            Ok(mode) => mode.parse()?,
+            Err(_) => TokenValidationMode::default(),
+        };
+
+        let token_validation = TokenValidationConfig {
+            jwks_url,
+            jwks_file: std::env::var("JWKS_FILE").ok().map(PathBuf::from),
+            issuer: std::env::var("JWT_ISSUER").ok(),
+            mode,
+            introspection_url,
+            introspection_client_id: std::env::var("INTROSPECTION_CLIENT_ID").ok(),
+            introspection_client_secret: std::env::var("INTROSPECTION_CLIENT_SECRET").ok(),
+        };
+
+        let defaults = MiroHttpConfig::default();
+        let miro_http = MiroHttpConfig {
+            timeout_secs: env_number("MIRO_HTTP_TIMEOUT_SECS", defaults.timeout_secs)?,
+            connect_timeout_secs: env_number(
+                "MIRO_HTTP_CONNECT_TIMEOUT_SECS",
+                defaults.connect_timeout_secs,
+            )?,
+            pool_idle_timeout_secs: env_number(
+                "MIRO_HTTP_POOL_IDLE_TIMEOUT_SECS",
+                defaults.pool_idle_timeout_secs,
+            )?,
+            pool_max_idle_per_host: env_number(
+                "MIRO_HTTP_POOL_MAX_IDLE_PER_HOST",
+                defaults.pool_max_idle_per_host,
+            )?,
+            tcp_keepalive_secs: env_number(
+                "MIRO_HTTP_TCP_KEEPALIVE_SECS",
+                defaults.tcp_keepalive_secs,
+            )?,
+        };
+
+        let mcp = McpConfig {
+            resource_poll_interval_secs: env_number(
+                "MCP_RESOURCE_POLL_INTERVAL_SECS",
+                McpConfig::default().resource_poll_interval_secs,
+            )?,
+            prompts_dir: std::env::var("MCP_PROMPTS_DIR").ok().map(PathBuf::from),
+            session_idle_timeout_secs: env_number(
+                "MCP_SESSION_IDLE_TIMEOUT_SECS",
+                McpConfig::default().session_idle_timeout_secs,
+            )?,
+            event_buffer_size: env_number(
+                "MCP_EVENT_BUFFER_SIZE",
+                McpConfig::default().event_buffer_size,
+            )?,
+        };
+
         Ok(Config {
             client_id,
             client_secret,
@@ -179,6 +429,9 @@ impl Config {
             encryption_key,
             port,
             base_url,
+            token_validation,
+            miro_http,
+            mcp,
         })
     }
 
@@ -236,6 +489,16 @@ impl Config {
     }
 }
 
+/// Read a numeric environment variable, falling back to `default` when unset
+fn env_number<T: std::str::FromStr>(name: &str, default: T) -> Result<T, ConfigError> {
+    match std::env::var(name) {
+        Ok(value) => value.trim().parse().map_err(|_| {
+            ConfigError::ParseError(format!("{} must be a number, got '{}'", name, value))
+        }),
+        Err(_) => Ok(default),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -329,6 +592,112 @@ mod tests {
         std::env::remove_var("MIRO_ENCRYPTION_KEY");
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_from_env_vars_token_validation() {
+        std::env::set_var("MIRO_CLIENT_ID", "test_client_id");
+        std::env::set_var("MIRO_REDIRECT_URI", "http://localhost:3000/callback");
+        std::env::set_var("JWKS_URL", "https://auth.example.com/.well-known/jwks.json");
+        std::env::set_var("JWT_ISSUER", "https://auth.example.com");
+
+        let config = Config::from_env_vars().unwrap();
+        assert_eq!(
+            config.token_validation.jwks_url.as_deref(),
+            Some("https://auth.example.com/.well-known/jwks.json")
+        );
+        assert_eq!(
+            config.token_validation.issuer.as_deref(),
+            Some("https://auth.example.com")
+        );
+        assert!(config.token_validation.jwks_file.is_none());
+
+        std::env::set_var("JWKS_URL", "not a url");
+        assert!(Config::from_env_vars().is_err());
+
+        // Cleanup
+        std::env::remove_var("MIRO_CLIENT_ID");
+        std::env::remove_var("MIRO_REDIRECT_URI");
+        std::env::remove_var("JWKS_URL");
+        std::env::remove_var("JWT_ISSUER");
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_from_env_vars_introspection_mode() {
+        std::env::set_var("MIRO_CLIENT_ID", "test_client_id");
+        std::env::set_var("MIRO_REDIRECT_URI", "http://localhost:3000/callback");
+
+        let config = Config::from_env_vars().unwrap();
+        assert_eq!(config.token_validation.mode, TokenValidationMode::Jwt);
+
+        std::env::set_var("TOKEN_VALIDATION_MODE", "jwt_then_introspection");
+        std::env::set_var("INTROSPECTION_URL", "https://auth.example.com/introspect");
+        std::env::set_var("INTROSPECTION_CLIENT_ID", "rs-client");
+        let config = Config::from_env_vars().unwrap();
+        assert_eq!(
+            config.token_validation.mode,
+            TokenValidationMode::JwtThenIntrospection
+        );
+        assert_eq!(
+            config.token_validation.introspection_url.as_deref(),
+            Some("https://auth.example.com/introspect")
+        );
+        assert_eq!(
+            config.token_validation.introspection_client_id.as_deref(),
+            Some("rs-client")
+        );
+
+        std::env::set_var("TOKEN_VALIDATION_MODE", "magic");
+        assert!(Config::from_env_vars().is_err());
+
+        // Cleanup
+        std::env::remove_var("MIRO_CLIENT_ID");
+        std::env::remove_var("MIRO_REDIRECT_URI");
+        std::env::remove_var("TOKEN_VALIDATION_MODE");
+        std::env::remove_var("INTROSPECTION_URL");
+        std::env::remove_var("INTROSPECTION_CLIENT_ID");
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_from_env_vars_miro_http_pool() {
+        std::env::set_var("MIRO_CLIENT_ID", "test_client_id");
+        std::env::set_var("MIRO_REDIRECT_URI", "http://localhost:3000/callback");
+
+        let config = Config::from_env_vars().unwrap();
+        assert_eq!(config.miro_http, MiroHttpConfig::default());
+
+        std::env::set_var("MIRO_HTTP_TIMEOUT_SECS", "15");
+        std::env::set_var("MIRO_HTTP_POOL_MAX_IDLE_PER_HOST", "8");
+        let config = Config::from_env_vars().unwrap();
+        assert_eq!(config.miro_http.timeout_secs, 15);
+        assert_eq!(config.miro_http.pool_max_idle_per_host, 8);
+        assert_eq!(config.miro_http.tcp_keepalive_secs, 60);
+
+        std::env::set_var("MIRO_HTTP_TIMEOUT_SECS", "soon");
+        assert!(Config::from_env_vars().is_err());
+
+        // Cleanup
+        std::env::remove_var("MIRO_CLIENT_ID");
+        std::env::remove_var("MIRO_REDIRECT_URI");
+        std::env::remove_var("MIRO_HTTP_TIMEOUT_SECS");
+        std::env::remove_var("MIRO_HTTP_POOL_MAX_IDLE_PER_HOST");
+    }
+
+    #[test]
+    fn test_config_file_miro_http_defaults() {
+        let file: ConfigFile = serde_json::from_value(serde_json::json!({
+            "client_id": "id",
+            "redirect_uri": "http://localhost:3000/callback",
+            "port": 3000,
+            "miro_http": { "pool_max_idle_per_host": 4 }
+        }))
+        .unwrap();
+        assert_eq!(file.miro_http.pool_max_idle_per_host, 4);
+        assert_eq!(file.miro_http.timeout_secs, 30);
+        assert_eq!(file.mcp, McpConfig::default());
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_from_env_vars_invalid_redirect_uri() {
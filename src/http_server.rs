@@ -1,15 +1,35 @@
-use crate::auth::{ProtectedResourceMetadata, TokenValidator};
+use crate::auth::challenge::RESOURCE_METADATA_PATH;
+use crate::auth::{
+    extract_bearer_token, AuthError, BearerChallenge, BearerToken, ProtectedResourceMetadata,
+    TokenValidator, UserInfo,
+};
 use crate::config::Config;
+use crate::mcp::notifier;
+use crate::mcp::prompts::PromptLibrary;
+use crate::mcp::protocol::{error_codes, JsonRpcRequest};
+use crate::mcp::session::{
+    NotificationStream, Session, SessionStore, LAST_EVENT_ID_HEADER, SESSION_ID_HEADER,
+};
+use crate::mcp::{parse_request, McpHandler};
+use crate::miro::{MiroClientConfig, MiroHttpClient};
 use axum::{
+    body::Bytes,
     extract::State,
-    http::{Request, StatusCode},
+    http::{header, HeaderMap, HeaderValue, Request, StatusCode},
     middleware::{self, Next},
-    response::{IntoResponse, Response},
-    routing::get,
-    Json, Router,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
+    routing::{get, post},
+    Extension, Json, Router,
 };
+use futures::stream::{self, StreamExt};
+use serde_json::json;
+use std::convert::Infallible;
 use std::sync::Arc;
-use tracing::info;
+use std::time::Duration;
+use tracing::{info, warn, Instrument};
 use uuid::Uuid;
 
 // OAuth proxy removed in ADR-005 (Resource Server pattern)
@@ -20,14 +40,18 @@ async fn health_check() -> impl IntoResponse {
     (StatusCode::OK, "OK")
 }
 
+/// Resource URL advertised in metadata and WWW-Authenticate challenges
+fn resource_url(config: &Config) -> String {
+    config
+        .base_url
+        .clone()
+        .unwrap_or_else(|| "https://miro-mcp.example.com".to_string())
+}
+
 /// Protected Resource Metadata endpoint (RFC 9728)
 /// Advertises OAuth authorization server and resource capabilities
 async fn protected_resource_metadata(State(config): State<Arc<Config>>) -> impl IntoResponse {
-    let base_url = config
-        .base_url
-        .clone()
-        .unwrap_or_else(|| "https://miro-mcp.example.com".to_string());
-    let metadata = ProtectedResourceMetadata::new_for_miro(base_url);
+    let metadata = ProtectedResourceMetadata::new_for_miro(resource_url(&config));
     Json(metadata)
 }
 
@@ -60,14 +84,15 @@ async fn correlation_id_middleware(mut request: Request<axum::body::Body>, next:
         .extensions_mut()
         .insert(RequestId(request_id.clone()));
 
-    // Execute request within the span
-    let _enter = span.enter();
-
-    info!("Request started");
-    let response = next.run(request).await;
-    info!("Request completed");
-
-    response
+    // Execute request within the span (instrumented, since the handler awaits)
+    async move {
+        info!("Request started");
+        let response = next.run(request).await;
+        info!("Request completed");
+        response
+    }
+    .instrument(span)
+    .await
 }
 
 /// Application state for ADR-005 Resource Server pattern
@@ -76,6 +101,331 @@ async fn correlation_id_middleware(mut request: Request<axum::body::Body>, next:
 pub struct AppStateADR002 {
     pub token_validator: Arc<TokenValidator>,
     pub config: Arc<Config>,
+    pub mcp_handler: Arc<McpHandler>,
+    pub sessions: Arc<SessionStore>,
+}
+
+/// Build the 401/403 response for an authentication failure
+///
+/// Carries an RFC 6750 / RFC 9728 `WWW-Authenticate` challenge so clients can
+/// discover the authorization server and re-authenticate.
+fn auth_error_response(config: &Config, error: &AuthError) -> Response {
+    let challenge = BearerChallenge::from_auth_error(&resource_url(config), error);
+    let body = Json(json!({
+        "error": challenge.error.unwrap_or("unauthorized"),
+        "error_description": challenge
+            .error_description
+            .clone()
+            .unwrap_or_else(|| error.to_string()),
+    }));
+
+    (
+        challenge.status_code(),
+        [(header::WWW_AUTHENTICATE, challenge.to_header_value())],
+        body,
+    )
+        .into_response()
+}
+
+/// Bearer token authentication middleware for protected routes
+///
+/// Extracts and validates the Bearer token, then stores `UserInfo` and the raw
+/// `BearerToken` in request extensions. Rejected requests never reach handlers.
+async fn bearer_auth_middleware(
+    State(state): State<AppStateADR002>,
+    mut request: Request<axum::body::Body>,
+    next: Next,
+) -> Response {
+    let token = match extract_bearer_token(request.headers()) {
+        Ok(token) => token,
+        Err(e) => {
+            warn!(error = %e, "Request without valid Bearer token");
+            return auth_error_response(&state.config, &e);
+        }
+    };
+
+    let user_info = match state.token_validator.validate(&token).await {
+        Ok(user_info) => user_info,
+        Err(e) => {
+            warn!(error = %e, "Bearer token rejected");
+            return auth_error_response(&state.config, &e);
+        }
+    };
+
+    request.extensions_mut().insert(user_info);
+    request.extensions_mut().insert(BearerToken(token));
+
+    next.run(request).await
+}
+
+/// Resolve the `Mcp-Session-Id` header of a request
+///
+/// `Ok(None)` when the header is absent; a 404 response when the session is
+/// unknown or belongs to another user (MCP: the client must re-initialize).
+fn request_session(
+    state: &AppStateADR002,
+    headers: &HeaderMap,
+    user_info: &UserInfo,
+) -> Result<Option<Arc<Session>>, Box<Response>> {
+    let Some(id) = headers.get(SESSION_ID_HEADER) else {
+        return Ok(None);
+    };
+
+    id.to_str()
+        .ok()
+        .and_then(|id| state.sessions.get(id, &user_info.user_id))
+        .map(Some)
+        .ok_or_else(|| {
+            warn!(user_id = %user_info.user_id, "Unknown MCP session");
+            Box::new(
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({
+                        "error": "session_not_found",
+                        "error_description": "Unknown or expired MCP session - initialize again",
+                    })),
+                )
+                    .into_response(),
+            )
+        })
+}
+
+/// MCP Streamable HTTP endpoint (JSON-RPC 2.0 over POST)
+///
+/// Requests get a JSON response; notifications get 202 Accepted with no body.
+/// A successful `initialize` starts a session and returns its id in the
+/// `Mcp-Session-Id` header.
+async fn mcp_endpoint(
+    State(state): State<AppStateADR002>,
+    Extension(token): Extension<BearerToken>,
+    Extension(user_info): Extension<UserInfo>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let request = match parse_request(&body) {
+        Ok(request) => request,
+        Err(error_response) => return Json(*error_response).into_response(),
+    };
+
+    // Missing scopes get a 403 challenge so the client can request them
+    if let Err(e) = state.mcp_handler.authorize(&request, &user_info) {
+        warn!(error = %e, user_id = %user_info.user_id, "MCP request lacks scope");
+        return auth_error_response(&state.config, &e);
+    }
+
+    let session = match request_session(&state, &headers, &user_info) {
+        Ok(session) => session,
+        Err(response) => return *response,
+    };
+    let is_initialize = request.method == "initialize";
+
+    if wants_progress_stream(&request, &headers) {
+        return progress_stream_response(state, request, token, user_info, session);
+    }
+
+    let response = match &session {
+        Some(session) => {
+            state
+                .mcp_handler
+                .handle_in_session(request, &token.0, &user_info, session)
+                .await
+        }
+        None => {
+            state
+                .mcp_handler
+                .handle(request, &token.0, &user_info)
+                .await
+        }
+    };
+
+    let Some(response) = response else {
+        return StatusCode::ACCEPTED.into_response();
+    };
+
+    // Miro rejected the token: a 401 challenge makes the client re-authenticate
+    if response
+        .error
+        .as_ref()
+        .is_some_and(|e| e.code == error_codes::UNAUTHORIZED)
+    {
+        warn!(user_id = %user_info.user_id, "Miro rejected the access token");
+        return auth_error_response(&state.config, &AuthError::TokenInvalid);
+    }
+
+    let mut http_response = Json(&response).into_response();
+    if is_initialize && session.is_none() && response.error.is_none() {
+        let session = state.sessions.create(&user_info.user_id);
+        info!(session_id = %session.id(), user_id = %user_info.user_id, "MCP session started");
+        if let Ok(value) = HeaderValue::from_str(session.id()) {
+            http_response.headers_mut().insert(SESSION_ID_HEADER, value);
+        }
+    }
+    http_response
+}
+
+/// Whether to answer with an SSE stream carrying progress before the result
+///
+/// Only tool calls that asked for progress, from clients that accept
+/// `text/event-stream`; everyone else gets a plain JSON response.
+fn wants_progress_stream(request: &JsonRpcRequest, headers: &HeaderMap) -> bool {
+    let accepts_sse = headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .any(|value| value.contains("text/event-stream"));
+    let has_progress_token = request
+        .params
+        .as_ref()
+        .and_then(|p| p.pointer("/_meta/progressToken"))
+        .is_some_and(|token| !token.is_null());
+
+    request.method == "tools/call" && request.id.is_some() && accepts_sse && has_progress_token
+}
+
+/// Run a request in the background and stream its progress, then its response
+///
+/// The call keeps running if the client disconnects; clients stop it with
+/// `notifications/cancelled`. The 200 status is sent up front, so a token
+/// Miro rejects mid-call arrives as a JSON-RPC `UNAUTHORIZED` error instead
+/// of a 401.
+fn progress_stream_response(
+    state: AppStateADR002,
+    request: JsonRpcRequest,
+    token: BearerToken,
+    user_info: UserInfo,
+    session: Option<Arc<Session>>,
+) -> Response {
+    let (notifier, receiver) = notifier::channel(state.config.mcp.event_buffer_size);
+    let handler = state.mcp_handler.clone();
+    let call = tokio::spawn(
+        async move {
+            handler
+                .handle_with_progress(request, &token.0, &user_info, session.as_deref(), &notifier)
+                .await
+        }
+        .in_current_span(),
+    );
+
+    // The channel closes when the call finishes, after its last progress
+    let progress = stream::unfold(receiver, |mut receiver| async move {
+        let notification = receiver.recv().await?;
+        Some((sse_message(&notification), receiver))
+    });
+    let result = stream::once(async move {
+        match call.await {
+            Ok(Some(response)) => sse_message(&response),
+            Ok(None) => Event::default().comment("no response"),
+            Err(e) => {
+                warn!(error = %e, "MCP tool call task failed");
+                Event::default().comment("tool call failed")
+            }
+        }
+    });
+
+    Sse::new(progress.chain(result).map(Ok::<_, Infallible>))
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+fn sse_message<T: serde::Serialize>(message: &T) -> Event {
+    Event::default()
+        .event("message")
+        .json_data(message)
+        .unwrap_or_else(|_| Event::default().comment("unserializable message"))
+}
+
+/// Server-to-client SSE stream of a session (`GET /mcp`)
+///
+/// Carries `notifications/resources/updated` for the session's resource
+/// subscriptions. One stream per session; a second concurrent stream gets
+/// 409 Conflict. Events carry ids, and a reconnect with `Last-Event-ID`
+/// first replays buffered events the client missed.
+async fn mcp_sse_endpoint(
+    State(state): State<AppStateADR002>,
+    Extension(user_info): Extension<UserInfo>,
+    headers: HeaderMap,
+) -> Response {
+    let session = match request_session(&state, &headers, &user_info) {
+        Ok(Some(session)) => session,
+        Ok(None) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({
+                    "error": "invalid_request",
+                    "error_description": "Mcp-Session-Id header required",
+                })),
+            )
+                .into_response()
+        }
+        Err(response) => return *response,
+    };
+
+    // Resume after the last event the client saw (SSE reconnection)
+    let last_event_id = headers
+        .get(LAST_EVENT_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok());
+
+    let Some(stream) = NotificationStream::open(session.clone(), last_event_id) else {
+        return (
+            StatusCode::CONFLICT,
+            Json(json!({
+                "error": "stream_already_open",
+                "error_description": "This session already has an open SSE stream",
+            })),
+        )
+            .into_response();
+    };
+
+    info!(session_id = %session.id(), "MCP SSE stream opened");
+    let events = stream
+        .map(|event| Ok::<_, Infallible>(sse_message(&event.message).id(event.id.to_string())));
+
+    Sse::new(events)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+/// Terminate a session (`DELETE /mcp`)
+///
+/// Stops its subscriptions and closes its SSE stream; later requests with the
+/// session id get 404.
+async fn mcp_delete_session(
+    State(state): State<AppStateADR002>,
+    Extension(user_info): Extension<UserInfo>,
+    headers: HeaderMap,
+) -> Response {
+    let Some(id) = headers
+        .get(SESSION_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(json!({
+                "error": "invalid_request",
+                "error_description": "Mcp-Session-Id header required",
+            })),
+        )
+            .into_response();
+    };
+
+    match state.sessions.remove(id, &user_info.user_id) {
+        Some(session) => {
+            info!(session_id = %session.id(), user_id = %user_info.user_id, "MCP session terminated");
+            StatusCode::NO_CONTENT.into_response()
+        }
+        None => {
+            warn!(user_id = %user_info.user_id, "Delete for unknown MCP session");
+            (
+                StatusCode::NOT_FOUND,
+                Json(json!({
+                    "error": "session_not_found",
+                    "error_description": "Unknown or expired MCP session",
+                })),
+            )
+                .into_response()
+        }
+    }
 }
 
 /// Create HTTP server for ADR-005 Resource Server pattern
@@ -84,24 +434,60 @@ pub struct AppStateADR002 {
 /// - Protected Resource Metadata endpoint (RFC 9728)
 /// - Bearer token authentication with JWT validation
 /// - MCP protocol endpoints
+/// - Idle session reaper (spawned, so this must run inside a Tokio runtime)
 pub fn create_app_adr002(token_validator: Arc<TokenValidator>, config: Arc<Config>) -> Router {
+    // One Miro connection pool for all callers; requests only add their token
+    let miro_http =
+        MiroHttpClient::new(MiroClientConfig::from(&config.miro_http)).unwrap_or_else(|e| {
+            warn!(error = %e, "Invalid Miro HTTP settings - using defaults");
+            MiroHttpClient::default()
+        });
+
+    let mut prompts = PromptLibrary::builtin();
+    if let Some(dir) = &config.mcp.prompts_dir {
+        match prompts.clone().with_dir(dir) {
+            Ok(loaded) => prompts = loaded,
+            Err(e) => warn!(error = %e, "Prompt templates not loaded - serving built-ins only"),
+        }
+    }
+
+    let sessions = SessionStore::new(Duration::from_secs(config.mcp.resource_poll_interval_secs))
+        .with_idle_timeout(Duration::from_secs(config.mcp.session_idle_timeout_secs))
+        .with_event_buffer(config.mcp.event_buffer_size);
+    let sessions = Arc::new(sessions);
+    SessionStore::spawn_reaper(&sessions);
+
     let state = AppStateADR002 {
         token_validator,
         config,
+        mcp_handler: Arc::new(McpHandler::with_miro_http(miro_http).with_prompts(prompts)),
+        sessions,
     };
 
     // Public routes (no authentication required)
     let public_routes = Router::new()
         .route("/health", get(health_check))
+        .route(RESOURCE_METADATA_PATH, get(protected_resource_metadata))
+        .with_state(state.config.clone());
+
+    // Protected routes (Bearer token required)
+    let protected_routes = Router::new()
         .route(
-            "/.well-known/oauth-protected-resource",
-            get(protected_resource_metadata),
+            "/mcp",
+            post(mcp_endpoint)
+                .get(mcp_sse_endpoint)
+                .delete(mcp_delete_session),
         )
-        .with_state(state.config.clone());
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            bearer_auth_middleware,
+        ))
+        .with_state(state);
 
     // Apply correlation ID middleware to ALL requests
     Router::new()
         .merge(public_routes)
+        .merge(protected_routes)
         .layer(middleware::from_fn(correlation_id_middleware))
 }
 
@@ -139,8 +525,8 @@ pub async fn run_server_adr002(
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_create_app_adr002() {
+    #[tokio::test]
+    async fn test_create_app_adr002() {
         // Create test-specific Config instead of loading from environment/file
         let config = Arc::new(Config {
             client_id: "test_client_id".to_string(),
@@ -149,6 +535,9 @@ mod tests {
             encryption_key: [0u8; 32],
             port: 3000,
             base_url: Some("https://test.example.com".to_string()),
+            token_validation: Default::default(),
+            miro_http: Default::default(),
+            mcp: Default::default(),
         });
 
         let token_validator = Arc::new(TokenValidator::new(
@@ -1,7 +1,10 @@
 pub mod auth;
 pub mod config;
 pub mod http_server;
+pub mod mcp;
 pub mod miro;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
 
 pub use auth::{AuthError, TokenValidator, UserInfo};
 pub use config::Config;
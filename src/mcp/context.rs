@@ -0,0 +1,98 @@
+//! Per-call context handed to tool handlers: progress and cancellation
+
+use crate::mcp::notifier::Notifier;
+use crate::mcp::protocol::JsonRpcRequest;
+use serde_json::{json, Value};
+use tokio_util::sync::CancellationToken;
+
+/// Emits `notifications/progress` for one request
+///
+/// Inert unless the client sent a `progressToken` and the transport can
+/// deliver notifications, so handlers can report unconditionally.
+#[derive(Debug, Clone, Default)]
+pub struct ProgressReporter {
+    target: Option<(Value, Notifier)>,
+}
+
+impl ProgressReporter {
+    pub fn new(progress_token: Option<Value>, notifier: Option<Notifier>) -> Self {
+        Self {
+            target: progress_token.zip(notifier),
+        }
+    }
+
+    /// Whether reports reach a client
+    pub fn is_enabled(&self) -> bool {
+        self.target.is_some()
+    }
+
+    /// Report progress; `progress` must increase with every call
+    pub fn report(&self, progress: f64, total: Option<f64>, message: Option<&str>) {
+        let Some((token, notifier)) = &self.target else {
+            return;
+        };
+
+        let mut params = json!({ "progressToken": token, "progress": progress });
+        if let Some(total) = total {
+            params["total"] = json!(total);
+        }
+        if let Some(message) = message {
+            params["message"] = json!(message);
+        }
+        // A closed channel means the client went away; nothing left to tell it
+        let _ = notifier.send(JsonRpcRequest::notification(
+            "notifications/progress",
+            params,
+        ));
+    }
+}
+
+/// What a tool handler gets besides its arguments
+#[derive(Debug, Clone, Default)]
+pub struct ToolContext {
+    pub progress: ProgressReporter,
+    /// Cancelled when the client sends `notifications/cancelled` for the call
+    pub cancellation: CancellationToken,
+}
+
+impl ToolContext {
+    pub fn new(progress: ProgressReporter, cancellation: CancellationToken) -> Self {
+        Self {
+            progress,
+            cancellation,
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation.is_cancelled()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::notifier;
+
+    #[test]
+    fn test_progress_needs_token_and_channel() {
+        let (notifier, mut receiver) = notifier::channel(8);
+
+        ProgressReporter::new(None, Some(notifier.clone())).report(1.0, None, None);
+        assert!(!ProgressReporter::new(Some(json!("t")), None).is_enabled());
+        assert!(receiver.try_recv().is_none());
+
+        let reporter = ProgressReporter::new(Some(json!("t")), Some(notifier));
+        reporter.report(20.0, Some(50.0), Some("Created 20 of 50 items"));
+        let notification = receiver.try_recv().unwrap();
+        assert_eq!(notification.method, "notifications/progress");
+        assert_eq!(
+            notification.params.unwrap(),
+            json!({
+                "progressToken": "t",
+                "progress": 20.0,
+                "total": 50.0,
+                "message": "Created 20 of 50 items"
+            })
+        );
+    }
+}
@@ -0,0 +1,324 @@
+//! How Miro API failures surface over MCP
+//!
+//! | `MiroError`                                   | MCP                                             |
+//! |-----------------------------------------------|-------------------------------------------------|
+//! | `ApiError`, `Forbidden`, `NotFound`, `Conflict`, `RateLimitExceeded`, `HttpError`, `BulkOperationError` | `isError` tool result with a structured [`ToolError`] |
+//! | `BulkCreatePartial`                           | As its cause, plus the items already created as `structuredContent.created` |
+//! | `Unauthorized`, `AuthError`                   | JSON-RPC `UNAUTHORIZED`; HTTP answers 401 so the client re-authenticates |
+//! | `Cancelled`                                   | JSON-RPC `REQUEST_CANCELLED`                    |
+//! | `InvalidInput`                                | JSON-RPC `INVALID_PARAMS`                       |
+//! | `JsonError`, `Internal`                       | JSON-RPC `INTERNAL_ERROR`                       |
+//!
+//! Tool arguments that fail schema validation or deserialization never reach
+//! Miro; they too become `isError` results, of kind `invalid_arguments`.
+//!
+//! Methods without tool results (`resources/*`) report API failures as
+//! JSON-RPC `MIRO_API_ERROR` with the same [`ToolError`] as `data`.
+
+use crate::mcp::protocol::{CallToolResult, Content, JsonRpcError};
+use crate::mcp::schema::FieldError;
+use crate::miro::{MiroApiError, MiroError, MiroFieldError};
+use serde::Serialize;
+use serde_json::json;
+
+/// Broad class of a failed tool call, for the model to branch on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolErrorKind {
+    /// The arguments do not match the tool's input schema; Miro was not called
+    InvalidArguments,
+    /// Miro rejected the arguments (400, 422) or a bulk request was invalid
+    Validation,
+    /// The token lacks access to the board or item (403)
+    Forbidden,
+    /// Board or item does not exist (404)
+    NotFound,
+    /// The change conflicts with the current board state (409)
+    Conflict,
+    /// Rate limit still exceeded after retries (429)
+    RateLimited,
+    /// Any other Miro API failure, including 5xx
+    Api,
+    /// Miro could not be reached or timed out
+    Network,
+}
+
+/// Structured details of a failed tool call
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ToolError {
+    pub kind: ToolErrorKind,
+    /// HTTP status returned by Miro, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// Miro's error code (e.g. `invalidParameters`), if the body had one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    pub message: String,
+    /// Whether repeating the same call may succeed
+    pub retryable: bool,
+    /// Suggested wait before retrying, in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_after_secs: Option<u64>,
+    /// Invalid fields, reported by Miro or found by schema validation
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<MiroFieldError>,
+}
+
+impl ToolError {
+    /// Classify a Miro failure; `None` for auth, cancellation, local and internal errors
+    pub fn from_miro(error: &MiroError) -> Option<Self> {
+        let tool_error = match error {
+            MiroError::Forbidden(e) => Self::api(ToolErrorKind::Forbidden, e),
+            MiroError::NotFound(e) => Self::api(ToolErrorKind::NotFound, e),
+            MiroError::Conflict(e) => Self::api(ToolErrorKind::Conflict, e),
+            MiroError::ApiError(e) => {
+                let kind = match e.status {
+                    400 | 422 => ToolErrorKind::Validation,
+                    429 => ToolErrorKind::RateLimited,
+                    _ => ToolErrorKind::Api,
+                };
+                Self::api(kind, e)
+            }
+            MiroError::RateLimitExceeded(info) => Self {
+                kind: ToolErrorKind::RateLimited,
+                status: Some(429),
+                code: None,
+                message: format!("Miro rate limit exceeded ({})", info),
+                retryable: true,
+                retry_after_secs: info.wait_hint().map(|wait| wait.as_secs().max(1)),
+                fields: Vec::new(),
+            },
+            MiroError::HttpError(e) => Self {
+                kind: ToolErrorKind::Network,
+                status: e.status().map(|s| s.as_u16()),
+                code: None,
+                message: format!("Miro API unreachable: {}", e),
+                retryable: true,
+                retry_after_secs: None,
+                fields: Vec::new(),
+            },
+            MiroError::BulkOperationError(message) => Self {
+                kind: ToolErrorKind::Validation,
+                status: None,
+                code: None,
+                message: message.clone(),
+                retryable: false,
+                retry_after_secs: None,
+                fields: Vec::new(),
+            },
+            MiroError::BulkCreatePartial {
+                created,
+                total,
+                error,
+            } => {
+                let mut tool_error = Self::from_miro(error)?;
+                tool_error.message = format!(
+                    "{} after creating {} of {} items",
+                    tool_error.message,
+                    created.len(),
+                    total
+                );
+                tool_error
+            }
+            MiroError::Unauthorized
+            | MiroError::AuthError(_)
+            | MiroError::Cancelled
+            | MiroError::InvalidInput(_)
+            | MiroError::Internal(_)
+            | MiroError::JsonError(_) => return None,
+        };
+        Some(tool_error)
+    }
+
+    /// Arguments rejected before the tool ran, with every offending field
+    pub fn invalid_arguments(tool_name: &str, detail: Option<&str>, errors: &[FieldError]) -> Self {
+        let mut message = format!("Invalid arguments for {}", tool_name);
+        if let Some(detail) = detail {
+            message.push_str(": ");
+            message.push_str(detail);
+        }
+        Self {
+            kind: ToolErrorKind::InvalidArguments,
+            status: None,
+            code: None,
+            message,
+            retryable: false,
+            retry_after_secs: None,
+            fields: errors
+                .iter()
+                .map(|e| MiroFieldError {
+                    field: e.field.clone(),
+                    message: e.message.clone(),
+                    reason: None,
+                })
+                .collect(),
+        }
+    }
+
+    fn api(kind: ToolErrorKind, error: &MiroApiError) -> Self {
+        Self {
+            kind,
+            status: Some(error.status),
+            code: error.code.clone(),
+            message: error.message.clone(),
+            retryable: error.status == 429 || error.status >= 500,
+            retry_after_secs: None,
+            fields: error.fields().to_vec(),
+        }
+    }
+
+    /// What the model should do next
+    fn hint(&self) -> Option<String> {
+        match (self.retry_after_secs, self.retryable, self.kind) {
+            (Some(secs), _, _) => Some(format!("Retry after {}s.", secs)),
+            (None, true, _) => Some("Retry later.".to_string()),
+            (None, false, ToolErrorKind::Validation | ToolErrorKind::InvalidArguments) => {
+                Some("Fix the arguments before retrying.".to_string())
+            }
+            _ => None,
+        }
+    }
+
+    fn summary(&self) -> String {
+        let mut text = self.message.clone();
+        match (self.status, &self.code) {
+            (Some(status), Some(code)) => text.push_str(&format!(" (Miro {} {})", status, code)),
+            (Some(status), None) => text.push_str(&format!(" (Miro {})", status)),
+            _ => {}
+        }
+        for field in &self.fields {
+            text.push_str(&format!("; {}: {}", field.field, field.message));
+        }
+        if let Some(hint) = self.hint() {
+            text.push(' ');
+            text.push_str(&hint);
+        }
+        text
+    }
+
+    /// `isError` tool result with this error as `structuredContent.error`
+    pub fn into_result(self) -> CallToolResult {
+        CallToolResult {
+            content: vec![Content::Text {
+                text: self.summary(),
+            }],
+            structured_content: Some(json!({ "error": self })),
+            is_error: true,
+        }
+    }
+}
+
+/// Outcome of a tool call that failed in Miro
+///
+/// API and validation failures are results the model can act on; auth,
+/// cancellation and internal failures are protocol errors.
+pub fn tool_result(error: MiroError) -> Result<CallToolResult, JsonRpcError> {
+    match ToolError::from_miro(&error) {
+        Some(tool_error) => {
+            let mut result = tool_error.into_result();
+            if let (MiroError::BulkCreatePartial { created, .. }, Some(structured)) =
+                (&error, result.structured_content.as_mut())
+            {
+                structured["created"] = json!(created);
+            }
+            Ok(result)
+        }
+        None => Err(rpc_error(error)),
+    }
+}
+
+/// JSON-RPC error for a Miro failure outside a tool call
+pub fn rpc_error(error: MiroError) -> JsonRpcError {
+    match error {
+        MiroError::Unauthorized | MiroError::AuthError(_) => JsonRpcError::unauthorized(),
+        MiroError::Cancelled => JsonRpcError::request_cancelled(),
+        MiroError::JsonError(e) => {
+            JsonRpcError::internal_error(format!("Unexpected Miro API response: {}", e))
+        }
+        MiroError::InvalidInput(message) => JsonRpcError::invalid_params(message),
+        MiroError::BulkCreatePartial { error, .. } if ToolError::from_miro(&error).is_none() => {
+            rpc_error(*error)
+        }
+        MiroError::Internal(message) => JsonRpcError::internal_error(message),
+        other => {
+            let tool_error = ToolError::from_miro(&other).expect("API failures are classified");
+            JsonRpcError::miro_api_error(tool_error.summary())
+                .with_data(json!({ "error": tool_error }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::protocol::error_codes;
+    use crate::miro::RateLimitInfo;
+    use serde_json::Value;
+    use std::time::Duration;
+
+    #[test]
+    fn test_api_error_becomes_structured_result() {
+        let body = r#"{"status":400,"code":"invalidParameters","message":"Color is invalid","type":"error",
+            "context":{"fields":[{"field":"style.fillColor","message":"unknown color"}]}}"#;
+        let result = tool_result(MiroError::ApiError(MiroApiError::parse(400, body))).unwrap();
+
+        assert!(result.is_error);
+        let error = &result.structured_content.unwrap()["error"];
+        assert_eq!(error["kind"], "validation");
+        assert_eq!(error["status"], 400);
+        assert_eq!(error["code"], "invalidParameters");
+        assert_eq!(error["message"], "Color is invalid");
+        assert_eq!(error["retryable"], false);
+        assert_eq!(error["fields"][0]["field"], "style.fillColor");
+        let Content::Text { text } = &result.content[0];
+        assert_eq!(
+            text,
+            "Color is invalid (Miro 400 invalidParameters); style.fillColor: unknown color Fix the arguments before retrying."
+        );
+    }
+
+    #[test]
+    fn test_rate_limit_and_server_errors_are_retryable() {
+        let info = RateLimitInfo {
+            retry_after: Some(Duration::from_secs(7)),
+            ..Default::default()
+        };
+        let error = ToolError::from_miro(&MiroError::RateLimitExceeded(info)).unwrap();
+        assert_eq!(error.kind, ToolErrorKind::RateLimited);
+        assert_eq!(error.retry_after_secs, Some(7));
+
+        let error = ToolError::from_miro(&MiroError::ApiError(MiroApiError::parse(
+            503,
+            "upstream down",
+        )))
+        .unwrap();
+        assert_eq!((error.kind, error.retryable), (ToolErrorKind::Api, true));
+        assert_eq!(error.code, None);
+        assert_eq!(error.message, "upstream down");
+    }
+
+    #[test]
+    fn test_protocol_level_failures() {
+        let error = tool_result(MiroError::Unauthorized).unwrap_err();
+        assert_eq!(error.code, error_codes::UNAUTHORIZED);
+
+        let error = tool_result(MiroError::Cancelled).unwrap_err();
+        assert_eq!(error.code, error_codes::REQUEST_CANCELLED);
+
+        let json = serde_json::from_str::<Value>("{").unwrap_err();
+        let error = tool_result(MiroError::JsonError(json)).unwrap_err();
+        assert_eq!(error.code, error_codes::INTERNAL_ERROR);
+
+        let error =
+            tool_result(MiroError::InvalidInput("Invalid page cursor: x".into())).unwrap_err();
+        assert_eq!(error.code, error_codes::INVALID_PARAMS);
+
+        let error =
+            tool_result(MiroError::Internal("Unsupported HTTP method: TRACE".into())).unwrap_err();
+        assert_eq!(error.code, error_codes::INTERNAL_ERROR);
+
+        let error = rpc_error(MiroError::Forbidden(MiroApiError::parse(403, "{}")));
+        assert_eq!(error.code, error_codes::MIRO_API_ERROR);
+        assert_eq!(error.data.unwrap()["error"]["kind"], "forbidden");
+    }
+}
@@ -0,0 +1,1033 @@
+use crate::auth::scopes::BOARDS_READ;
+use crate::auth::{AuthError, UserInfo};
+use crate::mcp::context::{ProgressReporter, ToolContext};
+use crate::mcp::notifier::Notifier;
+use crate::mcp::prompts::PromptLibrary;
+use crate::mcp::protocol::{
+    CallToolParams, CancelledParams, GetPromptParams, Implementation, InitializeParams,
+    InitializeResult, JsonRpcError, JsonRpcRequest, JsonRpcResponse, ListPromptsResult,
+    ListResourceTemplatesResult, ListResourcesResult, ListToolsResult, LoggingCapability,
+    PromptsCapability, ResourceUriParams, ResourcesCapability, ServerCapabilities, SetLevelParams,
+    ToolsCapability, JSONRPC_VERSION, LATEST_PROTOCOL_VERSION, SUPPORTED_PROTOCOL_VERSIONS,
+};
+use crate::mcp::session::Session;
+use crate::mcp::{errors, resources, tools};
+use crate::miro::MiroHttpClient;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, info_span, warn, Instrument, Span};
+
+/// Parse a raw HTTP body into a JSON-RPC request
+///
+/// Returns a ready-to-send error response when the body is not valid JSON
+/// (-32700) or not a valid JSON-RPC 2.0 request object (-32600).
+pub fn parse_request(body: &[u8]) -> Result<JsonRpcRequest, Box<JsonRpcResponse>> {
+    let value: Value = serde_json::from_slice(body).map_err(|e| {
+        Box::new(JsonRpcResponse::error(
+            Value::Null,
+            JsonRpcError::parse_error(e.to_string()),
+        ))
+    })?;
+
+    // Echo the id back when we can find one, even for malformed requests
+    let id = value.get("id").cloned().unwrap_or(Value::Null);
+
+    let request: JsonRpcRequest = serde_json::from_value(value).map_err(|e| {
+        Box::new(JsonRpcResponse::error(
+            id.clone(),
+            JsonRpcError::invalid_request(e.to_string()),
+        ))
+    })?;
+
+    if request.jsonrpc != JSONRPC_VERSION {
+        return Err(Box::new(JsonRpcResponse::error(
+            id,
+            JsonRpcError::invalid_request("jsonrpc must be \"2.0\""),
+        )));
+    }
+
+    Ok(request)
+}
+
+/// MCP protocol handler for the Streamable HTTP transport
+///
+/// Routes JSON-RPC methods (initialize, tools/*, resources/*, prompts/*) and executes
+/// tool calls and resource reads against the Miro API with the caller's
+/// bearer token.
+pub struct McpHandler {
+    server_info: Implementation,
+    miro_http: MiroHttpClient,
+    prompts: PromptLibrary,
+    in_flight: InFlightCalls,
+}
+
+/// Where a request came from and where its progress goes
+#[derive(Clone, Copy, Default)]
+struct RequestContext<'a> {
+    session: Option<&'a Session>,
+    progress: Option<&'a Notifier>,
+}
+
+/// Running tool calls, keyed by (session or user, request id)
+///
+/// `notifications/cancelled` only names a request id, so the key is scoped
+/// to the sender: a client can never cancel someone else's call. A request
+/// id may be reused only once its call has finished.
+#[derive(Default)]
+struct InFlightCalls {
+    calls: Mutex<HashMap<(String, String), InFlightCall>>,
+    next_generation: AtomicU64,
+}
+
+struct InFlightCall {
+    /// Tells this call apart from a later one reusing its key
+    generation: u64,
+    token: CancellationToken,
+}
+
+impl InFlightCalls {
+    fn start(&self, key: (String, String)) -> Result<InFlightGuard<'_>, JsonRpcError> {
+        let token = CancellationToken::new();
+        let generation = self.next_generation.fetch_add(1, Ordering::Relaxed);
+        match self.lock().entry(key.clone()) {
+            Entry::Occupied(_) => {
+                return Err(JsonRpcError::invalid_request(format!(
+                    "Request id {} is already in use by a running call",
+                    key.1
+                )))
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(InFlightCall {
+                    generation,
+                    token: token.clone(),
+                });
+            }
+        }
+        Ok(InFlightGuard {
+            calls: self,
+            key,
+            generation,
+            token,
+        })
+    }
+
+    fn cancel(&self, key: &(String, String)) -> bool {
+        match self.lock().get(key) {
+            Some(call) => {
+                call.token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<(String, String), InFlightCall>> {
+        self.calls.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Deregisters a call when it finishes
+struct InFlightGuard<'a> {
+    calls: &'a InFlightCalls,
+    key: (String, String),
+    generation: u64,
+    token: CancellationToken,
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        let mut calls = self.calls.lock();
+        if calls
+            .get(&self.key)
+            .is_some_and(|call| call.generation == self.generation)
+        {
+            calls.remove(&self.key);
+        }
+    }
+}
+
+/// Key under which a request is tracked for cancellation
+fn in_flight_key(session: Option<&Session>, user_info: &UserInfo, id: &Value) -> (String, String) {
+    let owner = match session {
+        Some(session) => format!("session:{}", session.id()),
+        None => format!("user:{}", user_info.user_id),
+    };
+    (owner, id.to_string())
+}
+
+impl McpHandler {
+    pub fn new() -> Self {
+        Self::with_miro_http(MiroHttpClient::default())
+    }
+
+    /// Create a handler whose tool calls share the given connection pool
+    pub fn with_miro_http(miro_http: MiroHttpClient) -> Self {
+        Self {
+            server_info: Implementation {
+                name: env!("CARGO_PKG_NAME").to_string(),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+            },
+            miro_http,
+            prompts: PromptLibrary::builtin(),
+            in_flight: InFlightCalls::default(),
+        }
+    }
+
+    /// Serve these prompt templates instead of the built-in ones
+    pub fn with_prompts(mut self, prompts: PromptLibrary) -> Self {
+        self.prompts = prompts;
+        self
+    }
+
+    /// Handle a single JSON-RPC message
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Parsed JSON-RPC request or notification
+    /// * `bearer_token` - Validated Miro access token of the caller
+    /// * `user_info` - Caller identity and granted scopes
+    ///
+    /// # Returns
+    ///
+    /// `None` for notifications (no response expected), otherwise the response.
+    pub async fn handle(
+        &self,
+        request: JsonRpcRequest,
+        bearer_token: &str,
+        user_info: &UserInfo,
+    ) -> Option<JsonRpcResponse> {
+        self.dispatch(request, bearer_token, user_info, RequestContext::default())
+            .await
+    }
+
+    /// Handle a message that belongs to an MCP session
+    ///
+    /// Session-scoped methods (`resources/subscribe`, `resources/unsubscribe`)
+    /// are only available here.
+    pub async fn handle_in_session(
+        &self,
+        request: JsonRpcRequest,
+        bearer_token: &str,
+        user_info: &UserInfo,
+        session: &Session,
+    ) -> Option<JsonRpcResponse> {
+        let context = RequestContext {
+            session: Some(session),
+            progress: None,
+        };
+        self.dispatch(request, bearer_token, user_info, context)
+            .await
+    }
+
+    /// Handle a message whose progress notifications can be delivered
+    ///
+    /// `tools/call` requests carrying a `progressToken` send
+    /// `notifications/progress` on `progress` while they run; the transport
+    /// must forward them before the response.
+    pub async fn handle_with_progress(
+        &self,
+        request: JsonRpcRequest,
+        bearer_token: &str,
+        user_info: &UserInfo,
+        session: Option<&Session>,
+        progress: &Notifier,
+    ) -> Option<JsonRpcResponse> {
+        let context = RequestContext {
+            session,
+            progress: Some(progress),
+        };
+        self.dispatch(request, bearer_token, user_info, context)
+            .await
+    }
+
+    async fn dispatch(
+        &self,
+        request: JsonRpcRequest,
+        bearer_token: &str,
+        user_info: &UserInfo,
+        context: RequestContext<'_>,
+    ) -> Option<JsonRpcResponse> {
+        // Lets the MCP log layer send this request's events to the session
+        let span = match context.session {
+            Some(session) => info_span!("mcp_session", mcp_session_id = %session.id()),
+            None => Span::none(),
+        };
+        self.route(request, bearer_token, user_info, context)
+            .instrument(span)
+            .await
+    }
+
+    async fn route(
+        &self,
+        request: JsonRpcRequest,
+        bearer_token: &str,
+        user_info: &UserInfo,
+        context: RequestContext<'_>,
+    ) -> Option<JsonRpcResponse> {
+        debug!(method = %request.method, "MCP request received");
+
+        let Some(id) = request.id.clone() else {
+            self.handle_notification(&request, user_info, context.session);
+            return None;
+        };
+        let session = context.session;
+
+        let result = match request.method.as_str() {
+            "initialize" => self.initialize(request.params),
+            "ping" => Ok(Value::Object(Default::default())),
+            "tools/list" => self.list_tools(user_info),
+            "tools/call" => match self.in_flight.start(in_flight_key(session, user_info, &id)) {
+                Ok(in_flight) => {
+                    self.call_tool(
+                        request.params,
+                        bearer_token,
+                        user_info,
+                        context.progress,
+                        &in_flight.token,
+                    )
+                    .await
+                }
+                Err(e) => Err(e),
+            },
+            "resources/list" => self.list_resources(bearer_token, user_info).await,
+            "resources/templates/list" => self.list_resource_templates(user_info),
+            "resources/read" => {
+                self.read_resource(request.params, bearer_token, user_info)
+                    .await
+            }
+            "resources/subscribe" => {
+                self.subscribe(request.params, bearer_token, user_info, session)
+                    .await
+            }
+            "resources/unsubscribe" => self.unsubscribe(request.params, user_info, session),
+            "prompts/list" => to_value(&ListPromptsResult {
+                prompts: self.prompts.prompts(),
+            }),
+            "prompts/get" => self.get_prompt(request.params),
+            "logging/setLevel" => self.set_log_level(request.params, session),
+            method => Err(JsonRpcError::method_not_found(method)),
+        };
+
+        Some(match result {
+            Ok(value) => JsonRpcResponse::success(id, value),
+            Err(error) => {
+                warn!(method = %request.method, code = error.code, message = %error.message, "MCP request failed");
+                JsonRpcResponse::error(id, error)
+            }
+        })
+    }
+
+    /// Check the caller's scopes before dispatching a request
+    ///
+    /// Lets the transport reject `tools/call` and `resources/*` with an HTTP
+    /// 403 `insufficient_scope` challenge instead of a JSON-RPC error.
+    pub fn authorize(
+        &self,
+        request: &JsonRpcRequest,
+        user_info: &UserInfo,
+    ) -> Result<(), AuthError> {
+        if request.method.starts_with("resources/") {
+            return user_info.require_scope(BOARDS_READ);
+        }
+        if request.method != "tools/call" {
+            return Ok(());
+        }
+
+        match request
+            .params
+            .as_ref()
+            .and_then(|p| p.get("name"))
+            .and_then(Value::as_str)
+        {
+            Some(name) => tools::authorize_tool(name, user_info),
+            // Malformed params are reported by `handle`
+            None => Ok(()),
+        }
+    }
+
+    fn handle_notification(
+        &self,
+        request: &JsonRpcRequest,
+        user_info: &UserInfo,
+        session: Option<&Session>,
+    ) {
+        match request.method.as_str() {
+            "notifications/initialized" => info!("MCP client initialized"),
+            "notifications/cancelled" => {
+                let Some(params) = request
+                    .params
+                    .clone()
+                    .and_then(|p| serde_json::from_value::<CancelledParams>(p).ok())
+                else {
+                    warn!("Ignoring malformed notifications/cancelled");
+                    return;
+                };
+                let key = in_flight_key(session, user_info, &params.request_id);
+                if self.in_flight.cancel(&key) {
+                    info!(request_id = %params.request_id, reason = ?params.reason, "MCP request cancelled");
+                } else {
+                    // Already finished, or never ours: nothing to stop
+                    debug!(request_id = %params.request_id, "Cancellation for unknown request");
+                }
+            }
+            method => debug!(method = %method, "Ignoring MCP notification"),
+        }
+    }
+
+    fn initialize(&self, params: Option<Value>) -> Result<Value, JsonRpcError> {
+        let params: InitializeParams = parse_params(params)?;
+
+        // Echo the client's version when we support it, otherwise offer our latest
+        let protocol_version = params
+            .protocol_version
+            .filter(|v| SUPPORTED_PROTOCOL_VERSIONS.contains(&v.as_str()))
+            .unwrap_or_else(|| LATEST_PROTOCOL_VERSION.to_string());
+
+        if let Some(client) = &params.client_info {
+            info!(
+                client_name = %client.name,
+                client_version = %client.version,
+                protocol_version = %protocol_version,
+                "MCP session initializing"
+            );
+        }
+
+        let result = InitializeResult {
+            protocol_version,
+            capabilities: ServerCapabilities {
+                tools: Some(ToolsCapability {
+                    list_changed: false,
+                }),
+                resources: Some(ResourcesCapability {
+                    subscribe: true,
+                    list_changed: false,
+                }),
+                prompts: Some(PromptsCapability {
+                    list_changed: false,
+                }),
+                logging: Some(LoggingCapability {}),
+            },
+            server_info: self.server_info.clone(),
+            instructions: None,
+        };
+
+        to_value(&result)
+    }
+
+    fn list_tools(&self, user_info: &UserInfo) -> Result<Value, JsonRpcError> {
+        // Only advertise tools the caller's token can actually use
+        to_value(&ListToolsResult {
+            tools: tools::list_tools_for(user_info),
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        params: Option<Value>,
+        bearer_token: &str,
+        user_info: &UserInfo,
+        progress: Option<&Notifier>,
+        cancellation: &CancellationToken,
+    ) -> Result<Value, JsonRpcError> {
+        let params: CallToolParams = params
+            .ok_or_else(|| JsonRpcError::invalid_params("Missing params for tools/call"))
+            .and_then(|p| {
+                serde_json::from_value(p).map_err(|e| JsonRpcError::invalid_params(e.to_string()))
+            })?;
+
+        if let Some(scope) = tools::required_scope(&params.name) {
+            if !user_info.has_scope(scope) {
+                warn!(tool = %params.name, scope = %scope, user_id = %user_info.user_id, "Tool call denied");
+                return Err(JsonRpcError::insufficient_scope(scope));
+            }
+        }
+
+        let client = self
+            .miro_http
+            .for_token(bearer_token)
+            .with_cancellation(cancellation.clone());
+        let ctx = ToolContext::new(
+            ProgressReporter::new(
+                params.meta.and_then(|meta| meta.progress_token),
+                progress.cloned(),
+            ),
+            cancellation.clone(),
+        );
+
+        let arguments = params
+            .arguments
+            .unwrap_or_else(|| Value::Object(Default::default()));
+        let result = tools::call_tool_with_context(&client, &params.name, arguments, &ctx).await?;
+
+        to_value(&result)
+    }
+
+    async fn list_resources(
+        &self,
+        bearer_token: &str,
+        user_info: &UserInfo,
+    ) -> Result<Value, JsonRpcError> {
+        require_resource_scope(user_info)?;
+
+        let client = self.miro_http.for_token(bearer_token);
+        let resources = resources::list_resources(&client)
+            .await
+            .map_err(errors::rpc_error)?;
+
+        to_value(&ListResourcesResult { resources })
+    }
+
+    fn list_resource_templates(&self, user_info: &UserInfo) -> Result<Value, JsonRpcError> {
+        require_resource_scope(user_info)?;
+
+        to_value(&ListResourceTemplatesResult {
+            resource_templates: resources::templates(),
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        params: Option<Value>,
+        bearer_token: &str,
+        user_info: &UserInfo,
+    ) -> Result<Value, JsonRpcError> {
+        let params = uri_params(params, "resources/read")?;
+        require_resource_scope(user_info)?;
+
+        let client = self.miro_http.for_token(bearer_token);
+        let result = resources::read_resource(&client, &params.uri).await?;
+
+        to_value(&result)
+    }
+
+    fn get_prompt(&self, params: Option<Value>) -> Result<Value, JsonRpcError> {
+        let params: GetPromptParams = params
+            .ok_or_else(|| JsonRpcError::invalid_params("Missing params for prompts/get"))
+            .and_then(|p| {
+                serde_json::from_value(p).map_err(|e| JsonRpcError::invalid_params(e.to_string()))
+            })?;
+
+        to_value(&self.prompts.get(&params.name, &params.arguments)?)
+    }
+
+    async fn subscribe(
+        &self,
+        params: Option<Value>,
+        bearer_token: &str,
+        user_info: &UserInfo,
+        session: Option<&Session>,
+    ) -> Result<Value, JsonRpcError> {
+        let params = uri_params(params, "resources/subscribe")?;
+        require_resource_scope(user_info)?;
+        let session = require_session(session, "resources/subscribe")?;
+
+        // The watcher polls with the subscriber's own token
+        let client = Arc::new(self.miro_http.for_token(bearer_token));
+        session
+            .subscriptions()
+            .subscribe(&params.uri, client)
+            .await?;
+
+        Ok(Value::Object(Default::default()))
+    }
+
+    fn set_log_level(
+        &self,
+        params: Option<Value>,
+        session: Option<&Session>,
+    ) -> Result<Value, JsonRpcError> {
+        let params: SetLevelParams = params
+            .ok_or_else(|| JsonRpcError::invalid_params("Missing params for logging/setLevel"))
+            .and_then(|p| {
+                serde_json::from_value(p).map_err(|e| JsonRpcError::invalid_params(e.to_string()))
+            })?;
+        let session = require_session(session, "logging/setLevel")?;
+
+        session.log().set_level(params.level);
+        info!(level = ?params.level, "MCP log level set");
+        Ok(Value::Object(Default::default()))
+    }
+
+    fn unsubscribe(
+        &self,
+        params: Option<Value>,
+        user_info: &UserInfo,
+        session: Option<&Session>,
+    ) -> Result<Value, JsonRpcError> {
+        let params = uri_params(params, "resources/unsubscribe")?;
+        require_resource_scope(user_info)?;
+        let session = require_session(session, "resources/unsubscribe")?;
+
+        if !session.subscriptions().unsubscribe(&params.uri) {
+            debug!(uri = %params.uri, "Unsubscribe for URI without subscription");
+        }
+
+        Ok(Value::Object(Default::default()))
+    }
+}
+
+impl Default for McpHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Resources expose board contents, so every `resources/*` method needs read access
+fn require_resource_scope(user_info: &UserInfo) -> Result<(), JsonRpcError> {
+    if user_info.has_scope(BOARDS_READ) {
+        Ok(())
+    } else {
+        warn!(scope = %BOARDS_READ, user_id = %user_info.user_id, "Resource access denied");
+        Err(JsonRpcError::insufficient_scope(BOARDS_READ))
+    }
+}
+
+fn uri_params(params: Option<Value>, method: &str) -> Result<ResourceUriParams, JsonRpcError> {
+    params
+        .ok_or_else(|| JsonRpcError::invalid_params(format!("Missing params for {}", method)))
+        .and_then(|p| {
+            serde_json::from_value(p).map_err(|e| JsonRpcError::invalid_params(e.to_string()))
+        })
+}
+
+/// Subscriptions and log levels live in the session, so stateless requests cannot use them
+fn require_session<'a>(
+    session: Option<&'a Session>,
+    method: &str,
+) -> Result<&'a Session, JsonRpcError> {
+    session.ok_or_else(|| {
+        JsonRpcError::invalid_request(format!(
+            "{} requires an MCP session (send the Mcp-Session-Id returned by initialize)",
+            method
+        ))
+    })
+}
+
+/// Deserialize optional params, treating absent params as an empty object
+fn parse_params<T: DeserializeOwned + Default>(params: Option<Value>) -> Result<T, JsonRpcError> {
+    match params {
+        Some(value) => {
+            serde_json::from_value(value).map_err(|e| JsonRpcError::invalid_params(e.to_string()))
+        }
+        None => Ok(T::default()),
+    }
+}
+
+fn to_value<T: serde::Serialize>(value: &T) -> Result<Value, JsonRpcError> {
+    serde_json::to_value(value).map_err(|e| JsonRpcError::internal_error(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::protocol::{error_codes, LoggingLevel};
+    use serde_json::json;
+
+    fn user(scopes: &[&str]) -> UserInfo {
+        UserInfo::new(
+            "user".to_string(),
+            None,
+            scopes.iter().map(|s| s.to_string()).collect(),
+        )
+    }
+
+    fn request(id: Option<Value>, method: &str, params: Option<Value>) -> JsonRpcRequest {
+        JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id,
+            method: method.to_string(),
+            params,
+        }
+    }
+
+    #[test]
+    fn test_parse_request_invalid_json() {
+        let err = parse_request(b"{not json").unwrap_err();
+        assert_eq!(err.error.unwrap().code, error_codes::PARSE_ERROR);
+    }
+
+    #[test]
+    fn test_parse_request_missing_method() {
+        let err = parse_request(br#"{"jsonrpc":"2.0","id":7}"#).unwrap_err();
+        assert_eq!(err.id, json!(7));
+        assert_eq!(err.error.unwrap().code, error_codes::INVALID_REQUEST);
+    }
+
+    #[test]
+    fn test_parse_request_wrong_version() {
+        let err = parse_request(br#"{"jsonrpc":"1.0","id":1,"method":"ping"}"#).unwrap_err();
+        assert_eq!(err.error.unwrap().code, error_codes::INVALID_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_initialize_negotiates_supported_version() {
+        let handler = McpHandler::new();
+        let response = handler
+            .handle(
+                request(
+                    Some(json!(1)),
+                    "initialize",
+                    Some(json!({ "protocolVersion": "2024-11-05" })),
+                ),
+                "token",
+                &user(&[]),
+            )
+            .await
+            .unwrap();
+
+        let result = response.result.unwrap();
+        assert_eq!(result["protocolVersion"], "2024-11-05");
+        assert_eq!(result["serverInfo"]["name"], "miro-mcp-server");
+        assert!(result["capabilities"]["tools"].is_object());
+        assert_eq!(result["capabilities"]["resources"]["subscribe"], true);
+        assert!(result["capabilities"]["prompts"].is_object());
+        assert!(result["capabilities"]["logging"].is_object());
+    }
+
+    #[tokio::test]
+    async fn test_initialize_unknown_version_falls_back_to_latest() {
+        let handler = McpHandler::new();
+        let response = handler
+            .handle(
+                request(
+                    Some(json!(1)),
+                    "initialize",
+                    Some(json!({ "protocolVersion": "1999-01-01" })),
+                ),
+                "token",
+                &user(&[]),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.result.unwrap()["protocolVersion"],
+            LATEST_PROTOCOL_VERSION
+        );
+    }
+
+    #[tokio::test]
+    async fn test_notification_returns_none() {
+        let handler = McpHandler::new();
+        let response = handler
+            .handle(
+                request(None, "notifications/initialized", None),
+                "token",
+                &user(&[]),
+            )
+            .await;
+        assert!(response.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_method() {
+        let handler = McpHandler::new();
+        let response = handler
+            .handle(
+                request(Some(json!("a")), "completion/complete", None),
+                "token",
+                &user(&[]),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.id, json!("a"));
+        assert_eq!(response.error.unwrap().code, error_codes::METHOD_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_tools_call_without_params() {
+        let handler = McpHandler::new();
+        let response = handler
+            .handle(
+                request(Some(json!(3)), "tools/call", None),
+                "token",
+                &user(&[]),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.error.unwrap().code, error_codes::INVALID_PARAMS);
+    }
+
+    #[tokio::test]
+    async fn test_tools_call_with_invalid_arguments_is_tool_error() {
+        let handler = McpHandler::new();
+        let call = |name: &str| {
+            request(
+                Some(json!(4)),
+                "tools/call",
+                Some(json!({ "name": name, "arguments": { "name": 7 } })),
+            )
+        };
+
+        // Never reaches Miro: the result tells the model what to fix
+        let response = handler
+            .handle(call("create_board"), "token", &user(&["boards:write"]))
+            .await
+            .unwrap();
+        let result = response.result.unwrap();
+        assert_eq!(result["isError"], true);
+        assert_eq!(
+            result["structuredContent"]["error"]["kind"],
+            "invalid_arguments"
+        );
+        assert_eq!(
+            result["structuredContent"]["error"]["fields"][0]["field"],
+            "name"
+        );
+
+        let response = handler
+            .handle(call("no_such_tool"), "token", &user(&["boards:write"]))
+            .await
+            .unwrap();
+        assert_eq!(response.error.unwrap().code, error_codes::INVALID_PARAMS);
+    }
+
+    #[tokio::test]
+    async fn test_tools_list_hides_tools_without_scope() {
+        let handler = McpHandler::new();
+        let response = handler
+            .handle(
+                request(Some(json!(1)), "tools/list", None),
+                "token",
+                &user(&["boards:read"]),
+            )
+            .await
+            .unwrap();
+
+        let result = response.result.unwrap();
+        let tools = result["tools"].as_array().unwrap();
+        assert!(!tools.is_empty());
+        assert!(tools.iter().all(|t| t["name"] != "create_board"));
+    }
+
+    #[tokio::test]
+    async fn test_tools_call_without_scope_is_rejected() {
+        let handler = McpHandler::new();
+        let call = request(
+            Some(json!(4)),
+            "tools/call",
+            Some(json!({ "name": "delete_item", "arguments": {} })),
+        );
+
+        assert!(matches!(
+            handler.authorize(&call, &user(&["boards:read"])),
+            Err(AuthError::InsufficientScope(scope)) if scope == "boards:write"
+        ));
+
+        let response = handler
+            .handle(call, "token", &user(&["boards:read"]))
+            .await
+            .unwrap();
+        let error = response.error.unwrap();
+        assert_eq!(error.code, error_codes::INSUFFICIENT_SCOPE);
+        assert_eq!(error.data.unwrap()["scope"], "boards:write");
+    }
+
+    #[tokio::test]
+    async fn test_resource_templates_require_read_scope() {
+        let handler = McpHandler::new();
+        let list = request(Some(json!(5)), "resources/templates/list", None);
+
+        assert!(matches!(
+            handler.authorize(&list, &user(&[])),
+            Err(AuthError::InsufficientScope(scope)) if scope == "boards:read"
+        ));
+
+        let denied = handler
+            .handle(list.clone(), "token", &user(&[]))
+            .await
+            .unwrap();
+        assert_eq!(denied.error.unwrap().code, error_codes::INSUFFICIENT_SCOPE);
+
+        let response = handler
+            .handle(list, "token", &user(&["boards:read"]))
+            .await
+            .unwrap();
+        let templates = response.result.unwrap()["resourceTemplates"].clone();
+        assert_eq!(templates[0]["uriTemplate"], "miro://boards/{board_id}");
+    }
+
+    #[tokio::test]
+    async fn test_read_unknown_resource() {
+        let handler = McpHandler::new();
+        let response = handler
+            .handle(
+                request(
+                    Some(json!(6)),
+                    "resources/read",
+                    Some(json!({ "uri": "miro://widgets" })),
+                ),
+                "token",
+                &user(&["boards:read"]),
+            )
+            .await
+            .unwrap();
+        let error = response.error.unwrap();
+        assert_eq!(error.code, error_codes::RESOURCE_NOT_FOUND);
+        assert_eq!(error.data.unwrap()["uri"], "miro://widgets");
+    }
+
+    #[tokio::test]
+    async fn test_cancellation_is_scoped_to_sender() {
+        let handler = McpHandler::new();
+        let cancelled = request(
+            None,
+            "notifications/cancelled",
+            Some(json!({ "requestId": 42, "reason": "too slow" })),
+        );
+        assert!(handler
+            .handle(cancelled, "token", &user(&["boards:write"]))
+            .await
+            .is_none());
+
+        // Calls are scoped to their sender
+        let alice = user(&["boards:write"]);
+        let call = handler
+            .in_flight
+            .start(in_flight_key(None, &alice, &json!(1)))
+            .unwrap();
+        let mut mallory = user(&["boards:write"]);
+        mallory.user_id = "mallory".to_string();
+        assert!(!handler
+            .in_flight
+            .cancel(&in_flight_key(None, &mallory, &json!(1))));
+        assert!(handler
+            .in_flight
+            .cancel(&in_flight_key(None, &alice, &json!(1))));
+
+        // A running call's id cannot be reused, and its entry stays until it ends
+        let duplicate = handler
+            .in_flight
+            .start(in_flight_key(None, &alice, &json!(1)))
+            .err()
+            .unwrap();
+        assert_eq!(duplicate.code, error_codes::INVALID_REQUEST);
+        assert!(handler
+            .in_flight
+            .cancel(&in_flight_key(None, &alice, &json!(1))));
+        drop(call);
+        assert!(!handler
+            .in_flight
+            .cancel(&in_flight_key(None, &alice, &json!(1))));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_requires_session() {
+        let handler = McpHandler::new();
+        let subscribe = request(
+            Some(json!(7)),
+            "resources/subscribe",
+            Some(json!({ "uri": "miro://boards/b1" })),
+        );
+
+        let response = handler
+            .handle(subscribe.clone(), "token", &user(&["boards:read"]))
+            .await
+            .unwrap();
+        assert_eq!(response.error.unwrap().code, error_codes::INVALID_REQUEST);
+
+        // Rejected before any Miro call: the boards list is not subscribable
+        let session = Session::new("user", std::time::Duration::from_secs(15));
+        let response = handler
+            .handle_in_session(
+                request(
+                    Some(json!(8)),
+                    "resources/subscribe",
+                    Some(json!({ "uri": "miro://boards" })),
+                ),
+                "token",
+                &user(&["boards:read"]),
+                &session,
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.error.unwrap().code, error_codes::INVALID_PARAMS);
+    }
+
+    #[tokio::test]
+    async fn test_set_log_level() {
+        let handler = McpHandler::new();
+        let set_level = |level: &str| {
+            request(
+                Some(json!(10)),
+                "logging/setLevel",
+                Some(json!({ "level": level })),
+            )
+        };
+
+        let response = handler
+            .handle(set_level("info"), "token", &user(&[]))
+            .await
+            .unwrap();
+        assert_eq!(response.error.unwrap().code, error_codes::INVALID_REQUEST);
+
+        let session = Session::new("user", std::time::Duration::from_secs(15));
+        assert_eq!(session.log().level(), None);
+        let response = handler
+            .handle_in_session(set_level("warning"), "token", &user(&[]), &session)
+            .await
+            .unwrap();
+        assert_eq!(response.result.unwrap(), json!({}));
+        assert_eq!(session.log().level(), Some(LoggingLevel::Warning));
+
+        let response = handler
+            .handle_in_session(set_level("verbose"), "token", &user(&[]), &session)
+            .await
+            .unwrap();
+        assert_eq!(response.error.unwrap().code, error_codes::INVALID_PARAMS);
+    }
+
+    #[tokio::test]
+    async fn test_prompts_list_and_get() {
+        let handler = McpHandler::new();
+        let response = handler
+            .handle(
+                request(Some(json!(9)), "prompts/list", None),
+                "token",
+                &user(&[]),
+            )
+            .await
+            .unwrap();
+        let prompts = response.result.unwrap()["prompts"].clone();
+        assert_eq!(prompts[0]["name"], "retrospective");
+        assert!(prompts[0]["arguments"].is_array());
+
+        let response = handler
+            .handle(
+                request(
+                    Some(json!(10)),
+                    "prompts/get",
+                    Some(json!({ "name": "brainstorm", "arguments": { "topic": "Onboarding" } })),
+                ),
+                "token",
+                &user(&[]),
+            )
+            .await
+            .unwrap();
+        let result = response.result.unwrap();
+        assert_eq!(result["messages"][0]["role"], "user");
+        assert!(result["messages"][0]["content"]["text"]
+            .as_str()
+            .unwrap()
+            .contains("\"Onboarding\""));
+
+        let response = handler
+            .handle(
+                request(
+                    Some(json!(11)),
+                    "prompts/get",
+                    Some(json!({ "name": "nope" })),
+                ),
+                "token",
+                &user(&[]),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.error.unwrap().code, error_codes::INVALID_PARAMS);
+    }
+}
@@ -0,0 +1,287 @@
+//! Server logs for MCP clients (`logging` capability)
+//!
+//! Every session registers a [`SessionLog`]. The [`layer`] added to the
+//! binaries' `tracing` subscriber picks up events of this crate, finds the
+//! session of the request they were recorded in (the `mcp_session_id` span
+//! field set by the handler) and sends them to that client as
+//! `notifications/message`, once it has asked for them with
+//! `logging/setLevel`. The `request_id` of the enclosing HTTP request is
+//! added to the message so clients can quote it when reporting problems.
+//!
+//! Events outside a session (stateless HTTP requests, startup) only reach
+//! the server's own logs.
+
+use crate::mcp::notifier::Notifier;
+use crate::mcp::protocol::{JsonRpcRequest, LoggingLevel, LoggingMessageParams};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, Weak};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Span field naming the session whose client receives the span's events
+pub const SESSION_FIELD: &str = "mcp_session_id";
+
+/// Span field of the HTTP correlation id
+const REQUEST_ID_FIELD: &str = "request_id";
+
+/// Only this crate's events are forwarded; dependencies stay server-side
+const FORWARDED_TARGET: &str = "miro_mcp_server";
+
+/// Log messages of one session, sent from the level the client chose
+#[derive(Debug)]
+pub struct SessionLog {
+    notifier: Notifier,
+    level: Mutex<Option<LoggingLevel>>,
+}
+
+impl SessionLog {
+    /// Route events of session `session_id` to `notifier`
+    ///
+    /// The route lasts as long as the returned log. Nothing is sent until
+    /// [`set_level`](Self::set_level) is called.
+    pub fn register(session_id: &str, notifier: Notifier) -> Arc<Self> {
+        let log = Arc::new(Self {
+            notifier,
+            level: Mutex::new(None),
+        });
+        let mut routes = routes();
+        routes.retain(|_, log| log.strong_count() > 0);
+        routes.insert(session_id.to_string(), Arc::downgrade(&log));
+        log
+    }
+
+    /// Send messages of `level` and above (`logging/setLevel`)
+    pub fn set_level(&self, level: LoggingLevel) {
+        *self.level.lock().unwrap_or_else(|e| e.into_inner()) = Some(level);
+    }
+
+    /// Minimum level sent, `None` while the client has not asked for logs
+    pub fn level(&self) -> Option<LoggingLevel> {
+        *self.level.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn enabled(&self, level: LoggingLevel) -> bool {
+        self.level().is_some_and(|minimum| level >= minimum)
+    }
+
+    fn send(&self, params: LoggingMessageParams) {
+        let Ok(params) = serde_json::to_value(params) else {
+            return;
+        };
+        // A closed channel means the client went away; nothing left to tell it
+        let _ = self.notifier.send(JsonRpcRequest::notification(
+            "notifications/message",
+            params,
+        ));
+    }
+}
+
+/// Session id to log, for sessions still alive
+fn routes() -> MutexGuard<'static, HashMap<String, Weak<SessionLog>>> {
+    static ROUTES: OnceLock<Mutex<HashMap<String, Weak<SessionLog>>>> = OnceLock::new();
+    ROUTES
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+fn lookup(session_id: &str) -> Option<Arc<SessionLog>> {
+    routes().get(session_id).and_then(Weak::upgrade)
+}
+
+/// `tracing` layer forwarding this crate's events to MCP sessions
+///
+/// Filtered on its own, so the formatter's `RUST_LOG` filter must be a
+/// per-layer filter too for debug events to reach clients.
+pub fn layer<S>() -> impl Layer<S>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    McpLogLayer.with_filter(Targets::new().with_target(FORWARDED_TARGET, Level::DEBUG))
+}
+
+struct McpLogLayer;
+
+/// Fields recorded on a span, kept in its extensions
+#[derive(Default)]
+struct SpanFields(Map<String, Value>);
+
+impl<S> Layer<S> for McpLogLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut fields = SpanFields::default();
+        attrs.record(&mut JsonVisitor(&mut fields.0));
+        span.extensions_mut().insert(fields);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        if let Some(fields) = extensions.get_mut::<SpanFields>() {
+            values.record(&mut JsonVisitor(&mut fields.0));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut session_id = None;
+        let mut request_id = None;
+        for span in ctx.event_scope(event).into_iter().flatten() {
+            let extensions = span.extensions();
+            let Some(SpanFields(fields)) = extensions.get::<SpanFields>() else {
+                continue;
+            };
+            session_id = session_id.or_else(|| fields.get(SESSION_FIELD).cloned());
+            request_id = request_id.or_else(|| fields.get(REQUEST_ID_FIELD).cloned());
+        }
+
+        let Some(log) = session_id.as_ref().and_then(Value::as_str).and_then(lookup) else {
+            return;
+        };
+        let level = logging_level(event.metadata().level());
+        if !log.enabled(level) {
+            return;
+        }
+
+        let mut data = Map::new();
+        event.record(&mut JsonVisitor(&mut data));
+        if let Some(request_id) = request_id {
+            data.insert(REQUEST_ID_FIELD.to_string(), request_id);
+        }
+        log.send(LoggingMessageParams {
+            level,
+            logger: Some(event.metadata().target().to_string()),
+            data: Value::Object(data),
+        });
+    }
+}
+
+fn logging_level(level: &Level) -> LoggingLevel {
+    match *level {
+        Level::ERROR => LoggingLevel::Error,
+        Level::WARN => LoggingLevel::Warning,
+        Level::INFO => LoggingLevel::Info,
+        _ => LoggingLevel::Debug,
+    }
+}
+
+/// Records `tracing` fields as JSON values
+struct JsonVisitor<'a>(&'a mut Map<String, Value>);
+
+impl JsonVisitor<'_> {
+    fn insert(&mut self, field: &Field, value: impl Into<Value>) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+}
+
+impl Visit for JsonVisitor<'_> {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.insert(field, value);
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.insert(field, value);
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.insert(field, value);
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.insert(field, value);
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.insert(field, value);
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.insert(field, format!("{:?}", value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::notifier;
+    use serde_json::json;
+    use tracing::{debug, info, info_span, warn};
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn test_events_reach_session_from_chosen_level() {
+        let (notifier, mut receiver) = notifier::channel(8);
+        let log = SessionLog::register("session-a", notifier);
+        let subscriber = tracing_subscriber::registry().with(layer());
+
+        tracing::subscriber::with_default(subscriber, || {
+            let request = info_span!("http_request", request_id = "req-1");
+            let _request = request.enter();
+            let session = info_span!("mcp_session", mcp_session_id = "session-a");
+            let _session = session.enter();
+
+            info!("Before setLevel");
+            log.set_level(LoggingLevel::Info);
+            debug!("Below the chosen level");
+            info!(
+                elapsed_ms = 42u64,
+                path = "/boards",
+                "Miro API call finished"
+            );
+        });
+
+        let message = receiver.try_recv().unwrap();
+        assert_eq!(message.method, "notifications/message");
+        assert_eq!(
+            message.params.unwrap(),
+            json!({
+                "level": "info",
+                "logger": "miro_mcp_server::mcp::logging::tests",
+                "data": {
+                    "message": "Miro API call finished",
+                    "elapsed_ms": 42,
+                    "path": "/boards",
+                    "request_id": "req-1"
+                }
+            })
+        );
+        assert!(receiver.try_recv().is_none());
+    }
+
+    #[test]
+    fn test_events_stay_in_their_session() {
+        let (notifier, mut receiver) = notifier::channel(8);
+        let log = SessionLog::register("session-b", notifier);
+        log.set_level(LoggingLevel::Debug);
+        let subscriber = tracing_subscriber::registry().with(layer());
+
+        tracing::subscriber::with_default(subscriber, || {
+            warn!("No session in scope");
+            info_span!("mcp_session", mcp_session_id = "session-c")
+                .in_scope(|| warn!("Another session"));
+            info_span!("mcp_session", mcp_session_id = "session-b")
+                .in_scope(|| warn!("This session"));
+        });
+
+        let message = receiver.try_recv().unwrap();
+        let params = message.params.unwrap();
+        assert_eq!(params["level"], "warning");
+        assert_eq!(params["data"]["message"], "This session");
+        assert!(receiver.try_recv().is_none());
+
+        drop(log);
+        assert!(lookup("session-b").is_none());
+    }
+}
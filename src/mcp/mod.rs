@@ -0,0 +1,17 @@
+pub mod context;
+pub mod errors;
+pub mod handler;
+pub mod logging;
+pub mod notifier;
+pub mod prompts;
+pub mod protocol;
+pub mod registry;
+pub mod resources;
+pub mod schema;
+pub mod session;
+pub mod stdio;
+pub mod subscriptions;
+pub mod tools;
+
+pub use handler::{parse_request, McpHandler};
+pub use protocol::{CallToolResult, JsonRpcError, JsonRpcRequest, JsonRpcResponse, Tool};
@@ -0,0 +1,299 @@
+//! Bounded queue of server-to-client notifications
+//!
+//! A session's notifications wait here until a transport delivers them. An
+//! HTTP client may never open the `GET /mcp` stream, so the queue is capped:
+//! once it holds `capacity` notifications, the oldest is dropped to make room.
+//! Notifications that only point at a change can be coalesced, so a pending
+//! copy is not queued twice. Coalesced notifications are never dropped: a
+//! sender that coalesces relies on its pending copy reaching the client, and
+//! there is at most one per distinct message.
+//!
+//! Sending never logs through `tracing`, because the logging layer itself
+//! sends `notifications/message` through this queue.
+
+use crate::mcp::protocol::JsonRpcRequest;
+use std::collections::VecDeque;
+use std::fmt;
+use std::future::poll_fn;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+
+/// Create a queue keeping at most `capacity` undelivered notifications
+///
+/// A capacity of 0 is treated as 1, so a draining receiver still gets
+/// every notification.
+pub fn channel(capacity: usize) -> (Notifier, NotificationReceiver) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: VecDeque::new(),
+            capacity: capacity.max(1),
+            senders: 1,
+            receiver_open: true,
+            waker: None,
+        }),
+    });
+    (
+        Notifier {
+            shared: shared.clone(),
+        },
+        NotificationReceiver { shared },
+    )
+}
+
+/// The receiving half was dropped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("notification receiver is closed")]
+pub struct Closed;
+
+struct Shared {
+    state: Mutex<State>,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+struct State {
+    queue: VecDeque<Queued>,
+    capacity: usize,
+    senders: usize,
+    receiver_open: bool,
+    waker: Option<Waker>,
+}
+
+struct Queued {
+    message: JsonRpcRequest,
+    /// Sent with [`Notifier::send_coalesced`], so never dropped
+    coalesced: bool,
+}
+
+impl State {
+    fn contains(&self, message: &JsonRpcRequest) -> bool {
+        self.queue.iter().any(|queued| &queued.message == message)
+    }
+
+    /// Drop the oldest droppable notifications until at most `capacity` remain
+    fn evict(&mut self, capacity: usize) {
+        while self.queue.len() > capacity {
+            let Some(oldest) = self.queue.iter().position(|queued| !queued.coalesced) else {
+                return;
+            };
+            self.queue.remove(oldest);
+        }
+    }
+}
+
+/// Sending half of a notification queue
+pub struct Notifier {
+    shared: Arc<Shared>,
+}
+
+impl Notifier {
+    /// Queue a notification, dropping the oldest droppable one when full
+    pub fn send(&self, message: JsonRpcRequest) -> Result<(), Closed> {
+        self.push(message, false)
+    }
+
+    /// Queue a notification unless an identical one is still waiting
+    ///
+    /// It is kept until delivered, even when the queue is full.
+    pub fn send_coalesced(&self, message: JsonRpcRequest) -> Result<(), Closed> {
+        self.push(message, true)
+    }
+
+    /// Whether an identical notification is waiting for delivery
+    pub fn is_queued(&self, message: &JsonRpcRequest) -> bool {
+        self.shared.lock().contains(message)
+    }
+
+    fn push(&self, message: JsonRpcRequest, coalesce: bool) -> Result<(), Closed> {
+        let waker = {
+            let mut state = self.shared.lock();
+            if !state.receiver_open {
+                return Err(Closed);
+            }
+            if coalesce && state.contains(&message) {
+                return Ok(());
+            }
+            let capacity = state.capacity;
+            state.evict(capacity - 1);
+            state.queue.push_back(Queued {
+                message,
+                coalesced: coalesce,
+            });
+            state.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+        Ok(())
+    }
+
+    /// Whether the receiving half was dropped
+    pub fn is_closed(&self) -> bool {
+        !self.shared.lock().receiver_open
+    }
+
+    /// Change how many notifications the queue keeps, dropping the oldest
+    pub fn set_capacity(&self, capacity: usize) {
+        let mut state = self.shared.lock();
+        state.capacity = capacity.max(1);
+        let capacity = state.capacity;
+        state.evict(capacity);
+    }
+}
+
+impl Clone for Notifier {
+    fn clone(&self) -> Self {
+        self.shared.lock().senders += 1;
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl Drop for Notifier {
+    fn drop(&mut self) {
+        let waker = {
+            let mut state = self.shared.lock();
+            state.senders -= 1;
+            if state.senders > 0 {
+                return;
+            }
+            state.waker.take()
+        };
+        // The receiver sees the end of the queue
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl fmt::Debug for Notifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.shared.lock();
+        f.debug_struct("Notifier")
+            .field("queued", &state.queue.len())
+            .field("capacity", &state.capacity)
+            .finish()
+    }
+}
+
+/// Receiving half of a notification queue
+///
+/// Yields `None` once the queue is empty and every [`Notifier`] is gone.
+pub struct NotificationReceiver {
+    shared: Arc<Shared>,
+}
+
+impl NotificationReceiver {
+    /// Wait for the next notification
+    pub async fn recv(&mut self) -> Option<JsonRpcRequest> {
+        poll_fn(|cx| self.poll_recv(cx)).await
+    }
+
+    /// Next notification if one is queued
+    pub fn try_recv(&mut self) -> Option<JsonRpcRequest> {
+        self.shared
+            .lock()
+            .queue
+            .pop_front()
+            .map(|queued| queued.message)
+    }
+
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<JsonRpcRequest>> {
+        let mut state = self.shared.lock();
+        if let Some(queued) = state.queue.pop_front() {
+            return Poll::Ready(Some(queued.message));
+        }
+        if state.senders == 0 {
+            return Poll::Ready(None);
+        }
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl Drop for NotificationReceiver {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.receiver_open = false;
+        state.queue.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn notification(method: &str) -> JsonRpcRequest {
+        JsonRpcRequest::notification(method, json!({}))
+    }
+
+    #[test]
+    fn test_full_queue_drops_oldest() {
+        let (notifier, mut receiver) = channel(2);
+        for method in ["a", "b", "c"] {
+            notifier.send(notification(method)).unwrap();
+        }
+
+        assert_eq!(receiver.try_recv().unwrap().method, "b");
+        assert_eq!(receiver.try_recv().unwrap().method, "c");
+        assert!(receiver.try_recv().is_none());
+    }
+
+    #[test]
+    fn test_coalesced_notification_is_queued_once() {
+        let (notifier, mut receiver) = channel(4);
+        notifier.send_coalesced(notification("a")).unwrap();
+        notifier.send(notification("b")).unwrap();
+        notifier.send_coalesced(notification("a")).unwrap();
+        assert!(notifier.is_queued(&notification("a")));
+
+        assert_eq!(receiver.try_recv().unwrap().method, "a");
+        assert_eq!(receiver.try_recv().unwrap().method, "b");
+        assert!(receiver.try_recv().is_none());
+
+        // Delivered: the next one is queued again
+        notifier.send_coalesced(notification("a")).unwrap();
+        assert_eq!(receiver.try_recv().unwrap().method, "a");
+    }
+
+    #[test]
+    fn test_full_queue_keeps_coalesced_notifications() {
+        let (notifier, mut receiver) = channel(2);
+        notifier.send_coalesced(notification("updated")).unwrap();
+        for method in ["a", "b", "c"] {
+            notifier.send(notification(method)).unwrap();
+        }
+
+        assert_eq!(receiver.try_recv().unwrap().method, "updated");
+        assert_eq!(receiver.try_recv().unwrap().method, "c");
+        assert!(receiver.try_recv().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_receiver_ends_when_senders_are_gone() {
+        let (notifier, mut receiver) = channel(4);
+        let clone = notifier.clone();
+        drop(notifier);
+        clone.send(notification("a")).unwrap();
+        drop(clone);
+
+        assert_eq!(receiver.recv().await.unwrap().method, "a");
+        assert!(receiver.recv().await.is_none());
+    }
+
+    #[test]
+    fn test_send_fails_once_receiver_is_dropped() {
+        let (notifier, receiver) = channel(4);
+        assert!(!notifier.is_closed());
+        drop(receiver);
+
+        assert!(notifier.is_closed());
+        assert_eq!(notifier.send(notification("a")), Err(Closed));
+    }
+}
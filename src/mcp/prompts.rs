@@ -0,0 +1,389 @@
+//! MCP prompts: parameterized board-building templates
+//!
+//! A template is a Markdown file with YAML front matter:
+//!
+//! ```text
+//! ---
+//! name: retrospective
+//! description: Build a sprint retrospective board
+//! arguments:
+//!   - name: team
+//!     description: Team running the retrospective
+//!     required: true
+//!   - name: columns
+//!     default: Went well, To improve, Action items
+//! ---
+//! Set up a retrospective for {{team}} with columns {{columns}}...
+//! ```
+//!
+//! The built-in templates live in `prompts/` and are compiled in; a prompts
+//! directory configured at startup adds templates and overrides built-ins
+//! with the same name.
+
+use crate::mcp::protocol::{
+    Content, GetPromptResult, JsonRpcError, Prompt, PromptArgument, PromptMessage,
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use tracing::info;
+
+const BUILTIN: &[(&str, &str)] = &[
+    (
+        "retrospective.md",
+        include_str!("../../prompts/retrospective.md"),
+    ),
+    ("story-map.md", include_str!("../../prompts/story-map.md")),
+    ("kanban.md", include_str!("../../prompts/kanban.md")),
+    ("brainstorm.md", include_str!("../../prompts/brainstorm.md")),
+];
+
+#[derive(Error, Debug)]
+pub enum PromptError {
+    #[error("Failed to read prompt templates from {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Invalid prompt template {source_name}: {message}")]
+    Invalid {
+        source_name: String,
+        message: String,
+    },
+}
+
+/// Argument declared in a template's front matter
+#[derive(Debug, Clone, Deserialize)]
+pub struct TemplateArgument {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+    /// Substituted when the client omits the argument
+    #[serde(default)]
+    pub default: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FrontMatter {
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    arguments: Vec<TemplateArgument>,
+}
+
+/// A parsed prompt template
+#[derive(Debug, Clone)]
+pub struct PromptTemplate {
+    pub name: String,
+    pub description: Option<String>,
+    pub arguments: Vec<TemplateArgument>,
+    pub body: String,
+}
+
+impl PromptTemplate {
+    /// Parse a template file; `source_name` is only used in error messages
+    ///
+    /// Every `{{placeholder}}` in the body must be a declared argument, so
+    /// typos are caught at load time rather than rendered verbatim.
+    pub fn parse(source_name: &str, text: &str) -> Result<Self, PromptError> {
+        let invalid = |message: String| PromptError::Invalid {
+            source_name: source_name.to_string(),
+            message,
+        };
+
+        let text = text.replace("\r\n", "\n");
+        let rest = text
+            .strip_prefix("---\n")
+            .ok_or_else(|| invalid("missing YAML front matter (start with ---)".to_string()))?;
+        let (front, body) = rest
+            .split_once("\n---\n")
+            .or_else(|| rest.strip_suffix("\n---").map(|front| (front, "")))
+            .ok_or_else(|| invalid("unterminated front matter (close it with ---)".to_string()))?;
+
+        let front: FrontMatter = serde_yaml::from_str(front).map_err(|e| invalid(e.to_string()))?;
+        if front.name.trim().is_empty() {
+            return Err(invalid("name must not be empty".to_string()));
+        }
+
+        let template = Self {
+            name: front.name,
+            description: front.description,
+            arguments: front.arguments,
+            body: body.trim().to_string(),
+        };
+        for placeholder in placeholders(&template.body) {
+            if template.argument(placeholder).is_none() {
+                return Err(invalid(format!(
+                    "{{{{{}}}}} is not a declared argument",
+                    placeholder
+                )));
+            }
+        }
+        Ok(template)
+    }
+
+    fn argument(&self, name: &str) -> Option<&TemplateArgument> {
+        self.arguments.iter().find(|a| a.name == name)
+    }
+
+    /// Protocol description for `prompts/list`
+    pub fn prompt(&self) -> Prompt {
+        Prompt {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            arguments: self
+                .arguments
+                .iter()
+                .map(|a| PromptArgument {
+                    name: a.name.clone(),
+                    description: a.description.clone(),
+                    required: a.required,
+                })
+                .collect(),
+        }
+    }
+
+    /// Substitute arguments into the body
+    ///
+    /// Omitted optional arguments fall back to their default, or to nothing.
+    pub fn render(&self, arguments: &HashMap<String, String>) -> Result<String, JsonRpcError> {
+        let missing: Vec<&str> = self
+            .arguments
+            .iter()
+            .filter(|a| a.required && arguments.get(&a.name).is_none_or(|v| v.trim().is_empty()))
+            .map(|a| a.name.as_str())
+            .collect();
+        if !missing.is_empty() {
+            return Err(JsonRpcError::invalid_params(format!(
+                "Missing required arguments for prompt {}: {}",
+                self.name,
+                missing.join(", ")
+            )));
+        }
+
+        let mut rendered = String::with_capacity(self.body.len());
+        let mut rest = self.body.as_str();
+        while let Some((before, name, after)) = next_placeholder(rest) {
+            rendered.push_str(before);
+            let value = arguments
+                .get(name)
+                .filter(|v| !v.trim().is_empty())
+                .or_else(|| self.argument(name).and_then(|a| a.default.as_ref()));
+            rendered.push_str(value.map(String::as_str).unwrap_or_default());
+            rest = after;
+        }
+        rendered.push_str(rest);
+        Ok(rendered)
+    }
+}
+
+/// Split `text` at its first `{{name}}`: (text before, name, text after)
+fn next_placeholder(text: &str) -> Option<(&str, &str, &str)> {
+    let start = text.find("{{")?;
+    let end = start + 2 + text[start + 2..].find("}}")?;
+    Some((
+        &text[..start],
+        text[start + 2..end].trim(),
+        &text[end + 2..],
+    ))
+}
+
+fn placeholders(text: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut rest = text;
+    while let Some((_, name, after)) = next_placeholder(rest) {
+        names.push(name);
+        rest = after;
+    }
+    names
+}
+
+/// Prompt templates served by `prompts/list` and `prompts/get`
+#[derive(Debug, Clone)]
+pub struct PromptLibrary {
+    templates: Vec<PromptTemplate>,
+}
+
+impl PromptLibrary {
+    /// Templates shipped with the server (retrospective, story map, kanban, brainstorm)
+    pub fn builtin() -> Self {
+        let templates = BUILTIN
+            .iter()
+            .map(|(file, text)| {
+                PromptTemplate::parse(file, text).expect("built-in prompt templates are valid")
+            })
+            .collect();
+        Self { templates }
+    }
+
+    /// Add every `*.md` template in `dir`
+    ///
+    /// Templates replace already-loaded ones with the same name, so teams
+    /// can customize the built-ins.
+    pub fn with_dir(mut self, dir: &Path) -> Result<Self, PromptError> {
+        let io_error = |source| PromptError::Io {
+            path: dir.to_path_buf(),
+            source,
+        };
+
+        let mut files: Vec<PathBuf> = std::fs::read_dir(dir)
+            .map_err(io_error)?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+            .collect();
+        files.sort();
+
+        for path in files {
+            let text = std::fs::read_to_string(&path).map_err(|source| PromptError::Io {
+                path: path.clone(),
+                source,
+            })?;
+            let template = PromptTemplate::parse(&path.display().to_string(), &text)?;
+            info!(prompt = %template.name, path = %path.display(), "Loaded prompt template");
+            self.insert(template);
+        }
+        Ok(self)
+    }
+
+    fn insert(&mut self, template: PromptTemplate) {
+        match self.templates.iter_mut().find(|t| t.name == template.name) {
+            Some(existing) => *existing = template,
+            None => self.templates.push(template),
+        }
+    }
+
+    /// Prompt descriptions for `prompts/list`
+    pub fn prompts(&self) -> Vec<Prompt> {
+        self.templates.iter().map(PromptTemplate::prompt).collect()
+    }
+
+    /// Render a prompt for `prompts/get`
+    pub fn get(
+        &self,
+        name: &str,
+        arguments: &HashMap<String, String>,
+    ) -> Result<GetPromptResult, JsonRpcError> {
+        let template = self
+            .templates
+            .iter()
+            .find(|t| t.name == name)
+            .ok_or_else(|| JsonRpcError::invalid_params(format!("Unknown prompt: {}", name)))?;
+
+        Ok(GetPromptResult {
+            description: template.description.clone(),
+            messages: vec![PromptMessage {
+                role: "user".to_string(),
+                content: Content::Text {
+                    text: template.render(arguments)?,
+                },
+            }],
+        })
+    }
+}
+
+impl Default for PromptLibrary {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::protocol::error_codes;
+
+    const TEMPLATE: &str = "---\nname: demo\ndescription: Demo\narguments:\n  - name: topic\n    required: true\n  - name: size\n    default: \"3\"\n  - name: note\n---\nMake {{ topic }} x{{size}}.{{note}}\n";
+
+    fn args(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_render_substitutes_arguments_and_defaults() {
+        let template = PromptTemplate::parse("demo.md", TEMPLATE).unwrap();
+        assert_eq!(
+            template.render(&args(&[("topic", "ideas")])).unwrap(),
+            "Make ideas x3."
+        );
+        assert_eq!(
+            template
+                .render(&args(&[
+                    ("topic", "ideas"),
+                    ("size", "5"),
+                    ("note", " Go!")
+                ]))
+                .unwrap(),
+            "Make ideas x5. Go!"
+        );
+    }
+
+    #[test]
+    fn test_missing_required_argument() {
+        let template = PromptTemplate::parse("demo.md", TEMPLATE).unwrap();
+        let error = template.render(&args(&[("topic", " ")])).unwrap_err();
+        assert_eq!(error.code, error_codes::INVALID_PARAMS);
+        assert!(error.message.contains("topic"));
+    }
+
+    #[test]
+    fn test_undeclared_placeholder_is_rejected() {
+        let error = PromptTemplate::parse("bad.md", "---\nname: bad\n---\nHello {{who}}\n")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("bad.md"), "{}", error);
+        assert!(error.contains("{{who}}"), "{}", error);
+
+        assert!(PromptTemplate::parse("plain.md", "no front matter").is_err());
+    }
+
+    #[test]
+    fn test_builtin_prompts() {
+        let library = PromptLibrary::builtin();
+        let names: Vec<String> = library.prompts().into_iter().map(|p| p.name).collect();
+        assert_eq!(
+            names,
+            ["retrospective", "story-map", "kanban", "brainstorm"]
+        );
+
+        let result = library
+            .get("story-map", &args(&[("product", "Checkout")]))
+            .unwrap();
+        let Content::Text { text } = &result.messages[0].content;
+        assert!(text.contains("user story map for Checkout"));
+        assert!(text.contains("MVP, Release 2, Later"));
+        assert!(!text.contains("{{"));
+    }
+
+    #[test]
+    fn test_directory_templates_add_and_override() {
+        let dir = std::env::temp_dir().join(format!("miro-prompts-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("kanban.md"),
+            "---\nname: kanban\n---\nOur kanban\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("okr.md"), "---\nname: okr\n---\nOKR board\n").unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let library = PromptLibrary::builtin().with_dir(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let names: Vec<String> = library.prompts().into_iter().map(|p| p.name).collect();
+        assert_eq!(
+            names,
+            ["retrospective", "story-map", "kanban", "brainstorm", "okr"]
+        );
+        let kanban = library.get("kanban", &HashMap::new()).unwrap();
+        let Content::Text { text } = &kanban.messages[0].content;
+        assert_eq!(text, "Our kanban");
+    }
+}
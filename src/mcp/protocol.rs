@@ -0,0 +1,539 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// JSON-RPC protocol version (always "2.0")
+pub const JSONRPC_VERSION: &str = "2.0";
+
+/// Latest MCP protocol revision implemented by this server
+pub const LATEST_PROTOCOL_VERSION: &str = "2025-06-18";
+
+/// MCP protocol revisions we can negotiate with clients (newest first)
+pub const SUPPORTED_PROTOCOL_VERSIONS: &[&str] = &["2025-06-18", "2025-03-26", "2024-11-05"];
+
+/// Standard JSON-RPC 2.0 error codes
+pub mod error_codes {
+    /// Invalid JSON was received
+    pub const PARSE_ERROR: i32 = -32700;
+    /// The JSON sent is not a valid Request object
+    pub const INVALID_REQUEST: i32 = -32600;
+    /// The method does not exist / is not available
+    pub const METHOD_NOT_FOUND: i32 = -32601;
+    /// Invalid method parameter(s)
+    pub const INVALID_PARAMS: i32 = -32602;
+    /// Internal JSON-RPC error
+    pub const INTERNAL_ERROR: i32 = -32603;
+    /// Server-defined: the access token lacks a scope the method needs
+    pub const INSUFFICIENT_SCOPE: i32 = -32003;
+    /// MCP: the requested resource URI does not exist
+    pub const RESOURCE_NOT_FOUND: i32 = -32002;
+    /// Server-defined: Miro rejected the access token; re-authenticate
+    pub const UNAUTHORIZED: i32 = -32001;
+    /// Server-defined: a Miro API call failed; `data.error` has the details
+    pub const MIRO_API_ERROR: i32 = -32004;
+    /// The client cancelled the request (`notifications/cancelled`)
+    pub const REQUEST_CANCELLED: i32 = -32800;
+}
+
+/// JSON-RPC 2.0 request (or notification when `id` is absent)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonRpcRequest {
+    pub jsonrpc: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Value>,
+    pub method: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<Value>,
+}
+
+impl JsonRpcRequest {
+    /// Build a notification (no id) for server-to-client messages
+    pub fn notification(method: impl Into<String>, params: Value) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id: None,
+            method: method.into(),
+            params: Some(params),
+        }
+    }
+
+    /// Notifications carry no id and expect no response
+    pub fn is_notification(&self) -> bool {
+        self.id.is_none()
+    }
+}
+
+/// JSON-RPC 2.0 error object
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonRpcError {
+    pub code: i32,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl JsonRpcError {
+    pub fn new(code: i32, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+
+    pub fn parse_error(message: impl Into<String>) -> Self {
+        Self::new(error_codes::PARSE_ERROR, message)
+    }
+
+    pub fn invalid_request(message: impl Into<String>) -> Self {
+        Self::new(error_codes::INVALID_REQUEST, message)
+    }
+
+    pub fn method_not_found(method: &str) -> Self {
+        Self::new(
+            error_codes::METHOD_NOT_FOUND,
+            format!("Method not found: {}", method),
+        )
+    }
+
+    pub fn invalid_params(message: impl Into<String>) -> Self {
+        Self::new(error_codes::INVALID_PARAMS, message)
+    }
+
+    pub fn internal_error(message: impl Into<String>) -> Self {
+        Self::new(error_codes::INTERNAL_ERROR, message)
+    }
+
+    /// MCP "resource not found" for `uri`
+    pub fn resource_not_found(uri: &str) -> Self {
+        Self::new(
+            error_codes::RESOURCE_NOT_FOUND,
+            format!("Resource not found: {}", uri),
+        )
+        .with_data(serde_json::json!({ "uri": uri }))
+    }
+
+    /// Attach structured error details
+    pub fn with_data(mut self, data: Value) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    /// Token lacks `scope`; `data` carries the RFC 6750 error and scope
+    pub fn insufficient_scope(scope: &str) -> Self {
+        Self {
+            code: error_codes::INSUFFICIENT_SCOPE,
+            message: format!("Insufficient scope: {} required", scope),
+            data: Some(serde_json::json!({
+                "error": "insufficient_scope",
+                "scope": scope,
+            })),
+        }
+    }
+
+    /// Miro rejected the caller's token; `data` carries the RFC 6750 error
+    pub fn unauthorized() -> Self {
+        Self::new(
+            error_codes::UNAUTHORIZED,
+            "Miro rejected the access token - re-authenticate",
+        )
+        .with_data(serde_json::json!({ "error": "invalid_token" }))
+    }
+
+    /// Miro API failure outside a tool call
+    pub fn miro_api_error(message: impl Into<String>) -> Self {
+        Self::new(error_codes::MIRO_API_ERROR, message)
+    }
+
+    pub fn request_cancelled() -> Self {
+        Self::new(error_codes::REQUEST_CANCELLED, "Request cancelled")
+    }
+}
+
+/// JSON-RPC 2.0 response (exactly one of `result` or `error` is set)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonRpcResponse {
+    pub jsonrpc: String,
+    pub id: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<JsonRpcError>,
+}
+
+impl JsonRpcResponse {
+    pub fn success(id: Value, result: Value) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    pub fn error(id: Value, error: JsonRpcError) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id,
+            result: None,
+            error: Some(error),
+        }
+    }
+}
+
+/// Name and version of an MCP implementation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Implementation {
+    pub name: String,
+    pub version: String,
+}
+
+/// Tools capability advertised during initialization
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolsCapability {
+    pub list_changed: bool,
+}
+
+/// Resources capability advertised during initialization
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourcesCapability {
+    pub subscribe: bool,
+    pub list_changed: bool,
+}
+
+/// Prompts capability advertised during initialization
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptsCapability {
+    pub list_changed: bool,
+}
+
+/// Logging capability: the client may call `logging/setLevel`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LoggingCapability {}
+
+/// Capabilities advertised by this server
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ServerCapabilities {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<ToolsCapability>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<ResourcesCapability>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompts: Option<PromptsCapability>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logging: Option<LoggingCapability>,
+}
+
+/// Params of the `initialize` request (only the fields we use)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitializeParams {
+    #[serde(default)]
+    pub protocol_version: Option<String>,
+    #[serde(default)]
+    pub client_info: Option<Implementation>,
+}
+
+/// Result of the `initialize` request
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitializeResult {
+    pub protocol_version: String,
+    pub capabilities: ServerCapabilities,
+    pub server_info: Implementation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+}
+
+/// Tool definition returned by `tools/list`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tool {
+    pub name: String,
+    pub description: String,
+    pub input_schema: Value,
+    /// Schema of `structuredContent` in successful results
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_schema: Option<Value>,
+}
+
+/// Result of the `tools/list` request
+#[derive(Debug, Clone, Serialize)]
+pub struct ListToolsResult {
+    pub tools: Vec<Tool>,
+}
+
+/// Params of the `tools/call` request
+#[derive(Debug, Clone, Deserialize)]
+pub struct CallToolParams {
+    pub name: String,
+    #[serde(default)]
+    pub arguments: Option<Value>,
+    #[serde(rename = "_meta", default)]
+    pub meta: Option<RequestMeta>,
+}
+
+/// `_meta` of a request
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestMeta {
+    /// Opaque token the client wants `notifications/progress` tagged with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress_token: Option<Value>,
+}
+
+/// Params of `notifications/cancelled`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelledParams {
+    pub request_id: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Severity of a log message (RFC 5424 levels, least severe first)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoggingLevel {
+    Debug,
+    Info,
+    Notice,
+    Warning,
+    Error,
+    Critical,
+    Alert,
+    Emergency,
+}
+
+/// Params of `logging/setLevel`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetLevelParams {
+    pub level: LoggingLevel,
+}
+
+/// Params of `notifications/message`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoggingMessageParams {
+    pub level: LoggingLevel,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logger: Option<String>,
+    pub data: Value,
+}
+
+/// Content block returned inside a tool result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Content {
+    Text { text: String },
+}
+
+/// Result of the `tools/call` request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallToolResult {
+    pub content: Vec<Content>,
+    /// Machine-readable result matching the tool's `outputSchema`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub structured_content: Option<Value>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_error: bool,
+}
+
+impl CallToolResult {
+    /// Successful result with a single text block
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            content: vec![Content::Text { text: text.into() }],
+            structured_content: None,
+            is_error: false,
+        }
+    }
+
+    /// Successful result rendering `value` as pretty-printed JSON
+    pub fn json<T: Serialize>(value: &T) -> Result<Self, serde_json::Error> {
+        Ok(Self::text(serde_json::to_string_pretty(value)?))
+    }
+
+    /// Successful result carrying `value` as `structuredContent`
+    ///
+    /// Also rendered as a JSON text block for clients without structured
+    /// output support.
+    pub fn structured<T: Serialize>(value: &T) -> Result<Self, serde_json::Error> {
+        let structured = serde_json::to_value(value)?;
+        Ok(Self {
+            structured_content: Some(structured.clone()),
+            ..Self::text(serde_json::to_string_pretty(&structured)?)
+        })
+    }
+
+    /// Tool-level failure, visible to the model (not a protocol error)
+    pub fn error(text: impl Into<String>) -> Self {
+        Self {
+            content: vec![Content::Text { text: text.into() }],
+            structured_content: None,
+            is_error: true,
+        }
+    }
+}
+
+/// Resource listed by `resources/list`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Resource {
+    pub uri: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+}
+
+/// Parameterized resource advertised by `resources/templates/list` (RFC 6570)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceTemplate {
+    pub uri_template: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+}
+
+/// Result of the `resources/list` request
+#[derive(Debug, Clone, Serialize)]
+pub struct ListResourcesResult {
+    pub resources: Vec<Resource>,
+}
+
+/// Result of the `resources/templates/list` request
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListResourceTemplatesResult {
+    pub resource_templates: Vec<ResourceTemplate>,
+}
+
+/// Params of the `resources/read`, `resources/subscribe` and
+/// `resources/unsubscribe` requests
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResourceUriParams {
+    pub uri: String,
+}
+
+/// Text contents of a resource
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceContents {
+    pub uri: String,
+    pub mime_type: String,
+    pub text: String,
+}
+
+/// Result of the `resources/read` request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadResourceResult {
+    pub contents: Vec<ResourceContents>,
+}
+
+/// Argument accepted by a prompt
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PromptArgument {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// Prompt listed by `prompts/list`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Prompt {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub arguments: Vec<PromptArgument>,
+}
+
+/// Result of the `prompts/list` request
+#[derive(Debug, Clone, Serialize)]
+pub struct ListPromptsResult {
+    pub prompts: Vec<Prompt>,
+}
+
+/// Params of the `prompts/get` request
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetPromptParams {
+    pub name: String,
+    #[serde(default)]
+    pub arguments: HashMap<String, String>,
+}
+
+/// One message of a rendered prompt
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptMessage {
+    pub role: String,
+    pub content: Content,
+}
+
+/// Result of the `prompts/get` request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetPromptResult {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub messages: Vec<PromptMessage>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_deserialization() {
+        let json = r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#;
+        let request: JsonRpcRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.method, "tools/list");
+        assert!(!request.is_notification());
+        assert!(request.params.is_none());
+    }
+
+    #[test]
+    fn test_notification_has_no_id() {
+        let json = r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#;
+        let request: JsonRpcRequest = serde_json::from_str(json).unwrap();
+        assert!(request.is_notification());
+    }
+
+    #[test]
+    fn test_success_response_omits_error() {
+        let response = JsonRpcResponse::success(serde_json::json!(1), serde_json::json!({}));
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(json.contains("\"jsonrpc\":\"2.0\""));
+        assert!(json.contains("\"result\""));
+        assert!(!json.contains("\"error\""));
+    }
+
+    #[test]
+    fn test_error_response_omits_result() {
+        let response =
+            JsonRpcResponse::error(Value::Null, JsonRpcError::method_not_found("foo/bar"));
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(json.contains("-32601"));
+        assert!(json.contains("foo/bar"));
+        assert!(!json.contains("\"result\""));
+    }
+
+    #[test]
+    fn test_call_tool_result_serialization() {
+        let ok = serde_json::to_value(CallToolResult::text("done")).unwrap();
+        assert_eq!(ok["content"][0]["type"], "text");
+        assert_eq!(ok["content"][0]["text"], "done");
+        assert!(ok.get("isError").is_none());
+
+        let err = serde_json::to_value(CallToolResult::error("boom")).unwrap();
+        assert_eq!(err["isError"], true);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::mcp::context::ToolContext;
+use crate::mcp::errors::{self, ToolError};
+use crate::mcp::protocol::{CallToolResult, JsonRpcError, Tool};
+use crate::mcp::schema;
+use crate::miro::{MiroApi, MiroError};
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use tracing::warn;
+
+/// A typed MCP tool backed by Miro API operations
+///
+/// `inputSchema` and `outputSchema` in `tools/list` are generated from
+/// [`Input`](Self::Input) and [`Output`](Self::Output), and arguments are
+/// validated against the input schema before [`call`](Self::call) runs.
+#[async_trait]
+pub trait ToolHandler: Send + Sync + 'static {
+    /// Tool arguments
+    type Input: DeserializeOwned + JsonSchema + Send;
+    /// Successful result; must serialize to a JSON object
+    type Output: Serialize + JsonSchema;
+
+    /// Tool name, unique within a registry
+    fn name(&self) -> &'static str;
+
+    /// Human-readable description shown to the model
+    fn description(&self) -> &'static str;
+
+    /// OAuth scope the caller's token must grant
+    fn scope(&self) -> &'static str;
+
+    /// Run the tool
+    ///
+    /// Long-running tools report through `ctx.progress` and stop early when
+    /// `ctx` is cancelled; `client` already fails fast after cancellation.
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        input: Self::Input,
+        ctx: &ToolContext,
+    ) -> Result<Self::Output, MiroError>;
+}
+
+/// Type-erased registry entry
+#[async_trait]
+trait RegisteredTool: Send + Sync {
+    fn definition(&self) -> &Tool;
+    fn scope(&self) -> &'static str;
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        arguments: Value,
+        ctx: &ToolContext,
+    ) -> Result<CallToolResult, JsonRpcError>;
+}
+
+struct Entry<H> {
+    handler: H,
+    definition: Tool,
+}
+
+#[async_trait]
+impl<H: ToolHandler> RegisteredTool for Entry<H> {
+    fn definition(&self) -> &Tool {
+        &self.definition
+    }
+
+    fn scope(&self) -> &'static str {
+        self.handler.scope()
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        arguments: Value,
+        ctx: &ToolContext,
+    ) -> Result<CallToolResult, JsonRpcError> {
+        let name = self.handler.name();
+
+        let errors = schema::validate(&self.definition.input_schema, &arguments);
+        if !errors.is_empty() {
+            return Ok(rejected(ToolError::invalid_arguments(name, None, &errors)));
+        }
+
+        let input: H::Input = match serde_json::from_value(arguments) {
+            Ok(input) => input,
+            Err(e) => {
+                let detail = e.to_string();
+                return Ok(rejected(ToolError::invalid_arguments(
+                    name,
+                    Some(&detail),
+                    &[],
+                )));
+            }
+        };
+
+        match self.handler.call(client, input, ctx).await {
+            Ok(output) => CallToolResult::structured(&output)
+                .map_err(|e| JsonRpcError::internal_error(format!("Serialization failed: {}", e))),
+            Err(e) => {
+                warn!(tool = %name, error = %e, "Tool call failed");
+                errors::tool_result(e)
+            }
+        }
+    }
+}
+
+/// `isError` result for arguments the tool never ran with
+fn rejected(error: ToolError) -> CallToolResult {
+    warn!(error = %error.message, fields = error.fields.len(), "Tool arguments rejected");
+    error.into_result()
+}
+
+/// Set of tools served over MCP (shared by the HTTP and stdio transports)
+#[derive(Default)]
+pub struct ToolRegistry {
+    tools: Vec<Box<dyn RegisteredTool>>,
+}
+
+impl ToolRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a tool; schemas are generated once, here
+    ///
+    /// # Panics
+    ///
+    /// If a tool with the same name is already registered.
+    pub fn register<H: ToolHandler>(mut self, handler: H) -> Self {
+        assert!(
+            self.find(handler.name()).is_none(),
+            "tool {} registered twice",
+            handler.name()
+        );
+
+        let definition = Tool {
+            name: handler.name().to_string(),
+            description: handler.description().to_string(),
+            input_schema: schema::input_schema::<H::Input>(),
+            output_schema: Some(schema::output_schema::<H::Output>()),
+        };
+        self.tools.push(Box::new(Entry {
+            handler,
+            definition,
+        }));
+        self
+    }
+
+    /// Tool definitions, in registration order
+    pub fn tools(&self) -> Vec<Tool> {
+        self.tools.iter().map(|t| t.definition().clone()).collect()
+    }
+
+    /// Scope required to call a tool (`None` for unknown tools)
+    pub fn required_scope(&self, name: &str) -> Option<&'static str> {
+        self.find(name).map(|t| t.scope())
+    }
+
+    /// Validate arguments and execute a tool
+    ///
+    /// Returns a JSON-RPC error for unknown tools. Invalid arguments become
+    /// `isError` results listing each field; Miro failures are mapped by
+    /// [`errors::tool_result`].
+    pub async fn call(
+        &self,
+        client: &dyn MiroApi,
+        name: &str,
+        arguments: Value,
+        ctx: &ToolContext,
+    ) -> Result<CallToolResult, JsonRpcError> {
+        match self.find(name) {
+            Some(tool) => tool.call(client, arguments, ctx).await,
+            None => Err(JsonRpcError::invalid_params(format!(
+                "Unknown tool: {}",
+                name
+            ))),
+        }
+    }
+
+    fn find(&self, name: &str) -> Option<&dyn RegisteredTool> {
+        self.tools
+            .iter()
+            .find(|t| t.definition().name == name)
+            .map(|t| t.as_ref())
+    }
+}
@@ -0,0 +1,484 @@
+//! MCP resources: read-only views of boards and their items
+//!
+//! URIs served:
+//! - `miro://boards` - all boards visible to the token (JSON)
+//! - `miro://boards/{board_id}` - a board summary with items grouped by frame (markdown)
+//! - `miro://boards/{board_id}/items{?type,parent_id,format}` - board items
+//!   (compact JSON, or markdown with `format=markdown`)
+
+use crate::mcp::errors;
+use crate::mcp::protocol::{
+    JsonRpcError, ReadResourceResult, Resource, ResourceContents, ResourceTemplate,
+};
+use crate::miro::types::{Board, Item};
+use crate::miro::{MiroApi, MiroError};
+use serde_json::{json, Value};
+use std::fmt::Write;
+use url::Url;
+
+pub const SCHEME: &str = "miro";
+pub const BOARDS_URI: &str = "miro://boards";
+
+const JSON_MIME: &str = "application/json";
+const MARKDOWN_MIME: &str = "text/markdown";
+
+/// Rendering of an items resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Markdown,
+}
+
+/// A parsed `miro://` resource URI
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceUri {
+    Boards,
+    Board {
+        board_id: String,
+    },
+    Items {
+        board_id: String,
+        item_types: Vec<String>,
+        parent_id: Option<String>,
+        format: Format,
+    },
+}
+
+impl ResourceUri {
+    /// Parse a resource URI, returning `None` for anything this server does not serve
+    ///
+    /// `type` may be repeated or comma-separated (`?type=sticky_note,text`).
+    pub fn parse(uri: &str) -> Option<Self> {
+        let url = Url::parse(uri).ok()?;
+        if url.scheme() != SCHEME || url.host_str() != Some("boards") {
+            return None;
+        }
+
+        let segments: Vec<&str> = url
+            .path_segments()
+            .map(|s| s.filter(|segment| !segment.is_empty()).collect())
+            .unwrap_or_default();
+
+        match segments.as_slice() {
+            [] => Some(Self::Boards),
+            [board_id] => Some(Self::Board {
+                board_id: board_id.to_string(),
+            }),
+            [board_id, "items"] => {
+                let mut item_types = Vec::new();
+                let mut parent_id = None;
+                let mut format = Format::Json;
+                for (key, value) in url.query_pairs() {
+                    match key.as_ref() {
+                        "type" => item_types.extend(
+                            value
+                                .split(',')
+                                .map(str::trim)
+                                .filter(|t| !t.is_empty())
+                                .map(String::from),
+                        ),
+                        "parent_id" => parent_id = Some(value.into_owned()),
+                        "format" => {
+                            format = match value.as_ref() {
+                                "json" => Format::Json,
+                                "markdown" | "md" => Format::Markdown,
+                                _ => return None,
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Some(Self::Items {
+                    board_id: board_id.to_string(),
+                    item_types,
+                    parent_id,
+                    format,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Static and per-board resources for `resources/list`
+pub async fn list_resources(client: &dyn MiroApi) -> Result<Vec<Resource>, MiroError> {
+    let boards = client.list_boards().await?.items;
+
+    let mut resources = Vec::with_capacity(boards.len() + 1);
+    resources.push(Resource {
+        uri: BOARDS_URI.to_string(),
+        name: "Boards".to_string(),
+        description: Some("All Miro boards accessible with this token".to_string()),
+        mime_type: Some(JSON_MIME.to_string()),
+    });
+    resources.extend(boards.into_iter().map(|board| Resource {
+        uri: board_uri(&board.id),
+        name: board.name,
+        description: board.description.filter(|d| !d.is_empty()),
+        mime_type: Some(MARKDOWN_MIME.to_string()),
+    }));
+
+    Ok(resources)
+}
+
+/// Parameterized resources for `resources/templates/list`
+pub fn templates() -> Vec<ResourceTemplate> {
+    vec![
+        ResourceTemplate {
+            uri_template: "miro://boards/{board_id}".to_string(),
+            name: "Board".to_string(),
+            description: Some("Board summary with items grouped by frame".to_string()),
+            mime_type: Some(MARKDOWN_MIME.to_string()),
+        },
+        ResourceTemplate {
+            uri_template: "miro://boards/{board_id}/items{?type,parent_id,format}".to_string(),
+            name: "Board items".to_string(),
+            description: Some(
+                "Items on a board, optionally filtered by type (comma-separated) or parent \
+                 frame; format=markdown for a readable list"
+                    .to_string(),
+            ),
+            mime_type: Some(JSON_MIME.to_string()),
+        },
+    ]
+}
+
+/// Fetch and render a resource for `resources/read`
+pub async fn read_resource(
+    client: &dyn MiroApi,
+    uri: &str,
+) -> Result<ReadResourceResult, JsonRpcError> {
+    let parsed = ResourceUri::parse(uri).ok_or_else(|| JsonRpcError::resource_not_found(uri))?;
+    let not_found_or_internal = |e: MiroError| match e {
+        MiroError::NotFound(_) => JsonRpcError::resource_not_found(uri),
+        other => errors::rpc_error(other),
+    };
+
+    let (mime_type, text) = match parsed {
+        ResourceUri::Boards => {
+            let boards = client.list_boards().await.map_err(not_found_or_internal)?;
+            (JSON_MIME, boards_json(&boards.items).to_string())
+        }
+        ResourceUri::Board { board_id } => {
+            let board = client
+                .get_board(&board_id)
+                .await
+                .map_err(not_found_or_internal)?;
+            let items = client
+                .list_items(&board_id, None, None)
+                .await
+                .map_err(not_found_or_internal)?;
+            let mut text = board_markdown(&board, &items.items);
+            if items.truncated {
+                text.push_str(&truncation_note(items.items.len()));
+            }
+            (MARKDOWN_MIME, text)
+        }
+        ResourceUri::Items {
+            board_id,
+            item_types,
+            parent_id,
+            format,
+        } => {
+            let types: Vec<&str> = item_types.iter().map(String::as_str).collect();
+            let items = client
+                .list_items(
+                    &board_id,
+                    (!types.is_empty()).then_some(types),
+                    parent_id.as_deref(),
+                )
+                .await
+                .map_err(not_found_or_internal)?;
+            match format {
+                Format::Json => (JSON_MIME, items_json(&items.items).to_string()),
+                Format::Markdown => {
+                    let mut text = items_markdown(&items.items);
+                    if items.truncated {
+                        text.push_str(&truncation_note(items.items.len()));
+                    }
+                    (MARKDOWN_MIME, text)
+                }
+            }
+        }
+    };
+
+    Ok(ReadResourceResult {
+        contents: vec![ResourceContents {
+            uri: uri.to_string(),
+            mime_type: mime_type.to_string(),
+            text,
+        }],
+    })
+}
+
+/// Markdown footer for listings cut off at the item cap
+fn truncation_note(shown: usize) -> String {
+    format!(
+        "\n_Only the first {} items are shown; the board has more._\n",
+        shown
+    )
+}
+
+fn board_uri(board_id: &str) -> String {
+    format!("{}/{}", BOARDS_URI, board_id)
+}
+
+fn boards_json(boards: &[Board]) -> Value {
+    Value::Array(
+        boards
+            .iter()
+            .map(|board| {
+                let mut value = json!({
+                    "id": board.id,
+                    "name": board.name,
+                    "uri": board_uri(&board.id),
+                });
+                if let Some(description) = board.description.as_deref().filter(|d| !d.is_empty()) {
+                    value["description"] = json!(description);
+                }
+                value
+            })
+            .collect(),
+    )
+}
+
+/// Compact JSON for items: id, type, plain text, position and parent only
+pub fn items_json(items: &[Item]) -> Value {
+    Value::Array(items.iter().map(compact_item).collect())
+}
+
+fn compact_item(item: &Item) -> Value {
+    let mut value = json!({ "id": item.id, "type": item.item_type });
+    if let Some(text) = item_text(item) {
+        value["text"] = json!(text);
+    }
+    if let Some(position) = &item.position {
+        value["x"] = json!(position.x);
+        value["y"] = json!(position.y);
+    }
+    if let Some(parent) = &item.parent {
+        value["parent_id"] = json!(parent.id);
+    }
+    value
+}
+
+/// Markdown bullet list of items
+pub fn items_markdown(items: &[Item]) -> String {
+    let mut out = String::new();
+    for item in items {
+        push_item_line(&mut out, item);
+    }
+    if out.is_empty() {
+        out.push_str("_No items._\n");
+    }
+    out
+}
+
+/// Markdown summary of a board with items grouped under their frames
+pub fn board_markdown(board: &Board, items: &[Item]) -> String {
+    let mut out = format!("# {}\n\n", board.name);
+    if let Some(description) = board.description.as_deref().filter(|d| !d.is_empty()) {
+        let _ = writeln!(out, "{}\n", description);
+    }
+    let _ = writeln!(out, "Board ID: `{}` · {} items\n", board.id, items.len());
+
+    let frames: Vec<&Item> = items.iter().filter(|i| i.item_type == "frame").collect();
+    let in_frame =
+        |item: &Item, frame_id: &str| item.parent.as_ref().is_some_and(|p| p.id == frame_id);
+
+    for frame in &frames {
+        let title = item_text(frame).unwrap_or_else(|| "Untitled frame".to_string());
+        let _ = writeln!(out, "## {} (`{}`)\n", title, frame.id);
+        let children: Vec<Item> = items
+            .iter()
+            .filter(|i| in_frame(i, &frame.id))
+            .cloned()
+            .collect();
+        out.push_str(&items_markdown(&children));
+        out.push('\n');
+    }
+
+    let loose: Vec<Item> = items
+        .iter()
+        .filter(|i| i.item_type != "frame")
+        .filter(|i| !frames.iter().any(|f| in_frame(i, &f.id)))
+        .cloned()
+        .collect();
+    if !loose.is_empty() || frames.is_empty() {
+        if !frames.is_empty() {
+            out.push_str("## Outside frames\n\n");
+        }
+        out.push_str(&items_markdown(&loose));
+    }
+
+    out
+}
+
+fn push_item_line(out: &mut String, item: &Item) {
+    let _ = write!(out, "- **{}** `{}`", item.item_type, item.id);
+    if let Some(text) = item_text(item) {
+        let _ = write!(out, ": {}", text.replace('\n', " "));
+    }
+    if let Some(position) = &item.position {
+        let _ = write!(out, " @ ({}, {})", position.x, position.y);
+    }
+    out.push('\n');
+}
+
+/// Human-readable text of an item (`content` or `title`, HTML stripped)
+fn item_text(item: &Item) -> Option<String> {
+    let data = item.data.as_ref()?;
+    let raw = ["content", "title", "description"]
+        .iter()
+        .find_map(|key| data.get(*key).and_then(Value::as_str))?;
+    let text = strip_html(raw);
+    (!text.is_empty()).then_some(text)
+}
+
+/// Drop tags and decode the handful of entities Miro emits in rich text
+fn strip_html(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => {
+                in_tag = false;
+                // Keep words from adjacent paragraphs apart
+                if !text.ends_with(' ') {
+                    text.push(' ');
+                }
+            }
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    let text = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::miro::types::{Parent, Position};
+
+    fn item(id: &str, item_type: &str, data: Value, parent: Option<&str>) -> Item {
+        Item {
+            id: id.to_string(),
+            item_type: item_type.to_string(),
+            data: Some(data),
+            style: None,
+            position: Some(Position {
+                x: 1.0,
+                y: 2.5,
+                origin: None,
+            }),
+            geometry: None,
+            created_at: None,
+            modified_at: None,
+            parent: parent.map(|id| Parent { id: id.to_string() }),
+        }
+    }
+
+    #[test]
+    fn test_parse_uris() {
+        assert_eq!(
+            ResourceUri::parse("miro://boards"),
+            Some(ResourceUri::Boards)
+        );
+        assert_eq!(
+            ResourceUri::parse("miro://boards/uXjVN"),
+            Some(ResourceUri::Board {
+                board_id: "uXjVN".to_string()
+            })
+        );
+        assert_eq!(
+            ResourceUri::parse(
+                "miro://boards/uXjVN/items?type=sticky_note,text&type=frame&parent_id=9&format=markdown"
+            ),
+            Some(ResourceUri::Items {
+                board_id: "uXjVN".to_string(),
+                item_types: vec!["sticky_note".into(), "text".into(), "frame".into()],
+                parent_id: Some("9".to_string()),
+                format: Format::Markdown,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_foreign_uris() {
+        for uri in [
+            "https://boards/1",
+            "miro://widgets",
+            "miro://boards/1/connectors",
+            "miro://boards/1/items?format=xml",
+            "not a uri",
+        ] {
+            assert_eq!(ResourceUri::parse(uri), None, "{}", uri);
+        }
+    }
+
+    #[test]
+    fn test_compact_item_strips_html() {
+        let value = compact_item(&item(
+            "1",
+            "sticky_note",
+            json!({ "content": "<p>Fix &amp; ship</p><p>today</p>", "shape": "square" }),
+            Some("9"),
+        ));
+        assert_eq!(
+            value,
+            json!({
+                "id": "1",
+                "type": "sticky_note",
+                "text": "Fix & ship today",
+                "x": 1.0,
+                "y": 2.5,
+                "parent_id": "9"
+            })
+        );
+    }
+
+    #[test]
+    fn test_board_markdown_groups_by_frame() {
+        let board = Board {
+            id: "b1".to_string(),
+            name: "Retro".to_string(),
+            description: Some("Sprint 12".to_string()),
+            ..Default::default()
+        };
+        let items = vec![
+            item("9", "frame", json!({ "title": "Went well" }), None),
+            item(
+                "1",
+                "sticky_note",
+                json!({ "content": "<p>CI</p>" }),
+                Some("9"),
+            ),
+            item("2", "text", json!({ "content": "Loose note" }), None),
+        ];
+
+        let markdown = board_markdown(&board, &items);
+        assert!(markdown.starts_with("# Retro\n\nSprint 12\n"));
+        let frame = markdown.find("## Went well (`9`)").unwrap();
+        let sticky = markdown.find("- **sticky_note** `1`: CI").unwrap();
+        let outside = markdown.find("## Outside frames").unwrap();
+        let text = markdown.find("- **text** `2`: Loose note").unwrap();
+        assert!(frame < sticky && sticky < outside && outside < text);
+    }
+
+    #[test]
+    fn test_templates_are_rfc6570() {
+        let templates = templates();
+        assert!(templates
+            .iter()
+            .any(|t| t.uri_template == "miro://boards/{board_id}/items{?type,parent_id,format}"));
+    }
+}
@@ -0,0 +1,374 @@
+use schemars::generate::SchemaSettings;
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::fmt;
+
+/// JSON Schema for tool arguments of type `T` (as accepted by serde)
+///
+/// Subschemas are inlined so clients and [`validate`] never need to resolve
+/// `$ref`s.
+pub fn input_schema<T: JsonSchema>() -> Value {
+    generate::<T>(SchemaSettings::draft2020_12().for_deserialize())
+}
+
+/// JSON Schema for tool results of type `T` (as produced by serde)
+pub fn output_schema<T: JsonSchema>() -> Value {
+    generate::<T>(SchemaSettings::draft2020_12().for_serialize())
+}
+
+fn generate<T: JsonSchema>(settings: SchemaSettings) -> Value {
+    let generator = settings
+        .with(|s| {
+            s.inline_subschemas = true;
+            s.meta_schema = None;
+        })
+        .into_generator();
+    let mut schema = generator.into_root_schema_for::<T>().to_value();
+
+    if let Some(object) = schema.as_object_mut() {
+        // The Rust type name means nothing to clients; the tool name says it all
+        object.remove("title");
+        // Some MCP clients expect `properties` even for argument-less tools
+        if object.get("type") == Some(&json!("object")) {
+            object.entry("properties").or_insert_with(|| json!({}));
+        }
+    }
+    schema
+}
+
+/// One schema violation in tool arguments
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldError {
+    /// Path of the offending value (e.g., `items[0].position.x`)
+    pub field: String,
+    /// What is wrong with it
+    pub message: String,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Validate `value` against a JSON Schema, collecting every violation
+///
+/// Supports the keywords schemars emits for tool types: `type`, `enum`,
+/// `const`, `properties`, `required`, `additionalProperties`, `items`,
+/// length and range bounds, and `anyOf` / `oneOf` / `allOf`.
+pub fn validate(schema: &Value, value: &Value) -> Vec<FieldError> {
+    let mut errors = Vec::new();
+    check(schema, value, "arguments", &mut errors);
+    errors
+}
+
+fn check(schema: &Value, value: &Value, path: &str, errors: &mut Vec<FieldError>) {
+    let schema = match schema {
+        Value::Bool(true) => return,
+        Value::Bool(false) => return push(errors, path, "is not allowed"),
+        Value::Object(schema) => schema,
+        _ => return,
+    };
+
+    if let Some(alternatives) = schema
+        .get("anyOf")
+        .or_else(|| schema.get("oneOf"))
+        .and_then(Value::as_array)
+    {
+        check_alternatives(alternatives, value, path, errors);
+    }
+    if let Some(all) = schema.get("allOf").and_then(Value::as_array) {
+        for subschema in all {
+            check(subschema, value, path, errors);
+        }
+    }
+
+    if let Some(expected) = schema.get("type") {
+        if !type_matches(expected, value) {
+            let expected = match expected {
+                Value::Array(types) => types
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+                    .join(" or "),
+                other => other.as_str().unwrap_or_default().to_string(),
+            };
+            return push(
+                errors,
+                path,
+                format!("expected {}, got {}", expected, type_name(value)),
+            );
+        }
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            let allowed: Vec<String> = allowed.iter().map(Value::to_string).collect();
+            push(
+                errors,
+                path,
+                format!("must be one of {}", allowed.join(", ")),
+            );
+        }
+    }
+    if let Some(constant) = schema.get("const") {
+        if constant != value {
+            push(errors, path, format!("must be {}", constant));
+        }
+    }
+
+    match value {
+        Value::Number(number) => {
+            let Some(n) = number.as_f64() else { return };
+            let bound = |key: &str| schema.get(key).and_then(Value::as_f64);
+            if let Some(min) = bound("minimum").filter(|min| n < *min) {
+                push(errors, path, format!("must be at least {}", min));
+            }
+            if let Some(max) = bound("maximum").filter(|max| n > *max) {
+                push(errors, path, format!("must be at most {}", max));
+            }
+            if let Some(min) = bound("exclusiveMinimum").filter(|min| n <= *min) {
+                push(errors, path, format!("must be greater than {}", min));
+            }
+            if let Some(max) = bound("exclusiveMaximum").filter(|max| n >= *max) {
+                push(errors, path, format!("must be less than {}", max));
+            }
+        }
+        Value::String(s) => {
+            let len = s.chars().count() as u64;
+            if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+                if len < min {
+                    push(errors, path, format!("must be at least {} characters", min));
+                }
+            }
+            if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+                if len > max {
+                    push(errors, path, format!("must be at most {} characters", max));
+                }
+            }
+        }
+        Value::Array(items) => {
+            let len = items.len() as u64;
+            if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+                if len < min {
+                    push(errors, path, format!("must have at least {} items", min));
+                }
+            }
+            if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+                if len > max {
+                    push(errors, path, format!("must have at most {} items", max));
+                }
+            }
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    check(item_schema, item, &format!("{}[{}]", path, index), errors);
+                }
+            }
+        }
+        Value::Object(object) => {
+            let properties = schema.get("properties").and_then(Value::as_object);
+
+            if let Some(required) = schema.get("required").and_then(Value::as_array) {
+                for name in required.iter().filter_map(Value::as_str) {
+                    if !object.contains_key(name) {
+                        push(errors, &child(path, name), "is required");
+                    }
+                }
+            }
+
+            for (name, field) in object {
+                match properties.and_then(|p| p.get(name)) {
+                    Some(field_schema) => check(field_schema, field, &child(path, name), errors),
+                    None => {
+                        if let Some(additional) = schema.get("additionalProperties") {
+                            check(additional, field, &child(path, name), errors);
+                        }
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `anyOf` / `oneOf`: pass if any alternative matches
+///
+/// When exactly one alternative has the value's JSON type, its errors are
+/// reported (e.g., the fields of an optional object); otherwise a summary.
+fn check_alternatives(
+    alternatives: &[Value],
+    value: &Value,
+    path: &str,
+    errors: &mut Vec<FieldError>,
+) {
+    let mut candidates = Vec::new();
+    for alternative in alternatives {
+        let mut alternative_errors = Vec::new();
+        check(alternative, value, path, &mut alternative_errors);
+        if alternative_errors.is_empty() {
+            return;
+        }
+        let same_type = alternative
+            .get("type")
+            .is_some_and(|expected| type_matches(expected, value));
+        if same_type {
+            candidates.push(alternative_errors);
+        }
+    }
+
+    match candidates.len() {
+        1 => errors.extend(candidates.remove(0)),
+        _ => push(errors, path, "does not match any allowed shape"),
+    }
+}
+
+fn type_matches(expected: &Value, value: &Value) -> bool {
+    match expected {
+        Value::String(name) => is_type(name, value),
+        Value::Array(names) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|name| is_type(name, value)),
+        _ => true,
+    }
+}
+
+fn is_type(name: &str, value: &Value) -> bool {
+    match name {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn child(path: &str, name: &str) -> String {
+    match path {
+        "arguments" => name.to_string(),
+        _ => format!("{}.{}", path, name),
+    }
+}
+
+fn push(errors: &mut Vec<FieldError>, path: &str, message: impl Into<String>) {
+    errors.push(FieldError {
+        field: path.to_string(),
+        message: message.into(),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Deserialize, JsonSchema)]
+    #[allow(dead_code)]
+    struct Point {
+        x: f64,
+        y: f64,
+    }
+
+    #[derive(Deserialize, JsonSchema)]
+    #[allow(dead_code)]
+    struct Args {
+        /// Board to draw on
+        #[schemars(length(min = 1))]
+        board_id: String,
+        #[serde(default)]
+        position: Option<Point>,
+        #[schemars(length(min = 1, max = 2))]
+        tags: Vec<String>,
+        #[serde(default)]
+        limit: Option<u32>,
+    }
+
+    fn errors_for(value: Value) -> Vec<String> {
+        validate(&input_schema::<Args>(), &value)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_schema_is_inlined_object() {
+        let schema = input_schema::<Args>();
+        assert_eq!(schema["type"], "object");
+        assert!(schema.get("$defs").is_none());
+        assert!(schema.get("title").is_none());
+        assert_eq!(
+            schema["properties"]["board_id"]["description"],
+            "Board to draw on"
+        );
+    }
+
+    #[test]
+    fn test_valid_arguments_pass() {
+        let errors = errors_for(json!({
+            "board_id": "b1",
+            "position": { "x": 1.0, "y": 2 },
+            "tags": ["a"],
+            "limit": 5
+        }));
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_missing_and_mistyped_fields_reported_per_field() {
+        let errors = errors_for(json!({
+            "position": { "x": "left" },
+            "tags": [],
+            "limit": -1
+        }));
+        assert!(errors.contains(&"board_id: is required".to_string()));
+        assert!(errors.contains(&"position.x: expected number, got string".to_string()));
+        assert!(errors.contains(&"position.y: is required".to_string()));
+        assert!(errors.contains(&"tags: must have at least 1 items".to_string()));
+        assert!(errors.contains(&"limit: must be at least 0".to_string()));
+    }
+
+    #[test]
+    fn test_array_items_get_indexed_paths() {
+        let errors = errors_for(json!({ "board_id": "", "tags": ["a", 3] }));
+        assert_eq!(
+            errors,
+            vec![
+                "board_id: must be at least 1 characters",
+                "tags[1]: expected string, got number"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_struct_has_properties() {
+        #[derive(Deserialize, JsonSchema)]
+        struct Empty {}
+
+        let schema = input_schema::<Empty>();
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["properties"], json!({}));
+    }
+
+    #[test]
+    fn test_root_must_be_object() {
+        let errors = errors_for(json!([1]));
+        assert_eq!(errors, vec!["arguments: expected object, got array"]);
+    }
+}
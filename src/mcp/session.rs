@@ -0,0 +1,531 @@
+//! MCP sessions: per-client state that outlives a single request
+//!
+//! A session owns the client's resource subscriptions, its log level, and the
+//! bounded queue their notifications wait in until a transport delivers them
+//! (the SSE stream of `GET /mcp`, or stdout for stdio).
+//!
+//! Notifications delivered over SSE get increasing event ids and are kept in
+//! a bounded log, so a client that lost its connection can reconnect with
+//! `Last-Event-ID` and receive what it missed.
+
+use crate::mcp::logging::SessionLog;
+use crate::mcp::notifier::{self, NotificationReceiver, Notifier};
+use crate::mcp::protocol::JsonRpcRequest;
+use crate::mcp::subscriptions::Subscriptions;
+use futures::{Future, Stream};
+use std::collections::{HashMap, VecDeque};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tokio_util::sync::{CancellationToken, WaitForCancellationFutureOwned};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// HTTP header carrying the session id (MCP Streamable HTTP)
+pub const SESSION_ID_HEADER: &str = "mcp-session-id";
+
+/// HTTP header a reconnecting SSE client sends with the last event id it saw
+pub const LAST_EVENT_ID_HEADER: &str = "last-event-id";
+
+/// Notifications kept per session for `Last-Event-ID` replay, and queued
+/// per session while no stream is open
+pub const DEFAULT_EVENT_BUFFER: usize = 100;
+
+/// Sessions without requests or an open stream for this long are dropped
+pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// A notification as delivered on a session's stream
+#[derive(Debug, Clone)]
+pub struct SessionEvent {
+    /// SSE event id, increasing within the session
+    pub id: u64,
+    pub message: JsonRpcRequest,
+}
+
+/// Most recent delivered events, oldest first
+struct EventLog {
+    next_id: u64,
+    capacity: usize,
+    events: VecDeque<SessionEvent>,
+}
+
+impl EventLog {
+    fn new(capacity: usize) -> Self {
+        Self {
+            next_id: 1,
+            capacity,
+            events: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    fn record(&mut self, message: JsonRpcRequest) -> SessionEvent {
+        let event = SessionEvent {
+            id: self.next_id,
+            message,
+        };
+        self.next_id += 1;
+        if self.capacity > 0 {
+            if self.events.len() == self.capacity {
+                self.events.pop_front();
+            }
+            self.events.push_back(event.clone());
+        }
+        event
+    }
+
+    /// Kept events after `last_id`, and whether none were evicted
+    fn since(&self, last_id: u64) -> (Vec<SessionEvent>, bool) {
+        let oldest = self.events.front().map_or(self.next_id, |e| e.id);
+        let events = self
+            .events
+            .iter()
+            .filter(|e| e.id > last_id)
+            .cloned()
+            .collect();
+        (events, last_id + 1 >= oldest)
+    }
+}
+
+/// State of one MCP client session
+pub struct Session {
+    id: String,
+    user_id: String,
+    notifier: Notifier,
+    subscriptions: Subscriptions,
+    log: Arc<SessionLog>,
+    notifications: Mutex<Option<NotificationReceiver>>,
+    events: Mutex<EventLog>,
+    last_active: Mutex<Instant>,
+    closed: CancellationToken,
+}
+
+impl Session {
+    /// Create a session owned by `user_id`
+    pub fn new(user_id: impl Into<String>, poll_interval: Duration) -> Self {
+        let (notifier, receiver) = notifier::channel(DEFAULT_EVENT_BUFFER);
+        let id = Uuid::new_v4().to_string();
+        Self {
+            log: SessionLog::register(&id, notifier.clone()),
+            id,
+            user_id: user_id.into(),
+            subscriptions: Subscriptions::new(notifier.clone(), poll_interval),
+            notifier,
+            notifications: Mutex::new(Some(receiver)),
+            events: Mutex::new(EventLog::new(DEFAULT_EVENT_BUFFER)),
+            last_active: Mutex::new(Instant::now()),
+            closed: CancellationToken::new(),
+        }
+    }
+
+    /// Keep the last `capacity` delivered notifications for replay, and at
+    /// most `capacity` undelivered ones (the oldest are dropped)
+    pub fn with_event_buffer(self, capacity: usize) -> Self {
+        *self.events.lock().unwrap_or_else(|e| e.into_inner()) = EventLog::new(capacity);
+        self.notifier.set_capacity(capacity);
+        self
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// User whose token created the session
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    /// Queue a notification for the client
+    pub fn notifier(&self) -> &Notifier {
+        &self.notifier
+    }
+
+    pub fn subscriptions(&self) -> &Subscriptions {
+        &self.subscriptions
+    }
+
+    /// Server log messages forwarded to the client
+    pub fn log(&self) -> &SessionLog {
+        &self.log
+    }
+
+    /// Take the notification queue (`None` while another stream holds it)
+    pub fn take_notifications(&self) -> Option<NotificationReceiver> {
+        self.lock().take()
+    }
+
+    /// Give the queue back so the next stream picks up where this one stopped
+    pub fn restore_notifications(&self, receiver: NotificationReceiver) {
+        *self.lock() = Some(receiver);
+    }
+
+    /// Record client activity, restarting the idle timer
+    pub fn touch(&self) {
+        *self.last_active.lock().unwrap_or_else(|e| e.into_inner()) = Instant::now();
+    }
+
+    /// Time since the last request, or since the last stream closed
+    pub fn idle_time(&self) -> Duration {
+        self.last_active
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .elapsed()
+    }
+
+    /// Whether a stream currently holds the notification queue
+    pub fn has_open_stream(&self) -> bool {
+        self.lock().is_none()
+    }
+
+    /// Idle for `timeout` with no stream open
+    pub fn is_expired(&self, timeout: Duration) -> bool {
+        !self.has_open_stream() && self.idle_time() >= timeout
+    }
+
+    /// End the session: stop its subscriptions and its open stream
+    pub fn close(&self) {
+        self.closed.cancel();
+        self.subscriptions.unsubscribe_all();
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed.is_cancelled()
+    }
+
+    /// Assign the next event id to a delivered notification
+    fn record(&self, message: JsonRpcRequest) -> SessionEvent {
+        self.events
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .record(message)
+    }
+
+    fn events_since(&self, last_id: u64) -> (Vec<SessionEvent>, bool) {
+        self.events
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .since(last_id)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Option<NotificationReceiver>> {
+        self.notifications.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Stream of a session's notifications
+///
+/// Holds the session's queue while alive and returns it on drop, so a client
+/// that reconnects receives notifications queued in between. Ends when the
+/// session is closed.
+pub struct NotificationStream {
+    session: Arc<Session>,
+    receiver: Option<NotificationReceiver>,
+    replay: VecDeque<SessionEvent>,
+    closed: Pin<Box<WaitForCancellationFutureOwned>>,
+}
+
+impl NotificationStream {
+    /// Open the session's stream; `None` when one is already open
+    ///
+    /// With `last_event_id`, delivered events after that id are replayed
+    /// first. If some were already evicted from the log, the rest is
+    /// replayed and the gap is logged.
+    pub fn open(session: Arc<Session>, last_event_id: Option<u64>) -> Option<Self> {
+        let receiver = session.take_notifications()?;
+        let replay = match last_event_id {
+            Some(last_id) => {
+                let (events, complete) = session.events_since(last_id);
+                if !complete {
+                    warn!(session_id = %session.id(), last_event_id = last_id, "Missed events were evicted - replaying what is left");
+                }
+                events
+            }
+            None => Vec::new(),
+        };
+        if !replay.is_empty() {
+            info!(session_id = %session.id(), events = replay.len(), "Replaying missed notifications");
+        }
+
+        Some(Self {
+            closed: Box::pin(session.closed.clone().cancelled_owned()),
+            session,
+            receiver: Some(receiver),
+            replay: replay.into(),
+        })
+    }
+}
+
+impl Stream for NotificationStream {
+    type Item = SessionEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.closed.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(None);
+        }
+        if let Some(event) = self.replay.pop_front() {
+            return Poll::Ready(Some(event));
+        }
+
+        let message = match self.receiver.as_mut() {
+            Some(receiver) => receiver.poll_recv(cx),
+            None => return Poll::Ready(None),
+        };
+        message.map(|message| message.map(|message| self.session.record(message)))
+    }
+}
+
+impl Drop for NotificationStream {
+    fn drop(&mut self) {
+        if let Some(receiver) = self.receiver.take() {
+            self.session.restore_notifications(receiver);
+        }
+        // A client that just disconnected gets the full idle timeout to come back
+        self.session.touch();
+    }
+}
+
+/// Shortest period of [`SessionStore::spawn_reaper`]
+const MIN_REAP_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Sessions of the HTTP transport, keyed by `Mcp-Session-Id`
+///
+/// Expired sessions are swept whenever the store is used, and periodically by
+/// [`spawn_reaper`](Self::spawn_reaper).
+pub struct SessionStore {
+    poll_interval: Duration,
+    idle_timeout: Duration,
+    event_buffer: usize,
+    sessions: Mutex<HashMap<String, Arc<Session>>>,
+}
+
+impl SessionStore {
+    /// `poll_interval` applies to resource subscriptions of new sessions
+    pub fn new(poll_interval: Duration) -> Self {
+        Self {
+            poll_interval,
+            idle_timeout: DEFAULT_IDLE_TIMEOUT,
+            event_buffer: DEFAULT_EVENT_BUFFER,
+            sessions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Drop sessions idle for this long (default 30 minutes)
+    pub fn with_idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// Notifications kept per session for replay (default 100)
+    pub fn with_event_buffer(mut self, event_buffer: usize) -> Self {
+        self.event_buffer = event_buffer;
+        self
+    }
+
+    /// Start a session for `user_id`
+    pub fn create(&self, user_id: &str) -> Arc<Session> {
+        let session = Arc::new(
+            Session::new(user_id, self.poll_interval).with_event_buffer(self.event_buffer),
+        );
+        let mut sessions = self.lock();
+        self.sweep(&mut sessions);
+        sessions.insert(session.id().to_string(), session.clone());
+        session
+    }
+
+    /// Look up a session and mark it active
+    ///
+    /// Sessions belonging to a different user are reported as missing, so a
+    /// leaked session id is useless with another user's token.
+    pub fn get(&self, id: &str, user_id: &str) -> Option<Arc<Session>> {
+        let mut sessions = self.lock();
+        self.sweep(&mut sessions);
+        let session = sessions
+            .get(id)
+            .filter(|session| session.user_id() == user_id)
+            .cloned()?;
+        session.touch();
+        Some(session)
+    }
+
+    /// Terminate a session (`DELETE /mcp`); `None` when unknown or not `user_id`'s
+    pub fn remove(&self, id: &str, user_id: &str) -> Option<Arc<Session>> {
+        let mut sessions = self.lock();
+        if sessions.get(id)?.user_id() != user_id {
+            return None;
+        }
+        let session = sessions.remove(id)?;
+        session.close();
+        Some(session)
+    }
+
+    /// Number of live sessions
+    pub fn len(&self) -> usize {
+        let mut sessions = self.lock();
+        self.sweep(&mut sessions);
+        sessions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Close and drop every expired session
+    pub fn sweep_expired(&self) {
+        let mut sessions = self.lock();
+        self.sweep(&mut sessions);
+    }
+
+    /// Sweep `store` every half idle timeout until it is dropped
+    ///
+    /// On a quiet server nothing else touches the store, and an abandoned
+    /// session's watchers would keep polling Miro with its user's token.
+    pub fn spawn_reaper(store: &Arc<Self>) -> JoinHandle<()> {
+        let period = (store.idle_timeout / 2).max(MIN_REAP_INTERVAL);
+        let store = Arc::downgrade(store);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval_at(Instant::now() + period, period);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let Some(store) = store.upgrade() else {
+                    return;
+                };
+                store.sweep_expired();
+            }
+        })
+    }
+
+    fn sweep(&self, sessions: &mut HashMap<String, Arc<Session>>) {
+        sessions.retain(|id, session| {
+            if !session.is_expired(self.idle_timeout) {
+                return true;
+            }
+            info!(session_id = %id, user_id = %session.user_id(), "MCP session expired");
+            session.close();
+            false
+        });
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Arc<Session>>> {
+        self.sessions.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use serde_json::json;
+
+    #[test]
+    fn test_sessions_are_bound_to_their_user() {
+        let store = SessionStore::new(Duration::from_secs(15));
+        let session = store.create("alice");
+
+        assert!(store.get(session.id(), "alice").is_some());
+        assert!(store.get(session.id(), "mallory").is_none());
+        assert!(store.get("unknown", "alice").is_none());
+    }
+
+    #[test]
+    fn test_idle_sessions_expire_unless_streaming() {
+        let store = SessionStore::new(Duration::from_secs(15)).with_idle_timeout(Duration::ZERO);
+        let idle = store.create("alice");
+        assert!(store.get(idle.id(), "alice").is_none());
+        assert!(idle.is_closed());
+
+        let streaming = store.create("alice");
+        let stream = NotificationStream::open(streaming.clone(), None).unwrap();
+        assert!(store.get(streaming.id(), "alice").is_some());
+        drop(stream);
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn test_remove_requires_owner() {
+        let store = SessionStore::new(Duration::from_secs(15));
+        let session = store.create("alice");
+
+        assert!(store.remove(session.id(), "mallory").is_none());
+        assert!(store.remove(session.id(), "alice").is_some());
+        assert!(session.is_closed());
+        assert!(store.get(session.id(), "alice").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_notification_stream_is_exclusive_and_resumes() {
+        let session = Arc::new(Session::new("alice", Duration::from_secs(15)));
+        let mut stream = NotificationStream::open(session.clone(), None).unwrap();
+        assert!(NotificationStream::open(session.clone(), None).is_none());
+
+        let notifier = session.notifier();
+        notifier
+            .send(JsonRpcRequest::notification("a", json!({})))
+            .unwrap();
+        let event = stream.next().await.unwrap();
+        assert_eq!((event.id, event.message.method.as_str()), (1, "a"));
+
+        // Queued while no stream is open, delivered on reconnect
+        drop(stream);
+        notifier
+            .send(JsonRpcRequest::notification("b", json!({})))
+            .unwrap();
+        let mut stream = NotificationStream::open(session.clone(), None).unwrap();
+        let event = stream.next().await.unwrap();
+        assert_eq!((event.id, event.message.method.as_str()), (2, "b"));
+
+        // Closing the session ends its stream
+        session.close();
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_undelivered_notifications_are_capped() {
+        // A client that never opens the stream only gets the newest ones
+        let session = Arc::new(Session::new("alice", Duration::from_secs(15)).with_event_buffer(2));
+        for method in ["a", "b", "c"] {
+            session
+                .notifier()
+                .send(JsonRpcRequest::notification(method, json!({})))
+                .unwrap();
+        }
+
+        let mut stream = NotificationStream::open(session, None).unwrap();
+        let first = stream.next().await.unwrap();
+        let second = stream.next().await.unwrap();
+        assert_eq!(
+            [first.message.method, second.message.method],
+            ["b".to_string(), "c".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_replays_events_after_last_event_id() {
+        let session = Arc::new(Session::new("alice", Duration::from_secs(15)).with_event_buffer(2));
+        let mut stream = NotificationStream::open(session.clone(), None).unwrap();
+        for method in ["a", "b", "c"] {
+            session
+                .notifier()
+                .send(JsonRpcRequest::notification(method, json!({})))
+                .unwrap();
+            stream.next().await.unwrap();
+        }
+        drop(stream);
+
+        // The client saw event 2 but lost event 3 with the connection
+        let mut stream = NotificationStream::open(session.clone(), Some(2)).unwrap();
+        let event = stream.next().await.unwrap();
+        assert_eq!((event.id, event.message.method.as_str()), (3, "c"));
+        drop(stream);
+
+        // Event 2 fell out of the two-event buffer: replay what is left
+        let mut stream = NotificationStream::open(session, Some(0)).unwrap();
+        let ids = [
+            stream.next().await.unwrap().id,
+            stream.next().await.unwrap().id,
+        ];
+        assert_eq!(ids, [2, 3]);
+    }
+}
@@ -0,0 +1,236 @@
+use crate::auth::{scopes, UserInfo};
+use crate::mcp::handler::{parse_request, McpHandler};
+use crate::mcp::session::Session;
+use futures::stream::{FuturesUnordered, StreamExt};
+use serde::Serialize;
+use std::path::PathBuf;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tracing::{debug, info};
+
+/// Environment variable holding the Miro access token
+pub const TOKEN_ENV: &str = "MIRO_ACCESS_TOKEN";
+
+/// Environment variable pointing at a file that contains the Miro access token
+pub const TOKEN_FILE_ENV: &str = "MIRO_TOKEN_FILE";
+
+#[derive(Error, Debug)]
+pub enum StdioError {
+    #[error("No Miro access token: set {TOKEN_ENV} or {TOKEN_FILE_ENV}")]
+    MissingToken,
+
+    #[error("Failed to read token file {path}: {source}")]
+    TokenFile {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Token file {0} is empty")]
+    EmptyTokenFile(PathBuf),
+
+    #[error("Stdio transport error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Load the Miro access token for the stdio transport
+///
+/// `MIRO_ACCESS_TOKEN` wins; otherwise the trimmed contents of the file named
+/// by `MIRO_TOKEN_FILE`.
+pub fn load_token() -> Result<String, StdioError> {
+    if let Ok(token) = std::env::var(TOKEN_ENV) {
+        let token = token.trim();
+        if !token.is_empty() {
+            return Ok(token.to_string());
+        }
+    }
+
+    let path = std::env::var_os(TOKEN_FILE_ENV)
+        .map(PathBuf::from)
+        .ok_or(StdioError::MissingToken)?;
+    let contents = std::fs::read_to_string(&path).map_err(|source| StdioError::TokenFile {
+        path: path.clone(),
+        source,
+    })?;
+
+    match contents.trim() {
+        "" => Err(StdioError::EmptyTokenFile(path)),
+        token => Ok(token.to_string()),
+    }
+}
+
+/// Identity of the local stdio user
+///
+/// The token is the user's own, so every tool is offered and Miro enforces
+/// the token's real permissions.
+pub fn local_user() -> UserInfo {
+    UserInfo::new(
+        "stdio".to_string(),
+        None,
+        scopes::SUPPORTED_SCOPES
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+    )
+}
+
+/// Serve MCP over newline-delimited JSON-RPC (the MCP stdio transport)
+///
+/// Reads one message per line from `reader` and writes one response per line
+/// to `writer`, using the same handler and tool registry as the HTTP
+/// transport. The connection is a single MCP session, so resource
+/// subscriptions (polled every `poll_interval`) are available and their
+/// notifications are interleaved with responses. Requests run concurrently,
+/// so `notifications/cancelled` can stop a long tool call and progress
+/// notifications are written as they happen. Returns when `reader` reaches
+/// EOF and every request read has been answered.
+pub async fn serve<R, W>(
+    handler: &McpHandler,
+    bearer_token: &str,
+    user_info: &UserInfo,
+    poll_interval: Duration,
+    reader: R,
+    mut writer: W,
+) -> Result<(), StdioError>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let session = Session::new(user_info.user_id.clone(), poll_interval);
+    let mut notifications = session
+        .take_notifications()
+        .expect("new session owns its notification queue");
+    let mut lines = BufReader::new(reader).lines();
+    let mut pending = FuturesUnordered::new();
+    let mut reader_open = true;
+    info!("MCP stdio transport ready");
+
+    while reader_open || !pending.is_empty() {
+        tokio::select! {
+            biased;
+            Some(notification) = notifications.recv() => {
+                write_message(&mut writer, &notification).await?;
+            }
+            Some(response) = pending.next() => {
+                // Progress queued by the call goes out before its response
+                while let Some(notification) = notifications.try_recv() {
+                    write_message(&mut writer, &notification).await?;
+                }
+                // Notifications get no reply
+                if let Some(response) = response {
+                    write_message(&mut writer, &response).await?;
+                }
+            }
+            line = lines.next_line(), if reader_open => {
+                let Some(line) = line? else {
+                    reader_open = false;
+                    continue;
+                };
+                if line.trim().is_empty() {
+                    continue;
+                }
+
+                match parse_request(line.as_bytes()) {
+                    Ok(request) => pending.push(handler.handle_with_progress(
+                        request,
+                        bearer_token,
+                        user_info,
+                        Some(&session),
+                        session.notifier(),
+                    )),
+                    Err(error_response) => write_message(&mut writer, &*error_response).await?,
+                }
+            }
+        }
+    }
+
+    debug!("stdin closed - stopping stdio transport");
+    Ok(())
+}
+
+async fn write_message<W, T>(writer: &mut W, message: &T) -> Result<(), StdioError>
+where
+    W: AsyncWrite + Unpin,
+    T: Serialize,
+{
+    let mut out = serde_json::to_vec(message).map_err(std::io::Error::from)?;
+    out.push(b'\n');
+    writer.write_all(&out).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    async fn run(input: &str) -> Vec<Value> {
+        let handler = McpHandler::new();
+        let mut output = Vec::new();
+        serve(
+            &handler,
+            "token",
+            &local_user(),
+            Duration::from_secs(15),
+            input.as_bytes(),
+            &mut output,
+        )
+        .await
+        .unwrap();
+
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_one_response_per_request_line() {
+        let responses = run(concat!(
+            r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"protocolVersion":"2025-06-18","capabilities":{},"clientInfo":{"name":"test","version":"1"}}}"#,
+            "\n",
+            r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#,
+            "\n\n",
+            r#"{"jsonrpc":"2.0","id":2,"method":"tools/list"}"#,
+            "\n",
+        ))
+        .await;
+
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0]["id"], 1);
+        assert!(responses[0]["result"]["serverInfo"].is_object());
+        assert_eq!(responses[1]["id"], 2);
+        let tools = responses[1]["result"]["tools"].as_array().unwrap();
+        assert_eq!(tools.len(), crate::mcp::tools::list_tools().len());
+    }
+
+    #[tokio::test]
+    async fn test_malformed_line_gets_parse_error() {
+        let responses = run("{not json\n").await;
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0]["error"]["code"], -32700);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_load_token_from_env_or_file() {
+        std::env::remove_var(TOKEN_ENV);
+        std::env::remove_var(TOKEN_FILE_ENV);
+        assert!(matches!(load_token(), Err(StdioError::MissingToken)));
+
+        let path = std::env::temp_dir().join(format!("miro-token-{}", std::process::id()));
+        std::fs::write(&path, "file-token\n").unwrap();
+        std::env::set_var(TOKEN_FILE_ENV, &path);
+        assert_eq!(load_token().unwrap(), "file-token");
+
+        std::env::set_var(TOKEN_ENV, "env-token");
+        assert_eq!(load_token().unwrap(), "env-token");
+
+        // Cleanup
+        std::env::remove_var(TOKEN_ENV);
+        std::env::remove_var(TOKEN_FILE_ENV);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,315 @@
+//! `resources/subscribe` support by polling Miro
+//!
+//! Miro has no change feed we can use with user tokens, so each subscribed
+//! board URI gets a background task that lists its items on an interval and
+//! compares item ids and `modifiedAt` timestamps with the previous poll.
+//! Any difference emits `notifications/resources/updated` for that URI.
+//!
+//! The notification only names the URI, so one undelivered update per URI is
+//! enough: updates are coalesced, and a watcher skips its polls until the
+//! client has received the last one. A session nobody reads from stops
+//! costing Miro requests after its first change.
+
+use crate::mcp::errors;
+use crate::mcp::notifier::Notifier;
+use crate::mcp::protocol::{JsonRpcError, JsonRpcRequest};
+use crate::mcp::resources::ResourceUri;
+use crate::miro::{paginate, MiroApi, MiroError};
+use futures::TryStreamExt;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn, Instrument};
+
+/// Largest page Miro serves for board items
+const POLL_PAGE_SIZE: u32 = 50;
+
+/// Item id -> `modifiedAt` for one poll of a board
+type Snapshot = HashMap<String, Option<String>>;
+
+/// Differences between two polls of a board
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct BoardChanges {
+    pub added: usize,
+    pub removed: usize,
+    pub modified: usize,
+}
+
+impl BoardChanges {
+    pub fn is_empty(&self) -> bool {
+        self.added == 0 && self.removed == 0 && self.modified == 0
+    }
+}
+
+fn diff(previous: &Snapshot, current: &Snapshot) -> BoardChanges {
+    let mut changes = BoardChanges::default();
+    for (id, modified_at) in current {
+        match previous.get(id) {
+            None => changes.added += 1,
+            Some(before) if before != modified_at => changes.modified += 1,
+            Some(_) => {}
+        }
+    }
+    changes.removed = previous
+        .keys()
+        .filter(|id| !current.contains_key(*id))
+        .count();
+    changes
+}
+
+/// What a subscribed URI polls
+struct Watch {
+    uri: String,
+    board_id: String,
+    item_types: Vec<String>,
+    parent_id: Option<String>,
+}
+
+impl Watch {
+    fn from_uri(uri: &str) -> Result<Self, JsonRpcError> {
+        match ResourceUri::parse(uri) {
+            Some(ResourceUri::Board { board_id }) => Ok(Self {
+                uri: uri.to_string(),
+                board_id,
+                item_types: Vec::new(),
+                parent_id: None,
+            }),
+            Some(ResourceUri::Items {
+                board_id,
+                item_types,
+                parent_id,
+                ..
+            }) => Ok(Self {
+                uri: uri.to_string(),
+                board_id,
+                item_types,
+                parent_id,
+            }),
+            Some(ResourceUri::Boards) => Err(JsonRpcError::invalid_params(format!(
+                "{} does not support subscriptions; subscribe to a board URI",
+                uri
+            ))),
+            None => Err(JsonRpcError::resource_not_found(uri)),
+        }
+    }
+
+    /// Snapshot of every watched item
+    ///
+    /// Reads the whole listing rather than `list_items`, whose item cap would
+    /// hide changes on large boards and report items crossing it as added or
+    /// removed. Only ids and timestamps are kept.
+    async fn poll(&self, client: &dyn MiroApi) -> Result<Snapshot, MiroError> {
+        let types: Vec<&str> = self.item_types.iter().map(String::as_str).collect();
+        let types = (!types.is_empty()).then_some(types.as_slice());
+        let parent_id = self.parent_id.as_deref();
+        paginate(|cursor: Option<String>| async move {
+            client
+                .list_items_page(
+                    &self.board_id,
+                    types,
+                    parent_id,
+                    Some(POLL_PAGE_SIZE),
+                    cursor.as_deref(),
+                )
+                .await
+        })
+        .map_ok(|item| (item.id, item.modified_at))
+        .try_collect()
+        .await
+    }
+}
+
+/// Active resource subscriptions of one session
+///
+/// Watchers stop on [`unsubscribe`](Self::unsubscribe), when the session's
+/// notification queue closes, when Miro rejects the token, or when this
+/// value is dropped. They pause while their last update is undelivered.
+pub struct Subscriptions {
+    notifier: Notifier,
+    poll_interval: Duration,
+    watchers: Mutex<HashMap<String, JoinHandle<()>>>,
+}
+
+impl Subscriptions {
+    pub fn new(notifier: Notifier, poll_interval: Duration) -> Self {
+        Self {
+            notifier,
+            poll_interval,
+            watchers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Start watching a board URI
+    ///
+    /// The first poll happens immediately, so unknown boards are reported to
+    /// the caller and later changes are measured from the moment of
+    /// subscription. Subscribing again to the same URI restarts its watcher.
+    pub async fn subscribe(&self, uri: &str, client: Arc<dyn MiroApi>) -> Result<(), JsonRpcError> {
+        let watch = Watch::from_uri(uri)?;
+        let baseline = watch.poll(client.as_ref()).await.map_err(|e| match e {
+            MiroError::NotFound(_) => JsonRpcError::resource_not_found(uri),
+            other => errors::rpc_error(other),
+        })?;
+
+        // Keeps the subscribing session's span so the watcher's logs reach its client
+        let task = tokio::spawn(
+            watch_board(
+                watch,
+                client,
+                baseline,
+                self.poll_interval,
+                self.notifier.clone(),
+            )
+            .in_current_span(),
+        );
+        if let Some(previous) = self.lock().insert(uri.to_string(), task) {
+            previous.abort();
+        }
+
+        info!(uri = %uri, interval_secs = self.poll_interval.as_secs(), "Resource subscribed");
+        Ok(())
+    }
+
+    /// Stop watching a URI; returns false when it was not subscribed
+    pub fn unsubscribe(&self, uri: &str) -> bool {
+        match self.lock().remove(uri) {
+            Some(task) => {
+                task.abort();
+                info!(uri = %uri, "Resource unsubscribed");
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Stop every watcher
+    pub fn unsubscribe_all(&self) {
+        for (_, task) in self.lock().drain() {
+            task.abort();
+        }
+    }
+
+    /// URIs currently being watched
+    pub fn uris(&self) -> Vec<String> {
+        self.lock()
+            .iter()
+            .filter(|(_, task)| !task.is_finished())
+            .map(|(uri, _)| uri.clone())
+            .collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, JoinHandle<()>>> {
+        self.watchers.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for Subscriptions {
+    fn drop(&mut self) {
+        for task in self.lock().values() {
+            task.abort();
+        }
+    }
+}
+
+async fn watch_board(
+    watch: Watch,
+    client: Arc<dyn MiroApi>,
+    mut previous: Snapshot,
+    poll_interval: Duration,
+    notifier: Notifier,
+) {
+    let update = JsonRpcRequest::notification(
+        "notifications/resources/updated",
+        json!({ "uri": watch.uri }),
+    );
+    let mut ticker =
+        tokio::time::interval_at(tokio::time::Instant::now() + poll_interval, poll_interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+        if notifier.is_closed() {
+            debug!(uri = %watch.uri, "Session gone - stopping watcher");
+            return;
+        }
+        // Changes since then are picked up once the client catches up
+        if notifier.is_queued(&update) {
+            continue;
+        }
+
+        let current = match watch.poll(client.as_ref()).await {
+            Ok(current) => current,
+            Err(MiroError::Unauthorized) => {
+                warn!(uri = %watch.uri, "Miro rejected the subscriber's token - stopping watcher");
+                return;
+            }
+            Err(e) => {
+                // Transient failures (rate limits, network) just skip a poll
+                warn!(uri = %watch.uri, error = %e, "Board poll failed");
+                continue;
+            }
+        };
+
+        let changes = diff(&previous, &current);
+        if changes.is_empty() {
+            continue;
+        }
+        debug!(
+            uri = %watch.uri,
+            added = changes.added,
+            removed = changes.removed,
+            modified = changes.modified,
+            "Board changed"
+        );
+        // Coalesced updates are never dropped from the queue, so this change
+        // reaches the client even if the queue fills up before it connects
+        previous = current;
+
+        if notifier.send_coalesced(update.clone()).is_err() {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snap(entries: &[(&str, &str)]) -> Snapshot {
+        entries
+            .iter()
+            .map(|(id, modified)| (id.to_string(), Some(modified.to_string())))
+            .collect()
+    }
+
+    #[test]
+    fn test_diff_counts_added_removed_modified() {
+        let before = snap(&[("1", "t1"), ("2", "t1"), ("3", "t1")]);
+        let after = snap(&[("1", "t1"), ("2", "t2"), ("4", "t1")]);
+        assert_eq!(
+            diff(&before, &after),
+            BoardChanges {
+                added: 1,
+                removed: 1,
+                modified: 1
+            }
+        );
+        assert!(diff(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn test_only_board_uris_are_subscribable() {
+        assert!(Watch::from_uri("miro://boards/b1").is_ok());
+        assert!(Watch::from_uri("miro://boards/b1/items?type=sticky_note").is_ok());
+        assert_eq!(
+            Watch::from_uri("miro://boards").err().unwrap().code,
+            crate::mcp::protocol::error_codes::INVALID_PARAMS
+        );
+        assert_eq!(
+            Watch::from_uri("miro://nope").err().unwrap().code,
+            crate::mcp::protocol::error_codes::RESOURCE_NOT_FOUND
+        );
+    }
+}
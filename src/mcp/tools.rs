@@ -0,0 +1,2153 @@
+use crate::auth::scopes::{BOARDS_READ, BOARDS_WRITE};
+use crate::auth::{AuthError, UserInfo};
+use crate::mcp::context::ToolContext;
+use crate::mcp::protocol::{CallToolResult, JsonRpcError, Tool};
+use crate::mcp::registry::{ToolHandler, ToolRegistry};
+use crate::miro::types::{
+    AppCardData, AppCardField, AppCardResponse, AppCardStyle, Board, BoardMember, BoardRole,
+    BoardSharingPolicy, BoardSort, BulkItemRequest, Caption, CardData, CardResponse, CardStyle,
+    ConnectorResponse, FrameResponse, Geometry, InvitationFailure, Item, Position, ShapeResponse,
+    StickyNoteResponse, Tag, TextResponse,
+};
+use crate::miro::{
+    AppCardBuilder, BoardQuery, CardBuilder, MiroApi, MiroError, StickyNoteBuilder,
+    DEFAULT_MAX_ITEMS, MAX_BULK_ITEMS,
+};
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::OnceLock;
+use tracing::info;
+
+// ==================== Tool Parameters ====================
+
+/// Arguments of tools that take none
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct NoParams {}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct ListBoardsParams {
+    /// Text to search for in board names and descriptions
+    #[serde(default)]
+    pub query: Option<String>,
+    /// Only boards of this team
+    #[serde(default)]
+    pub team_id: Option<String>,
+    /// Only boards of this project
+    #[serde(default)]
+    pub project_id: Option<String>,
+    /// Only boards owned by this user ID
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// Result order: default, last_modified, last_opened, last_created or alphabetically
+    #[serde(default)]
+    pub sort: Option<BoardSort>,
+    /// Return a single page of this many boards instead of every match
+    #[serde(default)]
+    #[schemars(range(min = 1, max = 50))]
+    pub limit: Option<u32>,
+    /// Number of matching boards to skip, e.g. `next_offset` of a previous call
+    #[serde(default)]
+    pub offset: Option<u32>,
+}
+
+impl ListBoardsParams {
+    fn board_query(&self) -> BoardQuery {
+        let mut query = BoardQuery::new();
+        if let Some(text) = &self.query {
+            query = query.query(text);
+        }
+        if let Some(team_id) = &self.team_id {
+            query = query.team_id(team_id);
+        }
+        if let Some(project_id) = &self.project_id {
+            query = query.project_id(project_id);
+        }
+        if let Some(owner) = &self.owner {
+            query = query.owner(owner);
+        }
+        if let Some(sort) = self.sort {
+            query = query.sort(sort);
+        }
+        if let Some(limit) = self.limit {
+            query = query.limit(limit);
+        }
+        if let Some(offset) = self.offset {
+            query = query.offset(offset);
+        }
+        query
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateBoardParams {
+    /// Board name
+    #[schemars(length(min = 1))]
+    pub name: String,
+    /// Board description
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct BoardIdParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UpdateBoardParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    /// New board name
+    #[serde(default)]
+    #[schemars(length(min = 1))]
+    pub name: Option<String>,
+    /// New board description
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CopyBoardParams {
+    /// Board to copy, e.g. a sprint template
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    /// Name of the copy (default: the source board's name)
+    #[serde(default)]
+    #[schemars(length(min = 1))]
+    pub name: Option<String>,
+    /// Description of the copy (default: the source board's description)
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Team to create the copy in (default: the source board's team)
+    #[serde(default)]
+    pub team_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ShareBoardParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    /// Email addresses to invite
+    #[schemars(length(min = 1, max = 20))]
+    pub emails: Vec<String>,
+    /// Role to grant: viewer, commenter, editor or coowner
+    pub role: BoardRole,
+    /// Text of the invitation email
+    #[serde(default)]
+    pub message: Option<String>,
+    /// Only preview the invitations
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UpdateBoardMemberParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    #[schemars(length(min = 1))]
+    pub member_id: String,
+    /// New role: viewer, commenter, editor or coowner
+    pub role: BoardRole,
+    /// Only preview the change
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RemoveBoardMemberParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    #[schemars(length(min = 1))]
+    pub member_id: String,
+    /// Only preview who would lose access
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UpdateBoardSharingParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    /// Sharing settings to change; omitted fields stay as they are
+    pub sharing_policy: BoardSharingPolicy,
+    /// Only preview who would gain or lose access
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListItemsParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    /// Item types to include (sticky_note, shape, text, frame, ...)
+    #[serde(default)]
+    pub item_types: Option<Vec<String>>,
+    /// Only items inside this frame
+    #[serde(default)]
+    pub parent_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateStickyNoteParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    /// Note content (HTML allowed)
+    pub content: String,
+    pub x: f64,
+    pub y: f64,
+    /// Sticky note color (e.g., light_yellow, light_green, blue)
+    #[serde(default = "default_sticky_color")]
+    pub color: String,
+    /// Parent frame ID
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    /// IDs of board tags to attach (see list_tags / create_tag)
+    #[serde(default)]
+    pub tag_ids: Vec<String>,
+}
+
+fn default_sticky_color() -> String {
+    "light_yellow".to_string()
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateShapeParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    /// Shape type (rectangle, circle, triangle, ...)
+    pub shape_type: String,
+    pub x: f64,
+    pub y: f64,
+    #[schemars(range(min = 1.0))]
+    pub width: f64,
+    #[schemars(range(min = 1.0))]
+    pub height: f64,
+    /// Fill color (hex like #ff0000 or a named color)
+    #[serde(default = "default_shape_color")]
+    pub fill_color: String,
+    /// Text inside the shape
+    #[serde(default)]
+    pub content: Option<String>,
+    /// Parent frame ID
+    #[serde(default)]
+    pub parent_id: Option<String>,
+}
+
+fn default_shape_color() -> String {
+    "light_blue".to_string()
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateTextParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    pub content: String,
+    pub x: f64,
+    pub y: f64,
+    #[schemars(range(min = 1.0))]
+    pub width: f64,
+    /// Parent frame ID
+    #[serde(default)]
+    pub parent_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateFrameParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    /// Frame title
+    pub title: String,
+    pub x: f64,
+    pub y: f64,
+    #[schemars(range(min = 1.0))]
+    pub width: f64,
+    #[schemars(range(min = 1.0))]
+    pub height: f64,
+    /// Background color
+    #[serde(default)]
+    pub fill_color: Option<String>,
+    /// Parent frame ID
+    #[serde(default)]
+    pub parent_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateCardParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    /// Card title, e.g. an action item
+    pub title: String,
+    pub x: f64,
+    pub y: f64,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Miro user ID of the owner (see list_board_members)
+    #[serde(default)]
+    pub assignee_id: Option<String>,
+    /// Due date, ISO 8601 (e.g., 2025-03-31T17:00:00Z)
+    #[serde(default)]
+    pub due_date: Option<String>,
+    /// Hex color of the card's left border (e.g., #2d9bf0)
+    #[serde(default)]
+    pub theme: Option<String>,
+    /// Parent frame ID
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    /// IDs of board tags to attach (see list_tags / create_tag)
+    #[serde(default)]
+    pub tag_ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UpdateCardParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    #[schemars(length(min = 1))]
+    pub item_id: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Miro user ID of the new owner
+    #[serde(default)]
+    pub assignee_id: Option<String>,
+    /// New due date, ISO 8601
+    #[serde(default)]
+    pub due_date: Option<String>,
+    /// New hex color of the card's left border
+    #[serde(default)]
+    pub theme: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateAppCardParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    /// Card title
+    pub title: String,
+    pub x: f64,
+    pub y: f64,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Custom fields, e.g. owner, due date or status of the linked item
+    #[serde(default)]
+    pub fields: Vec<AppCardField>,
+    /// Connection status: disconnected, connected or disabled
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Hex color of the card's border
+    #[serde(default)]
+    pub fill_color: Option<String>,
+    /// Parent frame ID
+    #[serde(default)]
+    pub parent_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UpdateAppCardParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    #[schemars(length(min = 1))]
+    pub item_id: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Replaces all custom fields
+    #[serde(default)]
+    pub fields: Option<Vec<AppCardField>>,
+    /// New connection status: disconnected, connected or disabled
+    #[serde(default)]
+    pub status: Option<String>,
+    /// New hex color of the card's border
+    #[serde(default)]
+    pub fill_color: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ItemIdParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    #[schemars(length(min = 1))]
+    pub item_id: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateConnectorParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    /// Item the connector starts at
+    #[schemars(length(min = 1))]
+    pub start_item_id: String,
+    /// Item the connector ends at
+    #[schemars(length(min = 1))]
+    pub end_item_id: String,
+    #[serde(default)]
+    pub stroke_color: Option<String>,
+    #[serde(default)]
+    pub stroke_width: Option<f64>,
+    /// Line start decoration (e.g., none, arrow, stealth)
+    #[serde(default)]
+    pub start_cap: Option<String>,
+    /// Line end decoration (e.g., none, arrow, stealth)
+    #[serde(default)]
+    pub end_cap: Option<String>,
+    /// Text labels along the connector
+    #[serde(default)]
+    pub captions: Option<Vec<Caption>>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UpdateItemParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    #[schemars(length(min = 1))]
+    pub item_id: String,
+    /// New position
+    #[serde(default)]
+    pub position: Option<Position>,
+    /// Item-type specific content fields to change
+    #[serde(default)]
+    pub data: Option<Value>,
+    /// Item-type specific style fields to change
+    #[serde(default)]
+    pub style: Option<Value>,
+    /// New size
+    #[serde(default)]
+    pub geometry: Option<Geometry>,
+    /// Move the item into this frame
+    #[serde(default)]
+    pub parent_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DeleteItemParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    #[schemars(length(min = 1))]
+    pub item_id: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateTagParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    /// Tag text, unique on the board (e.g., bug, feature, question)
+    #[schemars(length(min = 1, max = 120))]
+    pub title: String,
+    /// Tag color: red, light_green, cyan, yellow, magenta, green, blue, gray,
+    /// violet, dark_green, dark_blue or black
+    #[serde(default)]
+    pub fill_color: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UpdateTagParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    #[schemars(length(min = 1))]
+    pub tag_id: String,
+    /// New tag text
+    #[serde(default)]
+    #[schemars(length(min = 1, max = 120))]
+    pub title: Option<String>,
+    /// New tag color
+    #[serde(default)]
+    pub fill_color: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct TagIdParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    #[schemars(length(min = 1))]
+    pub tag_id: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ItemTagParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    /// Sticky note or card to tag
+    #[schemars(length(min = 1))]
+    pub item_id: String,
+    #[schemars(length(min = 1))]
+    pub tag_id: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct BulkCreateItemsParams {
+    #[schemars(length(min = 1))]
+    pub board_id: String,
+    /// Items to create (sticky notes, shapes, texts, frames, cards, app cards)
+    #[schemars(length(min = 1, max = 200))]
+    pub items: Vec<BulkItemRequest>,
+}
+
+// ==================== Tool Results ====================
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct BoardList {
+    pub boards: Vec<Board>,
+    /// Offset to continue from: after a single page, or when more than 1000
+    /// boards matched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<u32>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ItemList {
+    pub items: Vec<Item>,
+    /// True when more items matched than the 1000 returned
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct BulkCreateResult {
+    /// Items created, in request order
+    pub items: Vec<Item>,
+    /// True when the call was cancelled before every item was created
+    pub cancelled: bool,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DeletedItem {
+    /// ID of the deleted item
+    pub deleted: String,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DeletedTag {
+    /// ID of the deleted tag
+    pub deleted: String,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct TagList {
+    pub tags: Vec<Tag>,
+    /// True when the board has more tags than the 1000 returned
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
+}
+
+/// Tags of an item after attaching or detaching one
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ItemTags {
+    pub item_id: String,
+    pub tags: Vec<Tag>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DeletedBoard {
+    /// ID of the deleted board
+    pub deleted: String,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct MemberList {
+    pub members: Vec<BoardMember>,
+    /// True when the board has more members than the 1000 returned
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
+}
+
+/// Who gains, loses or changes access to a board
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct AccessChanges {
+    /// True when nothing was changed and `changes` is a preview
+    pub dry_run: bool,
+    pub changes: Vec<AccessChange>,
+    /// Invitations Miro refused
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub failed: Vec<InvitationFailure>,
+}
+
+#[derive(Debug, PartialEq, Serialize, JsonSchema)]
+pub struct AccessChange {
+    /// Member ID, invited email, or audience of a sharing setting
+    /// (`anyone_with_link`, `team`, `organization`, `invited_by_link`)
+    pub who: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Access before the change; absent when gaining access, `unknown`
+    /// for invited emails, whose current access Miro does not reveal
+    pub from: Option<String>,
+    /// Access after the change; absent when losing access
+    pub to: Option<String>,
+}
+
+impl AccessChange {
+    fn member(member: &BoardMember, to: Option<BoardRole>) -> Self {
+        Self {
+            who: member.id.clone(),
+            name: Some(member.name.clone()).filter(|n| !n.is_empty()),
+            from: Some(member.role.as_str().to_string()),
+            to: to.map(|role| role.as_str().to_string()),
+        }
+    }
+
+    /// Changes of the audiences whose setting differs between `from` and `to`
+    fn sharing(from: &BoardSharingPolicy, to: &BoardSharingPolicy) -> Vec<Self> {
+        // "private" and "no_access" are reported as no access at all
+        let access =
+            |value: &Option<String>| value.clone().filter(|v| v != "private" && v != "no_access");
+        [
+            ("anyone_with_link", &from.access, &to.access),
+            ("team", &from.team_access, &to.team_access),
+            (
+                "organization",
+                &from.organization_access,
+                &to.organization_access,
+            ),
+            (
+                "invited_by_link",
+                &from.invite_to_account_and_board_link_access,
+                &to.invite_to_account_and_board_link_access,
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, _, new)| new.is_some())
+        .map(|(who, old, new)| Self {
+            who: who.to_string(),
+            name: None,
+            from: access(old),
+            to: access(new),
+        })
+        .filter(|change| change.from != change.to)
+        .collect()
+    }
+}
+
+// ==================== Tool Handlers ====================
+
+pub struct ListBoards;
+
+#[async_trait]
+impl ToolHandler for ListBoards {
+    type Input = ListBoardsParams;
+    type Output = BoardList;
+
+    fn name(&self) -> &'static str {
+        "list_boards"
+    }
+
+    fn description(&self) -> &'static str {
+        "List Miro boards accessible to the authenticated user, optionally searched by text and \
+         filtered by team, project or owner. Returns every match (the first 1000, with next_offset \
+         to continue) unless a limit is given."
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_READ
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: ListBoardsParams,
+        _ctx: &ToolContext,
+    ) -> Result<BoardList, MiroError> {
+        let query = p.board_query();
+        if p.limit.is_none() {
+            let boards = query.collect(client, DEFAULT_MAX_ITEMS).await?;
+            // Stopped at the cap: the caller continues from the first board left out
+            let next_offset = boards
+                .truncated
+                .then(|| p.offset.unwrap_or(0) + boards.items.len() as u32);
+            return Ok(BoardList {
+                boards: boards.items,
+                next_offset,
+            });
+        }
+
+        let page = query.build(client).await?;
+        Ok(BoardList {
+            boards: page.data,
+            next_offset: page.cursor.and_then(|cursor| cursor.parse().ok()),
+        })
+    }
+}
+
+pub struct CreateBoard;
+
+#[async_trait]
+impl ToolHandler for CreateBoard {
+    type Input = CreateBoardParams;
+    type Output = Board;
+
+    fn name(&self) -> &'static str {
+        "create_board"
+    }
+
+    fn description(&self) -> &'static str {
+        "Create a new Miro board"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: CreateBoardParams,
+        _ctx: &ToolContext,
+    ) -> Result<Board, MiroError> {
+        client.create_board(p.name, p.description).await
+    }
+}
+
+pub struct GetBoard;
+
+#[async_trait]
+impl ToolHandler for GetBoard {
+    type Input = BoardIdParams;
+    type Output = Board;
+
+    fn name(&self) -> &'static str {
+        "get_board"
+    }
+
+    fn description(&self) -> &'static str {
+        "Get a board's details: owner, team, sharing policy and view link"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_READ
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: BoardIdParams,
+        _ctx: &ToolContext,
+    ) -> Result<Board, MiroError> {
+        client.get_board(&p.board_id).await
+    }
+}
+
+pub struct UpdateBoard;
+
+#[async_trait]
+impl ToolHandler for UpdateBoard {
+    type Input = UpdateBoardParams;
+    type Output = Board;
+
+    fn name(&self) -> &'static str {
+        "update_board"
+    }
+
+    fn description(&self) -> &'static str {
+        "Rename a board or change its description"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: UpdateBoardParams,
+        _ctx: &ToolContext,
+    ) -> Result<Board, MiroError> {
+        client
+            .update_board(&p.board_id, p.name, p.description)
+            .await
+    }
+}
+
+pub struct CopyBoard;
+
+#[async_trait]
+impl ToolHandler for CopyBoard {
+    type Input = CopyBoardParams;
+    type Output = Board;
+
+    fn name(&self) -> &'static str {
+        "copy_board"
+    }
+
+    fn description(&self) -> &'static str {
+        "Copy a board with all its content, e.g. to start a sprint from a template board"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: CopyBoardParams,
+        _ctx: &ToolContext,
+    ) -> Result<Board, MiroError> {
+        client
+            .copy_board(&p.board_id, p.name, p.description, p.team_id)
+            .await
+    }
+}
+
+pub struct DeleteBoard;
+
+#[async_trait]
+impl ToolHandler for DeleteBoard {
+    type Input = BoardIdParams;
+    type Output = DeletedBoard;
+
+    fn name(&self) -> &'static str {
+        "delete_board"
+    }
+
+    fn description(&self) -> &'static str {
+        "Delete a board and everything on it"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: BoardIdParams,
+        _ctx: &ToolContext,
+    ) -> Result<DeletedBoard, MiroError> {
+        client.delete_board(&p.board_id).await?;
+        Ok(DeletedBoard {
+            deleted: p.board_id,
+        })
+    }
+}
+
+pub struct ListBoardMembers;
+
+#[async_trait]
+impl ToolHandler for ListBoardMembers {
+    type Input = BoardIdParams;
+    type Output = MemberList;
+
+    fn name(&self) -> &'static str {
+        "list_board_members"
+    }
+
+    fn description(&self) -> &'static str {
+        "List the members of a board with their roles"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_READ
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: BoardIdParams,
+        _ctx: &ToolContext,
+    ) -> Result<MemberList, MiroError> {
+        let members = client.list_board_members(&p.board_id).await?;
+        Ok(MemberList {
+            members: members.items,
+            truncated: members.truncated,
+        })
+    }
+}
+
+pub struct ShareBoard;
+
+#[async_trait]
+impl ToolHandler for ShareBoard {
+    type Input = ShareBoardParams;
+    type Output = AccessChanges;
+
+    fn name(&self) -> &'static str {
+        "share_board"
+    }
+
+    fn description(&self) -> &'static str {
+        "Invite people to a board by email with a role; sharing with an existing member changes \
+         their role. Use dry_run to preview the invitations; Miro lists members without \
+         emails, so their current access shows as unknown"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: ShareBoardParams,
+        _ctx: &ToolContext,
+    ) -> Result<AccessChanges, MiroError> {
+        let failed = if p.dry_run {
+            Vec::new()
+        } else {
+            client
+                .share_board(&p.board_id, p.emails.clone(), p.role, p.message)
+                .await?
+                .failed
+        };
+
+        // Board members are listed by display name, never by email, so an
+        // invitee may already be a member with any role
+        let changes = p
+            .emails
+            .into_iter()
+            .filter(|email| !failed.iter().any(|f| &f.email == email))
+            .map(|email| AccessChange {
+                who: email,
+                name: None,
+                from: Some("unknown".to_string()),
+                to: Some(p.role.as_str().to_string()),
+            })
+            .collect();
+        Ok(AccessChanges {
+            dry_run: p.dry_run,
+            changes,
+            failed,
+        })
+    }
+}
+
+pub struct UpdateBoardMember;
+
+#[async_trait]
+impl ToolHandler for UpdateBoardMember {
+    type Input = UpdateBoardMemberParams;
+    type Output = AccessChanges;
+
+    fn name(&self) -> &'static str {
+        "update_board_member"
+    }
+
+    fn description(&self) -> &'static str {
+        "Change a board member's role; use dry_run to preview the change"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: UpdateBoardMemberParams,
+        _ctx: &ToolContext,
+    ) -> Result<AccessChanges, MiroError> {
+        let member = client.get_board_member(&p.board_id, &p.member_id).await?;
+        let mut changes = vec![AccessChange::member(&member, Some(p.role))];
+        changes.retain(|change| change.from != change.to);
+        if !p.dry_run && !changes.is_empty() {
+            client
+                .update_board_member(&p.board_id, &p.member_id, p.role)
+                .await?;
+        }
+        Ok(AccessChanges {
+            dry_run: p.dry_run,
+            changes,
+            failed: Vec::new(),
+        })
+    }
+}
+
+pub struct RemoveBoardMember;
+
+#[async_trait]
+impl ToolHandler for RemoveBoardMember {
+    type Input = RemoveBoardMemberParams;
+    type Output = AccessChanges;
+
+    fn name(&self) -> &'static str {
+        "remove_board_member"
+    }
+
+    fn description(&self) -> &'static str {
+        "Remove a member from a board; use dry_run to preview who loses access"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: RemoveBoardMemberParams,
+        _ctx: &ToolContext,
+    ) -> Result<AccessChanges, MiroError> {
+        let member = client.get_board_member(&p.board_id, &p.member_id).await?;
+        if !p.dry_run {
+            client
+                .remove_board_member(&p.board_id, &p.member_id)
+                .await?;
+        }
+        Ok(AccessChanges {
+            dry_run: p.dry_run,
+            changes: vec![AccessChange::member(&member, None)],
+            failed: Vec::new(),
+        })
+    }
+}
+
+pub struct UpdateBoardSharing;
+
+#[async_trait]
+impl ToolHandler for UpdateBoardSharing {
+    type Input = UpdateBoardSharingParams;
+    type Output = AccessChanges;
+
+    fn name(&self) -> &'static str {
+        "update_board_sharing"
+    }
+
+    fn description(&self) -> &'static str {
+        "Change who can open a board by link, in the team or in the organization; \
+         use dry_run to preview who gains or loses access"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: UpdateBoardSharingParams,
+        _ctx: &ToolContext,
+    ) -> Result<AccessChanges, MiroError> {
+        let board = client.get_board(&p.board_id).await?;
+        let current = board
+            .policy
+            .and_then(|policy| policy.sharing_policy)
+            .unwrap_or_default();
+        let changes = AccessChange::sharing(&current, &p.sharing_policy);
+        if !p.dry_run && !changes.is_empty() {
+            client
+                .update_board_sharing(&p.board_id, p.sharing_policy)
+                .await?;
+        }
+        Ok(AccessChanges {
+            dry_run: p.dry_run,
+            changes,
+            failed: Vec::new(),
+        })
+    }
+}
+
+pub struct ListItems;
+
+#[async_trait]
+impl ToolHandler for ListItems {
+    type Input = ListItemsParams;
+    type Output = ItemList;
+
+    fn name(&self) -> &'static str {
+        "list_items"
+    }
+
+    fn description(&self) -> &'static str {
+        "List items on a board, optionally filtered by type and parent frame"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_READ
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: ListItemsParams,
+        _ctx: &ToolContext,
+    ) -> Result<ItemList, MiroError> {
+        let types: Option<Vec<&str>> = p
+            .item_types
+            .as_ref()
+            .map(|t| t.iter().map(String::as_str).collect());
+        let items = client
+            .list_items(&p.board_id, types, p.parent_id.as_deref())
+            .await?;
+        Ok(ItemList {
+            items: items.items,
+            truncated: items.truncated,
+        })
+    }
+}
+
+pub struct CreateStickyNote;
+
+#[async_trait]
+impl ToolHandler for CreateStickyNote {
+    type Input = CreateStickyNoteParams;
+    type Output = StickyNoteResponse;
+
+    fn name(&self) -> &'static str {
+        "create_sticky_note"
+    }
+
+    fn description(&self) -> &'static str {
+        "Create a sticky note on a board, optionally tagged with existing board tags"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: CreateStickyNoteParams,
+        _ctx: &ToolContext,
+    ) -> Result<StickyNoteResponse, MiroError> {
+        let mut note = StickyNoteBuilder::new(p.board_id, p.content, p.x, p.y).color(p.color);
+        if let Some(parent_id) = p.parent_id {
+            note = note.parent_id(parent_id);
+        }
+        for tag_id in p.tag_ids {
+            note = note.tag(tag_id);
+        }
+        note.build(client).await
+    }
+}
+
+pub struct CreateShape;
+
+#[async_trait]
+impl ToolHandler for CreateShape {
+    type Input = CreateShapeParams;
+    type Output = ShapeResponse;
+
+    fn name(&self) -> &'static str {
+        "create_shape"
+    }
+
+    fn description(&self) -> &'static str {
+        "Create a shape (rectangle, circle, triangle, ...) on a board"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: CreateShapeParams,
+        _ctx: &ToolContext,
+    ) -> Result<ShapeResponse, MiroError> {
+        client
+            .create_shape(
+                &p.board_id,
+                p.shape_type,
+                p.fill_color,
+                p.x,
+                p.y,
+                p.width,
+                p.height,
+                p.content,
+                p.parent_id,
+            )
+            .await
+    }
+}
+
+pub struct CreateText;
+
+#[async_trait]
+impl ToolHandler for CreateText {
+    type Input = CreateTextParams;
+    type Output = TextResponse;
+
+    fn name(&self) -> &'static str {
+        "create_text"
+    }
+
+    fn description(&self) -> &'static str {
+        "Create a text item on a board"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: CreateTextParams,
+        _ctx: &ToolContext,
+    ) -> Result<TextResponse, MiroError> {
+        client
+            .create_text(&p.board_id, p.content, p.x, p.y, p.width, p.parent_id)
+            .await
+    }
+}
+
+pub struct CreateFrame;
+
+#[async_trait]
+impl ToolHandler for CreateFrame {
+    type Input = CreateFrameParams;
+    type Output = FrameResponse;
+
+    fn name(&self) -> &'static str {
+        "create_frame"
+    }
+
+    fn description(&self) -> &'static str {
+        "Create a frame to group items on a board"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: CreateFrameParams,
+        _ctx: &ToolContext,
+    ) -> Result<FrameResponse, MiroError> {
+        client
+            .create_frame(
+                &p.board_id,
+                p.title,
+                p.x,
+                p.y,
+                p.width,
+                p.height,
+                p.fill_color,
+                p.parent_id,
+            )
+            .await
+    }
+}
+
+pub struct CreateCard;
+
+#[async_trait]
+impl ToolHandler for CreateCard {
+    type Input = CreateCardParams;
+    type Output = CardResponse;
+
+    fn name(&self) -> &'static str {
+        "create_card"
+    }
+
+    fn description(&self) -> &'static str {
+        "Create a card (e.g. an action item) with optional description, assignee, due date and tags"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: CreateCardParams,
+        _ctx: &ToolContext,
+    ) -> Result<CardResponse, MiroError> {
+        let mut card = CardBuilder::new(p.board_id, p.title, p.x, p.y);
+        if let Some(description) = p.description {
+            card = card.description(description);
+        }
+        if let Some(assignee_id) = p.assignee_id {
+            card = card.assignee(assignee_id);
+        }
+        if let Some(due_date) = p.due_date {
+            card = card.due_date(due_date);
+        }
+        if let Some(theme) = p.theme {
+            card = card.theme(theme);
+        }
+        if let Some(parent_id) = p.parent_id {
+            card = card.parent_id(parent_id);
+        }
+        for tag_id in p.tag_ids {
+            card = card.tag(tag_id);
+        }
+        card.build(client).await
+    }
+}
+
+pub struct GetCard;
+
+#[async_trait]
+impl ToolHandler for GetCard {
+    type Input = ItemIdParams;
+    type Output = CardResponse;
+
+    fn name(&self) -> &'static str {
+        "get_card"
+    }
+
+    fn description(&self) -> &'static str {
+        "Get a card with its title, description, assignee, due date and tags"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_READ
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: ItemIdParams,
+        _ctx: &ToolContext,
+    ) -> Result<CardResponse, MiroError> {
+        client.get_card(&p.board_id, &p.item_id).await
+    }
+}
+
+pub struct UpdateCard;
+
+#[async_trait]
+impl ToolHandler for UpdateCard {
+    type Input = UpdateCardParams;
+    type Output = CardResponse;
+
+    fn name(&self) -> &'static str {
+        "update_card"
+    }
+
+    fn description(&self) -> &'static str {
+        "Update a card's title, description, assignee, due date or color"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: UpdateCardParams,
+        _ctx: &ToolContext,
+    ) -> Result<CardResponse, MiroError> {
+        let data = CardData {
+            title: p.title,
+            description: p.description,
+            assignee_id: p.assignee_id,
+            due_date: p.due_date,
+        };
+        let style = p.theme.map(|theme| CardStyle {
+            card_theme: Some(theme),
+        });
+        client
+            .update_card(&p.board_id, &p.item_id, Some(data), style, None, None)
+            .await
+    }
+}
+
+pub struct CreateAppCard;
+
+#[async_trait]
+impl ToolHandler for CreateAppCard {
+    type Input = CreateAppCardParams;
+    type Output = AppCardResponse;
+
+    fn name(&self) -> &'static str {
+        "create_app_card"
+    }
+
+    fn description(&self) -> &'static str {
+        "Create an app card with custom fields and a connection status"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: CreateAppCardParams,
+        _ctx: &ToolContext,
+    ) -> Result<AppCardResponse, MiroError> {
+        let mut card = AppCardBuilder::new(p.board_id, p.title, p.x, p.y);
+        if let Some(description) = p.description {
+            card = card.description(description);
+        }
+        for field in p.fields {
+            card = card.custom_field(field);
+        }
+        if let Some(status) = p.status {
+            card = card.status(status);
+        }
+        if let Some(fill_color) = p.fill_color {
+            card = card.fill_color(fill_color);
+        }
+        if let Some(parent_id) = p.parent_id {
+            card = card.parent_id(parent_id);
+        }
+        card.build(client).await
+    }
+}
+
+pub struct GetAppCard;
+
+#[async_trait]
+impl ToolHandler for GetAppCard {
+    type Input = ItemIdParams;
+    type Output = AppCardResponse;
+
+    fn name(&self) -> &'static str {
+        "get_app_card"
+    }
+
+    fn description(&self) -> &'static str {
+        "Get an app card with its custom fields and status"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_READ
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: ItemIdParams,
+        _ctx: &ToolContext,
+    ) -> Result<AppCardResponse, MiroError> {
+        client.get_app_card(&p.board_id, &p.item_id).await
+    }
+}
+
+pub struct UpdateAppCard;
+
+#[async_trait]
+impl ToolHandler for UpdateAppCard {
+    type Input = UpdateAppCardParams;
+    type Output = AppCardResponse;
+
+    fn name(&self) -> &'static str {
+        "update_app_card"
+    }
+
+    fn description(&self) -> &'static str {
+        "Update an app card's title, description, custom fields, status or color"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: UpdateAppCardParams,
+        _ctx: &ToolContext,
+    ) -> Result<AppCardResponse, MiroError> {
+        let data = AppCardData {
+            title: p.title,
+            description: p.description,
+            fields: p.fields,
+            status: p.status,
+            owned: None,
+        };
+        let style = p.fill_color.map(|fill_color| AppCardStyle {
+            fill_color: Some(fill_color),
+        });
+        client
+            .update_app_card(&p.board_id, &p.item_id, Some(data), style, None, None)
+            .await
+    }
+}
+
+pub struct CreateConnector;
+
+#[async_trait]
+impl ToolHandler for CreateConnector {
+    type Input = CreateConnectorParams;
+    type Output = ConnectorResponse;
+
+    fn name(&self) -> &'static str {
+        "create_connector"
+    }
+
+    fn description(&self) -> &'static str {
+        "Connect two items on a board with a line"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: CreateConnectorParams,
+        _ctx: &ToolContext,
+    ) -> Result<ConnectorResponse, MiroError> {
+        client
+            .create_connector(
+                &p.board_id,
+                p.start_item_id,
+                p.end_item_id,
+                p.stroke_color,
+                p.stroke_width,
+                p.start_cap,
+                p.end_cap,
+                p.captions,
+            )
+            .await
+    }
+}
+
+pub struct UpdateItem;
+
+#[async_trait]
+impl ToolHandler for UpdateItem {
+    type Input = UpdateItemParams;
+    type Output = Item;
+
+    fn name(&self) -> &'static str {
+        "update_item"
+    }
+
+    fn description(&self) -> &'static str {
+        "Update an item's position, content, style, geometry or parent frame"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: UpdateItemParams,
+        _ctx: &ToolContext,
+    ) -> Result<Item, MiroError> {
+        client
+            .update_item(
+                &p.board_id,
+                &p.item_id,
+                p.position,
+                p.data,
+                p.style,
+                p.geometry,
+                p.parent_id,
+            )
+            .await
+    }
+}
+
+pub struct DeleteItem;
+
+#[async_trait]
+impl ToolHandler for DeleteItem {
+    type Input = DeleteItemParams;
+    type Output = DeletedItem;
+
+    fn name(&self) -> &'static str {
+        "delete_item"
+    }
+
+    fn description(&self) -> &'static str {
+        "Delete an item from a board"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: DeleteItemParams,
+        _ctx: &ToolContext,
+    ) -> Result<DeletedItem, MiroError> {
+        client.delete_item(&p.board_id, &p.item_id).await?;
+        Ok(DeletedItem { deleted: p.item_id })
+    }
+}
+
+pub struct BulkCreateItems;
+
+#[async_trait]
+impl ToolHandler for BulkCreateItems {
+    type Input = BulkCreateItemsParams;
+    type Output = BulkCreateResult;
+
+    fn name(&self) -> &'static str {
+        "bulk_create_items"
+    }
+
+    fn description(&self) -> &'static str {
+        "Create up to 200 items (sticky notes, shapes, texts, frames, cards, app cards) in one call, \
+         20 per Miro request"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: BulkCreateItemsParams,
+        ctx: &ToolContext,
+    ) -> Result<BulkCreateResult, MiroError> {
+        let total = p.items.len();
+        let mut created: Vec<Item> = Vec::with_capacity(total);
+
+        for chunk in p.items.chunks(MAX_BULK_ITEMS) {
+            // Chunks are the unit of cancellation: a chunk in flight always lands
+            if ctx.is_cancelled() {
+                info!(created = created.len(), total, "Bulk create cancelled");
+                return Ok(BulkCreateResult {
+                    items: created,
+                    cancelled: true,
+                });
+            }
+
+            match client.bulk_create_items(&p.board_id, chunk.to_vec()).await {
+                Ok(items) => created.extend(items),
+                Err(MiroError::Cancelled) => {
+                    return Ok(BulkCreateResult {
+                        items: created,
+                        cancelled: true,
+                    })
+                }
+                Err(e) if created.is_empty() => return Err(e),
+                Err(e) => {
+                    return Err(MiroError::BulkCreatePartial {
+                        created,
+                        total,
+                        error: Box::new(e),
+                    })
+                }
+            }
+
+            ctx.progress.report(
+                created.len() as f64,
+                Some(total as f64),
+                Some(&format!("Created {} of {} items", created.len(), total)),
+            );
+        }
+
+        Ok(BulkCreateResult {
+            items: created,
+            cancelled: false,
+        })
+    }
+}
+
+pub struct ListTags;
+
+#[async_trait]
+impl ToolHandler for ListTags {
+    type Input = BoardIdParams;
+    type Output = TagList;
+
+    fn name(&self) -> &'static str {
+        "list_tags"
+    }
+
+    fn description(&self) -> &'static str {
+        "List the tags defined on a board"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_READ
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: BoardIdParams,
+        _ctx: &ToolContext,
+    ) -> Result<TagList, MiroError> {
+        let tags = client.list_tags(&p.board_id).await?;
+        Ok(TagList {
+            tags: tags.items,
+            truncated: tags.truncated,
+        })
+    }
+}
+
+pub struct CreateTag;
+
+#[async_trait]
+impl ToolHandler for CreateTag {
+    type Input = CreateTagParams;
+    type Output = Tag;
+
+    fn name(&self) -> &'static str {
+        "create_tag"
+    }
+
+    fn description(&self) -> &'static str {
+        "Create a board tag that can be attached to sticky notes and cards"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: CreateTagParams,
+        _ctx: &ToolContext,
+    ) -> Result<Tag, MiroError> {
+        client.create_tag(&p.board_id, p.title, p.fill_color).await
+    }
+}
+
+pub struct UpdateTag;
+
+#[async_trait]
+impl ToolHandler for UpdateTag {
+    type Input = UpdateTagParams;
+    type Output = Tag;
+
+    fn name(&self) -> &'static str {
+        "update_tag"
+    }
+
+    fn description(&self) -> &'static str {
+        "Rename a board tag or change its color"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: UpdateTagParams,
+        _ctx: &ToolContext,
+    ) -> Result<Tag, MiroError> {
+        client
+            .update_tag(&p.board_id, &p.tag_id, p.title, p.fill_color)
+            .await
+    }
+}
+
+pub struct DeleteTag;
+
+#[async_trait]
+impl ToolHandler for DeleteTag {
+    type Input = TagIdParams;
+    type Output = DeletedTag;
+
+    fn name(&self) -> &'static str {
+        "delete_tag"
+    }
+
+    fn description(&self) -> &'static str {
+        "Delete a board tag; it is removed from every item carrying it"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: TagIdParams,
+        _ctx: &ToolContext,
+    ) -> Result<DeletedTag, MiroError> {
+        client.delete_tag(&p.board_id, &p.tag_id).await?;
+        Ok(DeletedTag { deleted: p.tag_id })
+    }
+}
+
+pub struct AttachTag;
+
+#[async_trait]
+impl ToolHandler for AttachTag {
+    type Input = ItemTagParams;
+    type Output = ItemTags;
+
+    fn name(&self) -> &'static str {
+        "attach_tag"
+    }
+
+    fn description(&self) -> &'static str {
+        "Attach a board tag to a sticky note or card"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: ItemTagParams,
+        _ctx: &ToolContext,
+    ) -> Result<ItemTags, MiroError> {
+        client
+            .attach_tag(&p.board_id, &p.item_id, &p.tag_id)
+            .await?;
+        let tags = client.get_item_tags(&p.board_id, &p.item_id).await?;
+        Ok(ItemTags {
+            item_id: p.item_id,
+            tags,
+        })
+    }
+}
+
+pub struct DetachTag;
+
+#[async_trait]
+impl ToolHandler for DetachTag {
+    type Input = ItemTagParams;
+    type Output = ItemTags;
+
+    fn name(&self) -> &'static str {
+        "detach_tag"
+    }
+
+    fn description(&self) -> &'static str {
+        "Detach a tag from an item; the tag stays on the board"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_WRITE
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: ItemTagParams,
+        _ctx: &ToolContext,
+    ) -> Result<ItemTags, MiroError> {
+        client
+            .detach_tag(&p.board_id, &p.item_id, &p.tag_id)
+            .await?;
+        let tags = client.get_item_tags(&p.board_id, &p.item_id).await?;
+        Ok(ItemTags {
+            item_id: p.item_id,
+            tags,
+        })
+    }
+}
+
+pub struct ListItemsByTag;
+
+#[async_trait]
+impl ToolHandler for ListItemsByTag {
+    type Input = TagIdParams;
+    type Output = ItemList;
+
+    fn name(&self) -> &'static str {
+        "list_items_by_tag"
+    }
+
+    fn description(&self) -> &'static str {
+        "List the sticky notes and cards carrying a tag"
+    }
+
+    fn scope(&self) -> &'static str {
+        BOARDS_READ
+    }
+
+    async fn call(
+        &self,
+        client: &dyn MiroApi,
+        p: TagIdParams,
+        _ctx: &ToolContext,
+    ) -> Result<ItemList, MiroError> {
+        let items = client.list_items_by_tag(&p.board_id, &p.tag_id).await?;
+        Ok(ItemList {
+            items: items.items,
+            truncated: items.truncated,
+        })
+    }
+}
+
+// ==================== Registry ====================
+
+/// The tool registry shared by every transport, in `tools/list` order
+pub fn registry() -> &'static ToolRegistry {
+    static REGISTRY: OnceLock<ToolRegistry> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        ToolRegistry::new()
+            .register(ListBoards)
+            .register(GetBoard)
+            .register(CreateBoard)
+            .register(UpdateBoard)
+            .register(CopyBoard)
+            .register(DeleteBoard)
+            .register(ListBoardMembers)
+            .register(ShareBoard)
+            .register(UpdateBoardMember)
+            .register(RemoveBoardMember)
+            .register(UpdateBoardSharing)
+            .register(ListItems)
+            .register(CreateStickyNote)
+            .register(CreateShape)
+            .register(CreateText)
+            .register(CreateFrame)
+            .register(CreateCard)
+            .register(GetCard)
+            .register(UpdateCard)
+            .register(CreateAppCard)
+            .register(GetAppCard)
+            .register(UpdateAppCard)
+            .register(CreateConnector)
+            .register(UpdateItem)
+            .register(DeleteItem)
+            .register(BulkCreateItems)
+            .register(ListTags)
+            .register(CreateTag)
+            .register(UpdateTag)
+            .register(DeleteTag)
+            .register(AttachTag)
+            .register(DetachTag)
+            .register(ListItemsByTag)
+    })
+}
+
+/// All tools exposed over MCP, in `tools/list` order
+pub fn list_tools() -> Vec<Tool> {
+    registry().tools()
+}
+
+// ==================== Tool Scopes ====================
+
+/// Scope required to call a tool (`None` for unknown tools)
+pub fn required_scope(name: &str) -> Option<&'static str> {
+    registry().required_scope(name)
+}
+
+/// Check that the caller's token grants the scope a tool requires
+pub fn authorize_tool(name: &str, user_info: &UserInfo) -> Result<(), AuthError> {
+    match required_scope(name) {
+        Some(scope) => user_info.require_scope(scope),
+        None => Ok(()),
+    }
+}
+
+/// Tools the caller's token is allowed to call, in `tools/list` order
+pub fn list_tools_for(user_info: &UserInfo) -> Vec<Tool> {
+    list_tools()
+        .into_iter()
+        .filter(|tool| authorize_tool(&tool.name, user_info).is_ok())
+        .collect()
+}
+
+// ==================== Tool Dispatch ====================
+
+/// Execute a tool by name against the Miro API
+///
+/// Returns a JSON-RPC error for unknown tools or arguments that fail schema
+/// validation; Miro API failures are reported as `isError` tool results.
+pub async fn call_tool(
+    client: &dyn MiroApi,
+    name: &str,
+    arguments: Value,
+) -> Result<CallToolResult, JsonRpcError> {
+    call_tool_with_context(client, name, arguments, &ToolContext::default()).await
+}
+
+/// Execute a tool with progress reporting and cancellation
+pub async fn call_tool_with_context(
+    client: &dyn MiroApi,
+    name: &str,
+    arguments: Value,
+    ctx: &ToolContext,
+) -> Result<CallToolResult, JsonRpcError> {
+    info!(tool = %name, "Executing tool");
+    registry().call(client, name, arguments, ctx).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::protocol::{error_codes, Content};
+    use crate::miro::MiroClient;
+    use serde_json::json;
+
+    #[test]
+    fn test_list_tools_names_unique() {
+        let tools = list_tools();
+        let mut names: Vec<_> = tools.iter().map(|t| t.name.as_str()).collect();
+        let total = names.len();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), total);
+        assert!(names.contains(&"list_boards"));
+        assert!(names.contains(&"bulk_create_items"));
+    }
+
+    #[test]
+    fn test_every_tool_declares_a_scope() {
+        for tool in list_tools() {
+            assert!(required_scope(&tool.name).is_some(), "{}", tool.name);
+        }
+    }
+
+    #[test]
+    fn test_list_tools_for_read_only_token() {
+        let user_info = UserInfo::new("user".to_string(), None, vec![BOARDS_READ.to_string()]);
+        let names: Vec<_> = list_tools_for(&user_info)
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "list_boards",
+                "get_board",
+                "list_board_members",
+                "list_items",
+                "get_card",
+                "get_app_card",
+                "list_tags",
+                "list_items_by_tag"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_authorize_tool_names_missing_scope() {
+        let user_info = UserInfo::new("user".to_string(), None, vec![BOARDS_READ.to_string()]);
+        assert!(authorize_tool("list_boards", &user_info).is_ok());
+        assert!(matches!(
+            authorize_tool("delete_item", &user_info),
+            Err(AuthError::InsufficientScope(scope)) if scope == BOARDS_WRITE
+        ));
+    }
+
+    #[test]
+    fn test_tool_schemas_are_objects() {
+        for tool in list_tools() {
+            assert_eq!(tool.input_schema["type"], "object", "{}", tool.name);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_call_unknown_tool() {
+        let client = MiroClient::new("token".to_string()).unwrap();
+        let err = call_tool(&client, "no_such_tool", json!({}))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, error_codes::INVALID_PARAMS);
+        assert!(err.message.contains("no_such_tool"));
+    }
+
+    #[tokio::test]
+    async fn test_call_tool_missing_required_argument() {
+        let client = MiroClient::new("token".to_string()).unwrap();
+        let result = call_tool(&client, "create_board", json!({})).await.unwrap();
+        assert!(result.is_error);
+        let error = &result.structured_content.unwrap()["error"];
+        assert_eq!(error["kind"], "invalid_arguments");
+        assert_eq!(error["fields"][0]["field"], "name");
+        assert_eq!(error["fields"][0]["message"], "is required");
+    }
+
+    #[test]
+    fn test_schemas_generated_from_params() {
+        let tools = list_tools();
+        let sticky = tools
+            .iter()
+            .find(|t| t.name == "create_sticky_note")
+            .unwrap();
+        let properties = &sticky.input_schema["properties"];
+        assert_eq!(
+            properties["content"]["description"],
+            "Note content (HTML allowed)"
+        );
+        assert_eq!(properties["color"]["default"], "light_yellow");
+        assert_eq!(
+            sticky.input_schema["required"],
+            json!(["board_id", "content", "x", "y"])
+        );
+    }
+
+    #[test]
+    fn test_output_schemas_are_objects() {
+        for tool in list_tools() {
+            let schema = tool.output_schema.expect("output schema");
+            assert_eq!(schema["type"], "object", "{}", tool.name);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bulk_item_count_validated_before_miro_call() {
+        let client = MiroClient::new("token".to_string()).unwrap();
+        let result = call_tool(
+            &client,
+            "bulk_create_items",
+            json!({ "board_id": "board-1", "items": [] }),
+        )
+        .await
+        .unwrap();
+        assert!(result.is_error);
+        let Content::Text { text } = &result.content[0];
+        assert!(text.contains("items: must have at least 1 items"));
+    }
+
+    #[test]
+    fn test_sharing_changes_list_affected_audiences() {
+        let current = BoardSharingPolicy {
+            access: Some("private".to_string()),
+            team_access: Some("edit".to_string()),
+            organization_access: Some("view".to_string()),
+            ..Default::default()
+        };
+        let requested = BoardSharingPolicy {
+            access: Some("view".to_string()),
+            team_access: Some("edit".to_string()),
+            organization_access: Some("private".to_string()),
+            ..Default::default()
+        };
+
+        let changes = AccessChange::sharing(&current, &requested);
+        assert_eq!(
+            changes,
+            vec![
+                AccessChange {
+                    who: "anyone_with_link".to_string(),
+                    name: None,
+                    from: None,
+                    to: Some("view".to_string()),
+                },
+                AccessChange {
+                    who: "organization".to_string(),
+                    name: None,
+                    from: Some("view".to_string()),
+                    to: None,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_arguments_listed_per_field() {
+        let client = MiroClient::new("token".to_string()).unwrap();
+        let result = call_tool(
+            &client,
+            "create_shape",
+            json!({ "board_id": "board-1", "shape_type": "circle", "x": "left", "y": 0, "width": 0 }),
+        )
+        .await
+        .unwrap();
+
+        assert!(result.is_error);
+        let structured = result.structured_content.as_ref().unwrap();
+        assert_eq!(structured["error"]["kind"], "invalid_arguments");
+        assert_eq!(structured["error"]["retryable"], false);
+        let fields: Vec<&str> = structured["error"]["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| e["field"].as_str().unwrap())
+            .collect();
+        assert_eq!(fields, vec!["height", "width", "x"]);
+        let Content::Text { text } = &result.content[0];
+        assert!(text.contains("x: expected number, got string"));
+    }
+}
@@ -0,0 +1,648 @@
+use crate::miro::builders::BoardQuery;
+use crate::miro::client::{MiroClient, MiroError};
+use crate::miro::pagination::{Collected, Page};
+use crate::miro::types::{
+    AppCardData, AppCardResponse, AppCardStyle, Board, BoardMember, BoardRole, BoardSharingPolicy,
+    BulkItemRequest, Caption, CardData, CardResponse, CardStyle, ConnectorResponse,
+    CreateAppCardRequest, CreateCardRequest, FrameResponse, Geometry, InvitationResult, Item,
+    Position, ShapeResponse, StickyNoteResponse, Tag, TextResponse,
+};
+use async_trait::async_trait;
+use serde_json::Value;
+
+/// Board and item operations of the Miro REST API
+///
+/// Implemented by [`MiroClient`]; MCP tool handlers depend on this trait so
+/// they can be exercised against test doubles.
+#[async_trait]
+pub trait MiroApi: Send + Sync {
+    /// List accessible boards, following pagination cursors
+    async fn list_boards(&self) -> Result<Collected<Board>, MiroError>;
+
+    /// Fetch a single page of boards
+    async fn list_boards_page(
+        &self,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<Page<Board>, MiroError>;
+
+    /// Fetch the page of boards matching a search query
+    async fn search_boards(&self, query: &BoardQuery) -> Result<Page<Board>, MiroError>;
+
+    /// Create a new board
+    async fn create_board(
+        &self,
+        name: String,
+        description: Option<String>,
+    ) -> Result<Board, MiroError>;
+
+    /// Fetch a single board
+    async fn get_board(&self, board_id: &str) -> Result<Board, MiroError>;
+
+    /// Rename a board or change its description
+    async fn update_board(
+        &self,
+        board_id: &str,
+        name: Option<String>,
+        description: Option<String>,
+    ) -> Result<Board, MiroError>;
+
+    /// Copy a board with its content
+    async fn copy_board(
+        &self,
+        board_id: &str,
+        name: Option<String>,
+        description: Option<String>,
+        team_id: Option<String>,
+    ) -> Result<Board, MiroError>;
+
+    /// Delete a board
+    async fn delete_board(&self, board_id: &str) -> Result<(), MiroError>;
+
+    /// Change the board's sharing policy
+    async fn update_board_sharing(
+        &self,
+        board_id: &str,
+        sharing_policy: BoardSharingPolicy,
+    ) -> Result<Board, MiroError>;
+
+    /// List board members, following pagination
+    async fn list_board_members(&self, board_id: &str)
+        -> Result<Collected<BoardMember>, MiroError>;
+
+    /// Fetch one board member
+    async fn get_board_member(
+        &self,
+        board_id: &str,
+        member_id: &str,
+    ) -> Result<BoardMember, MiroError>;
+
+    /// Invite users to a board by email
+    async fn share_board(
+        &self,
+        board_id: &str,
+        emails: Vec<String>,
+        role: BoardRole,
+        message: Option<String>,
+    ) -> Result<InvitationResult, MiroError>;
+
+    /// Change the role of a board member
+    async fn update_board_member(
+        &self,
+        board_id: &str,
+        member_id: &str,
+        role: BoardRole,
+    ) -> Result<BoardMember, MiroError>;
+
+    /// Remove a member from a board
+    async fn remove_board_member(&self, board_id: &str, member_id: &str) -> Result<(), MiroError>;
+
+    /// Create a card
+    async fn create_card(
+        &self,
+        board_id: &str,
+        card: CreateCardRequest,
+    ) -> Result<CardResponse, MiroError>;
+
+    /// Fetch a card
+    async fn get_card(&self, board_id: &str, item_id: &str) -> Result<CardResponse, MiroError>;
+
+    /// Update a card; `None` arguments and data fields are left unchanged
+    async fn update_card(
+        &self,
+        board_id: &str,
+        item_id: &str,
+        data: Option<CardData>,
+        style: Option<CardStyle>,
+        position: Option<Position>,
+        parent_id: Option<String>,
+    ) -> Result<CardResponse, MiroError>;
+
+    /// Create an app card
+    async fn create_app_card(
+        &self,
+        board_id: &str,
+        app_card: CreateAppCardRequest,
+    ) -> Result<AppCardResponse, MiroError>;
+
+    /// Fetch an app card
+    async fn get_app_card(
+        &self,
+        board_id: &str,
+        item_id: &str,
+    ) -> Result<AppCardResponse, MiroError>;
+
+    /// Update an app card; `None` arguments and data fields are left unchanged
+    async fn update_app_card(
+        &self,
+        board_id: &str,
+        item_id: &str,
+        data: Option<AppCardData>,
+        style: Option<AppCardStyle>,
+        position: Option<Position>,
+        parent_id: Option<String>,
+    ) -> Result<AppCardResponse, MiroError>;
+
+    /// Create a board tag
+    async fn create_tag(
+        &self,
+        board_id: &str,
+        title: String,
+        fill_color: Option<String>,
+    ) -> Result<Tag, MiroError>;
+
+    /// List the tags of a board
+    async fn list_tags(&self, board_id: &str) -> Result<Collected<Tag>, MiroError>;
+
+    /// Fetch one board tag
+    async fn get_tag(&self, board_id: &str, tag_id: &str) -> Result<Tag, MiroError>;
+
+    /// Rename a tag or change its color
+    async fn update_tag(
+        &self,
+        board_id: &str,
+        tag_id: &str,
+        title: Option<String>,
+        fill_color: Option<String>,
+    ) -> Result<Tag, MiroError>;
+
+    /// Delete a tag, detaching it from every item
+    async fn delete_tag(&self, board_id: &str, tag_id: &str) -> Result<(), MiroError>;
+
+    /// Attach a tag to a sticky note or card
+    async fn attach_tag(
+        &self,
+        board_id: &str,
+        item_id: &str,
+        tag_id: &str,
+    ) -> Result<(), MiroError>;
+
+    /// Detach a tag from an item
+    async fn detach_tag(
+        &self,
+        board_id: &str,
+        item_id: &str,
+        tag_id: &str,
+    ) -> Result<(), MiroError>;
+
+    /// Tags attached to an item
+    async fn get_item_tags(&self, board_id: &str, item_id: &str) -> Result<Vec<Tag>, MiroError>;
+
+    /// List the items carrying a tag
+    async fn list_items_by_tag(
+        &self,
+        board_id: &str,
+        tag_id: &str,
+    ) -> Result<Collected<Item>, MiroError>;
+
+    /// List items on a board, following pagination cursors
+    async fn list_items(
+        &self,
+        board_id: &str,
+        item_types: Option<Vec<&str>>,
+        parent_id: Option<&str>,
+    ) -> Result<Collected<Item>, MiroError>;
+
+    /// Fetch a single page of board items
+    async fn list_items_page(
+        &self,
+        board_id: &str,
+        item_types: Option<&[&str]>,
+        parent_id: Option<&str>,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<Page<Item>, MiroError>;
+
+    /// Create a sticky note
+    async fn create_sticky_note(
+        &self,
+        board_id: &str,
+        content: String,
+        x: f64,
+        y: f64,
+        color: String,
+        parent_id: Option<String>,
+    ) -> Result<StickyNoteResponse, MiroError>;
+
+    /// Create a shape
+    #[allow(clippy::too_many_arguments)]
+    async fn create_shape(
+        &self,
+        board_id: &str,
+        shape_type: String,
+        fill_color: String,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        content: Option<String>,
+        parent_id: Option<String>,
+    ) -> Result<ShapeResponse, MiroError>;
+
+    /// Create a text item
+    async fn create_text(
+        &self,
+        board_id: &str,
+        content: String,
+        x: f64,
+        y: f64,
+        width: f64,
+        parent_id: Option<String>,
+    ) -> Result<TextResponse, MiroError>;
+
+    /// Create a frame
+    #[allow(clippy::too_many_arguments)]
+    async fn create_frame(
+        &self,
+        board_id: &str,
+        title: String,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        fill_color: Option<String>,
+        parent_id: Option<String>,
+    ) -> Result<FrameResponse, MiroError>;
+
+    /// Create a connector between two items
+    #[allow(clippy::too_many_arguments)]
+    async fn create_connector(
+        &self,
+        board_id: &str,
+        start_item_id: String,
+        end_item_id: String,
+        stroke_color: Option<String>,
+        stroke_width: Option<f64>,
+        start_cap: Option<String>,
+        end_cap: Option<String>,
+        captions: Option<Vec<Caption>>,
+    ) -> Result<ConnectorResponse, MiroError>;
+
+    /// Update item position, content, style, geometry or parent
+    #[allow(clippy::too_many_arguments)]
+    async fn update_item(
+        &self,
+        board_id: &str,
+        item_id: &str,
+        position: Option<Position>,
+        data: Option<Value>,
+        style: Option<Value>,
+        geometry: Option<Geometry>,
+        parent_id: Option<String>,
+    ) -> Result<Item, MiroError>;
+
+    /// Delete an item
+    async fn delete_item(&self, board_id: &str, item_id: &str) -> Result<(), MiroError>;
+
+    /// Create up to 20 items in one request
+    async fn bulk_create_items(
+        &self,
+        board_id: &str,
+        items: Vec<BulkItemRequest>,
+    ) -> Result<Vec<Item>, MiroError>;
+}
+
+#[async_trait]
+impl MiroApi for MiroClient {
+    async fn list_boards(&self) -> Result<Collected<Board>, MiroError> {
+        MiroClient::list_boards(self).await
+    }
+
+    async fn list_boards_page(
+        &self,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<Page<Board>, MiroError> {
+        MiroClient::list_boards_page(self, limit, cursor).await
+    }
+
+    async fn search_boards(&self, query: &BoardQuery) -> Result<Page<Board>, MiroError> {
+        MiroClient::search_boards(self, query).await
+    }
+
+    async fn create_board(
+        &self,
+        name: String,
+        description: Option<String>,
+    ) -> Result<Board, MiroError> {
+        MiroClient::create_board(self, name, description).await
+    }
+
+    async fn get_board(&self, board_id: &str) -> Result<Board, MiroError> {
+        MiroClient::get_board(self, board_id).await
+    }
+
+    async fn update_board(
+        &self,
+        board_id: &str,
+        name: Option<String>,
+        description: Option<String>,
+    ) -> Result<Board, MiroError> {
+        MiroClient::update_board(self, board_id, name, description).await
+    }
+
+    async fn copy_board(
+        &self,
+        board_id: &str,
+        name: Option<String>,
+        description: Option<String>,
+        team_id: Option<String>,
+    ) -> Result<Board, MiroError> {
+        MiroClient::copy_board(self, board_id, name, description, team_id).await
+    }
+
+    async fn delete_board(&self, board_id: &str) -> Result<(), MiroError> {
+        MiroClient::delete_board(self, board_id).await
+    }
+
+    async fn update_board_sharing(
+        &self,
+        board_id: &str,
+        sharing_policy: BoardSharingPolicy,
+    ) -> Result<Board, MiroError> {
+        MiroClient::update_board_sharing(self, board_id, sharing_policy).await
+    }
+
+    async fn list_board_members(
+        &self,
+        board_id: &str,
+    ) -> Result<Collected<BoardMember>, MiroError> {
+        MiroClient::list_board_members(self, board_id).await
+    }
+
+    async fn get_board_member(
+        &self,
+        board_id: &str,
+        member_id: &str,
+    ) -> Result<BoardMember, MiroError> {
+        MiroClient::get_board_member(self, board_id, member_id).await
+    }
+
+    async fn share_board(
+        &self,
+        board_id: &str,
+        emails: Vec<String>,
+        role: BoardRole,
+        message: Option<String>,
+    ) -> Result<InvitationResult, MiroError> {
+        MiroClient::share_board(self, board_id, emails, role, message).await
+    }
+
+    async fn update_board_member(
+        &self,
+        board_id: &str,
+        member_id: &str,
+        role: BoardRole,
+    ) -> Result<BoardMember, MiroError> {
+        MiroClient::update_board_member(self, board_id, member_id, role).await
+    }
+
+    async fn remove_board_member(&self, board_id: &str, member_id: &str) -> Result<(), MiroError> {
+        MiroClient::remove_board_member(self, board_id, member_id).await
+    }
+
+    async fn create_card(
+        &self,
+        board_id: &str,
+        card: CreateCardRequest,
+    ) -> Result<CardResponse, MiroError> {
+        MiroClient::create_card(self, board_id, card).await
+    }
+
+    async fn get_card(&self, board_id: &str, item_id: &str) -> Result<CardResponse, MiroError> {
+        MiroClient::get_card(self, board_id, item_id).await
+    }
+
+    async fn update_card(
+        &self,
+        board_id: &str,
+        item_id: &str,
+        data: Option<CardData>,
+        style: Option<CardStyle>,
+        position: Option<Position>,
+        parent_id: Option<String>,
+    ) -> Result<CardResponse, MiroError> {
+        MiroClient::update_card(self, board_id, item_id, data, style, position, parent_id).await
+    }
+
+    async fn create_app_card(
+        &self,
+        board_id: &str,
+        app_card: CreateAppCardRequest,
+    ) -> Result<AppCardResponse, MiroError> {
+        MiroClient::create_app_card(self, board_id, app_card).await
+    }
+
+    async fn get_app_card(
+        &self,
+        board_id: &str,
+        item_id: &str,
+    ) -> Result<AppCardResponse, MiroError> {
+        MiroClient::get_app_card(self, board_id, item_id).await
+    }
+
+    async fn update_app_card(
+        &self,
+        board_id: &str,
+        item_id: &str,
+        data: Option<AppCardData>,
+        style: Option<AppCardStyle>,
+        position: Option<Position>,
+        parent_id: Option<String>,
+    ) -> Result<AppCardResponse, MiroError> {
+        MiroClient::update_app_card(self, board_id, item_id, data, style, position, parent_id).await
+    }
+
+    async fn create_tag(
+        &self,
+        board_id: &str,
+        title: String,
+        fill_color: Option<String>,
+    ) -> Result<Tag, MiroError> {
+        MiroClient::create_tag(self, board_id, title, fill_color).await
+    }
+
+    async fn list_tags(&self, board_id: &str) -> Result<Collected<Tag>, MiroError> {
+        MiroClient::list_tags(self, board_id).await
+    }
+
+    async fn get_tag(&self, board_id: &str, tag_id: &str) -> Result<Tag, MiroError> {
+        MiroClient::get_tag(self, board_id, tag_id).await
+    }
+
+    async fn update_tag(
+        &self,
+        board_id: &str,
+        tag_id: &str,
+        title: Option<String>,
+        fill_color: Option<String>,
+    ) -> Result<Tag, MiroError> {
+        MiroClient::update_tag(self, board_id, tag_id, title, fill_color).await
+    }
+
+    async fn delete_tag(&self, board_id: &str, tag_id: &str) -> Result<(), MiroError> {
+        MiroClient::delete_tag(self, board_id, tag_id).await
+    }
+
+    async fn attach_tag(
+        &self,
+        board_id: &str,
+        item_id: &str,
+        tag_id: &str,
+    ) -> Result<(), MiroError> {
+        MiroClient::attach_tag(self, board_id, item_id, tag_id).await
+    }
+
+    async fn detach_tag(
+        &self,
+        board_id: &str,
+        item_id: &str,
+        tag_id: &str,
+    ) -> Result<(), MiroError> {
+        MiroClient::detach_tag(self, board_id, item_id, tag_id).await
+    }
+
+    async fn get_item_tags(&self, board_id: &str, item_id: &str) -> Result<Vec<Tag>, MiroError> {
+        MiroClient::get_item_tags(self, board_id, item_id).await
+    }
+
+    async fn list_items_by_tag(
+        &self,
+        board_id: &str,
+        tag_id: &str,
+    ) -> Result<Collected<Item>, MiroError> {
+        MiroClient::list_items_by_tag(self, board_id, tag_id).await
+    }
+
+    async fn list_items(
+        &self,
+        board_id: &str,
+        item_types: Option<Vec<&str>>,
+        parent_id: Option<&str>,
+    ) -> Result<Collected<Item>, MiroError> {
+        MiroClient::list_items(self, board_id, item_types, parent_id).await
+    }
+
+    async fn list_items_page(
+        &self,
+        board_id: &str,
+        item_types: Option<&[&str]>,
+        parent_id: Option<&str>,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<Page<Item>, MiroError> {
+        MiroClient::list_items_page(self, board_id, item_types, parent_id, limit, cursor).await
+    }
+
+    async fn create_sticky_note(
+        &self,
+        board_id: &str,
+        content: String,
+        x: f64,
+        y: f64,
+        color: String,
+        parent_id: Option<String>,
+    ) -> Result<StickyNoteResponse, MiroError> {
+        MiroClient::create_sticky_note(self, board_id, content, x, y, color, parent_id).await
+    }
+
+    async fn create_shape(
+        &self,
+        board_id: &str,
+        shape_type: String,
+        fill_color: String,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        content: Option<String>,
+        parent_id: Option<String>,
+    ) -> Result<ShapeResponse, MiroError> {
+        MiroClient::create_shape(
+            self, board_id, shape_type, fill_color, x, y, width, height, content, parent_id,
+        )
+        .await
+    }
+
+    async fn create_text(
+        &self,
+        board_id: &str,
+        content: String,
+        x: f64,
+        y: f64,
+        width: f64,
+        parent_id: Option<String>,
+    ) -> Result<TextResponse, MiroError> {
+        MiroClient::create_text(self, board_id, content, x, y, width, parent_id).await
+    }
+
+    async fn create_frame(
+        &self,
+        board_id: &str,
+        title: String,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        fill_color: Option<String>,
+        parent_id: Option<String>,
+    ) -> Result<FrameResponse, MiroError> {
+        MiroClient::create_frame(
+            self, board_id, title, x, y, width, height, fill_color, parent_id,
+        )
+        .await
+    }
+
+    async fn create_connector(
+        &self,
+        board_id: &str,
+        start_item_id: String,
+        end_item_id: String,
+        stroke_color: Option<String>,
+        stroke_width: Option<f64>,
+        start_cap: Option<String>,
+        end_cap: Option<String>,
+        captions: Option<Vec<Caption>>,
+    ) -> Result<ConnectorResponse, MiroError> {
+        MiroClient::create_connector(
+            self,
+            board_id,
+            start_item_id,
+            end_item_id,
+            stroke_color,
+            stroke_width,
+            start_cap,
+            end_cap,
+            captions,
+        )
+        .await
+    }
+
+    async fn update_item(
+        &self,
+        board_id: &str,
+        item_id: &str,
+        position: Option<Position>,
+        data: Option<Value>,
+        style: Option<Value>,
+        geometry: Option<Geometry>,
+        parent_id: Option<String>,
+    ) -> Result<Item, MiroError> {
+        MiroClient::update_item(
+            self, board_id, item_id, position, data, style, geometry, parent_id,
+        )
+        .await
+    }
+
+    async fn delete_item(&self, board_id: &str, item_id: &str) -> Result<(), MiroError> {
+        MiroClient::delete_item(self, board_id, item_id).await
+    }
+
+    async fn bulk_create_items(
+        &self,
+        board_id: &str,
+        items: Vec<BulkItemRequest>,
+    ) -> Result<Vec<Item>, MiroError> {
+        MiroClient::bulk_create_items(self, board_id, items).await
+    }
+}
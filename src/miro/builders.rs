@@ -2,9 +2,13 @@
 ///
 /// This module provides fluent builder APIs for methods with many parameters,
 /// improving readability and making optional parameters explicit.
-use crate::miro::client::{MiroClient, MiroError};
+use crate::miro::api::MiroApi;
+use crate::miro::client::MiroError;
+use crate::miro::pagination::{collect_all, offset_from_cursor, paginate, Collected, Page};
 use crate::miro::types::{
-    Caption, ConnectorResponse, ShapeResponse, StickyNoteResponse, TextResponse,
+    AppCardData, AppCardField, AppCardResponse, AppCardStyle, Board, BoardSort, BulkItemRequest,
+    Caption, CardData, CardResponse, CardStyle, ConnectorResponse, CreateAppCardRequest,
+    CreateCardRequest, Geometry, Parent, Position, ShapeResponse, StickyNoteResponse, TextResponse,
 };
 
 /// Builder for creating sticky notes with fluent API
@@ -17,6 +21,7 @@ use crate::miro::types::{
 /// let note = StickyNoteBuilder::new("board-id", "Hello World", 0.0, 100.0)
 ///     .color("light_yellow")
 ///     .parent_id("frame-123")
+///     .tag("tag-456")
 ///     .build(client)
 ///     .await?;
 /// # Ok(())
@@ -29,6 +34,7 @@ pub struct StickyNoteBuilder {
     y: f64,
     color: String,
     parent_id: Option<String>,
+    tag_ids: Vec<String>,
 }
 
 impl StickyNoteBuilder {
@@ -47,6 +53,7 @@ impl StickyNoteBuilder {
             y,
             color: "light_yellow".to_string(), // Default color
             parent_id: None,
+            tag_ids: Vec::new(),
         }
     }
 
@@ -67,9 +74,19 @@ impl StickyNoteBuilder {
         self
     }
 
+    /// Attach an existing board tag (can be called multiple times)
+    pub fn tag(mut self, tag_id: impl Into<String>) -> Self {
+        self.tag_ids.push(tag_id.into());
+        self
+    }
+
     /// Build and create the sticky note
-    pub async fn build(self, client: &MiroClient) -> Result<StickyNoteResponse, MiroError> {
-        client
+    ///
+    /// Miro cannot tag a note on creation, so tags are attached one by one
+    /// afterwards. If attaching fails, the note stays on the board and the
+    /// error is returned.
+    pub async fn build(self, client: &dyn MiroApi) -> Result<StickyNoteResponse, MiroError> {
+        let mut note = client
             .create_sticky_note(
                 &self.board_id,
                 self.content,
@@ -78,7 +95,15 @@ impl StickyNoteBuilder {
                 self.color,
                 self.parent_id,
             )
-            .await
+            .await?;
+
+        for tag_id in self.tag_ids {
+            client.attach_tag(&self.board_id, &note.id, &tag_id).await?;
+            if !note.tag_ids.contains(&tag_id) {
+                note.tag_ids.push(tag_id);
+            }
+        }
+        Ok(note)
     }
 }
 
@@ -160,7 +185,7 @@ impl ShapeBuilder {
     }
 
     /// Build and create the shape
-    pub async fn build(self, client: &MiroClient) -> Result<ShapeResponse, MiroError> {
+    pub async fn build(self, client: &dyn MiroApi) -> Result<ShapeResponse, MiroError> {
         client
             .create_shape(
                 &self.board_id,
@@ -233,7 +258,7 @@ impl TextBuilder {
     }
 
     /// Build and create the text item
-    pub async fn build(self, client: &MiroClient) -> Result<TextResponse, MiroError> {
+    pub async fn build(self, client: &dyn MiroApi) -> Result<TextResponse, MiroError> {
         client
             .create_text(
                 &self.board_id,
@@ -338,7 +363,7 @@ impl ConnectorBuilder {
     }
 
     /// Build and create the connector
-    pub async fn build(self, client: &MiroClient) -> Result<ConnectorResponse, MiroError> {
+    pub async fn build(self, client: &dyn MiroApi) -> Result<ConnectorResponse, MiroError> {
         let captions = if self.captions.is_empty() {
             None
         } else {
@@ -360,6 +385,421 @@ impl ConnectorBuilder {
     }
 }
 
+/// Builder for creating cards with fluent API
+///
+/// # Example
+/// ```no_run
+/// # use miro_mcp_server::miro::client::MiroClient;
+/// # use miro_mcp_server::miro::builders::CardBuilder;
+/// # async fn example(client: &MiroClient) -> Result<(), Box<dyn std::error::Error>> {
+/// let card = CardBuilder::new("board-id", "Fix flaky CI job", 0.0, 100.0)
+///     .description("Raised in the sprint 12 retro")
+///     .assignee("3458764517517819000")
+///     .due_date("2025-03-31T17:00:00Z")
+///     .tag("tag-123")
+///     .build(client)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct CardBuilder {
+    board_id: String,
+    x: f64,
+    y: f64,
+    data: CardData,
+    theme: Option<String>,
+    width: Option<f64>,
+    parent_id: Option<String>,
+    tag_ids: Vec<String>,
+}
+
+impl CardBuilder {
+    /// Create a new card builder
+    ///
+    /// # Arguments
+    /// * `board_id` - Board ID to create card on
+    /// * `title` - Card title
+    /// * `x` - X coordinate (center of card)
+    /// * `y` - Y coordinate (center of card)
+    pub fn new(board_id: impl Into<String>, title: impl Into<String>, x: f64, y: f64) -> Self {
+        Self {
+            board_id: board_id.into(),
+            x,
+            y,
+            data: CardData {
+                title: Some(title.into()),
+                ..Default::default()
+            },
+            theme: None,
+            width: None,
+            parent_id: None,
+            tag_ids: Vec::new(),
+        }
+    }
+
+    /// Set the card description
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.data.description = Some(description.into());
+        self
+    }
+
+    /// Assign the card to a Miro user ID
+    pub fn assignee(mut self, assignee_id: impl Into<String>) -> Self {
+        self.data.assignee_id = Some(assignee_id.into());
+        self
+    }
+
+    /// Set the due date (ISO 8601, e.g. 2025-03-31T17:00:00Z)
+    pub fn due_date(mut self, due_date: impl Into<String>) -> Self {
+        self.data.due_date = Some(due_date.into());
+        self
+    }
+
+    /// Set the hex color of the card's left border (e.g. #2d9bf0)
+    pub fn theme(mut self, theme: impl Into<String>) -> Self {
+        self.theme = Some(theme.into());
+        self
+    }
+
+    /// Set the width in pixels (Miro default: 320)
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the parent frame ID
+    pub fn parent_id(mut self, parent_id: impl Into<String>) -> Self {
+        self.parent_id = Some(parent_id.into());
+        self
+    }
+
+    /// Attach an existing board tag (can be called multiple times)
+    pub fn tag(mut self, tag_id: impl Into<String>) -> Self {
+        self.tag_ids.push(tag_id.into());
+        self
+    }
+
+    fn request(self) -> (String, CreateCardRequest, Vec<String>) {
+        let request = CreateCardRequest {
+            data: self.data,
+            style: self.theme.map(|theme| CardStyle {
+                card_theme: Some(theme),
+            }),
+            position: Position {
+                x: self.x,
+                y: self.y,
+                origin: Some("center".to_string()),
+            },
+            geometry: self.width.map(|width| Geometry {
+                width,
+                height: None,
+            }),
+            parent: self.parent_id.map(|id| Parent { id }),
+        };
+        (self.board_id, request, self.tag_ids)
+    }
+
+    /// Item for [`bulk_create_items`](crate::miro::MiroApi::bulk_create_items)
+    ///
+    /// Bulk requests cannot carry tags; attach them to the created cards
+    /// with `attach_tag`.
+    pub fn into_bulk_item(self) -> BulkItemRequest {
+        let (_, request, _) = self.request();
+        BulkItemRequest::Card {
+            item_type: "card".to_string(),
+            data: request.data,
+            style: request.style,
+            position: request.position,
+            geometry: request.geometry,
+            parent: request.parent,
+        }
+    }
+
+    /// Build and create the card
+    ///
+    /// Tags are attached one by one after creation. If attaching fails, the
+    /// card stays on the board and the error is returned.
+    pub async fn build(self, client: &dyn MiroApi) -> Result<CardResponse, MiroError> {
+        let (board_id, request, tag_ids) = self.request();
+        let mut card = client.create_card(&board_id, request).await?;
+
+        for tag_id in tag_ids {
+            client.attach_tag(&board_id, &card.id, &tag_id).await?;
+            if !card.tag_ids.contains(&tag_id) {
+                card.tag_ids.push(tag_id);
+            }
+        }
+        Ok(card)
+    }
+}
+
+/// Builder for creating app cards with fluent API
+///
+/// App cards show custom fields instead of a fixed assignee and due date,
+/// and Miro does not attach tags to them; put such values in fields.
+///
+/// # Example
+/// ```no_run
+/// # use miro_mcp_server::miro::client::MiroClient;
+/// # use miro_mcp_server::miro::builders::AppCardBuilder;
+/// # async fn example(client: &MiroClient) -> Result<(), Box<dyn std::error::Error>> {
+/// let card = AppCardBuilder::new("board-id", "JIRA-42: Fix flaky CI", 0.0, 100.0)
+///     .field("Owner: Ada")
+///     .field("Due: 2025-03-31")
+///     .status("connected")
+///     .build(client)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct AppCardBuilder {
+    board_id: String,
+    x: f64,
+    y: f64,
+    data: AppCardData,
+    fill_color: Option<String>,
+    width: Option<f64>,
+    parent_id: Option<String>,
+}
+
+impl AppCardBuilder {
+    /// Create a new app card builder
+    ///
+    /// # Arguments
+    /// * `board_id` - Board ID to create app card on
+    /// * `title` - Card title
+    /// * `x` - X coordinate (center of card)
+    /// * `y` - Y coordinate (center of card)
+    pub fn new(board_id: impl Into<String>, title: impl Into<String>, x: f64, y: f64) -> Self {
+        Self {
+            board_id: board_id.into(),
+            x,
+            y,
+            data: AppCardData {
+                title: Some(title.into()),
+                ..Default::default()
+            },
+            fill_color: None,
+            width: None,
+            parent_id: None,
+        }
+    }
+
+    /// Set the card description
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.data.description = Some(description.into());
+        self
+    }
+
+    /// Add a plain text field (can be called multiple times)
+    pub fn field(self, value: impl Into<String>) -> Self {
+        self.custom_field(AppCardField {
+            value: Some(value.into()),
+            ..Default::default()
+        })
+    }
+
+    /// Add a field with colors, icon or tooltip (can be called multiple times)
+    pub fn custom_field(mut self, field: AppCardField) -> Self {
+        self.data.fields.get_or_insert_with(Vec::new).push(field);
+        self
+    }
+
+    /// Set the connection status: disconnected, connected or disabled
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.data.status = Some(status.into());
+        self
+    }
+
+    /// Set the hex color of the card's border
+    pub fn fill_color(mut self, fill_color: impl Into<String>) -> Self {
+        self.fill_color = Some(fill_color.into());
+        self
+    }
+
+    /// Set the width in pixels (Miro default: 320)
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the parent frame ID
+    pub fn parent_id(mut self, parent_id: impl Into<String>) -> Self {
+        self.parent_id = Some(parent_id.into());
+        self
+    }
+
+    fn request(self) -> (String, CreateAppCardRequest) {
+        let request = CreateAppCardRequest {
+            data: self.data,
+            style: self.fill_color.map(|fill_color| AppCardStyle {
+                fill_color: Some(fill_color),
+            }),
+            position: Position {
+                x: self.x,
+                y: self.y,
+                origin: Some("center".to_string()),
+            },
+            geometry: self.width.map(|width| Geometry {
+                width,
+                height: None,
+            }),
+            parent: self.parent_id.map(|id| Parent { id }),
+        };
+        (self.board_id, request)
+    }
+
+    /// Item for [`bulk_create_items`](crate::miro::MiroApi::bulk_create_items)
+    pub fn into_bulk_item(self) -> BulkItemRequest {
+        let (_, request) = self.request();
+        BulkItemRequest::AppCard {
+            item_type: "app_card".to_string(),
+            data: request.data,
+            style: request.style,
+            position: request.position,
+            geometry: request.geometry,
+            parent: request.parent,
+        }
+    }
+
+    /// Build and create the app card
+    pub async fn build(self, client: &dyn MiroApi) -> Result<AppCardResponse, MiroError> {
+        let (board_id, request) = self.request();
+        client.create_app_card(&board_id, request).await
+    }
+}
+
+/// Builder for searching and filtering boards
+///
+/// Miro pages board listings by offset; the returned [`Page`] carries the
+/// offset of the next page as its cursor.
+///
+/// # Example
+/// ```no_run
+/// # use miro_mcp_server::miro::client::MiroClient;
+/// # use miro_mcp_server::miro::builders::BoardQuery;
+/// # use miro_mcp_server::miro::types::BoardSort;
+/// # async fn example(client: &MiroClient) -> Result<(), Box<dyn std::error::Error>> {
+/// let page = BoardQuery::new()
+///     .query("retro")
+///     .team_id("3458764517517819000")
+///     .sort(BoardSort::LastModified)
+///     .limit(50)
+///     .build(client)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BoardQuery {
+    query: Option<String>,
+    team_id: Option<String>,
+    project_id: Option<String>,
+    owner: Option<String>,
+    sort: Option<BoardSort>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+}
+
+impl BoardQuery {
+    /// Create a query matching every accessible board
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Search text, matched against board names and descriptions
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Only boards of this team
+    pub fn team_id(mut self, team_id: impl Into<String>) -> Self {
+        self.team_id = Some(team_id.into());
+        self
+    }
+
+    /// Only boards of this project (Enterprise plans)
+    pub fn project_id(mut self, project_id: impl Into<String>) -> Self {
+        self.project_id = Some(project_id.into());
+        self
+    }
+
+    /// Only boards owned by this user ID
+    pub fn owner(mut self, owner: impl Into<String>) -> Self {
+        self.owner = Some(owner.into());
+        self
+    }
+
+    /// Set the result order
+    pub fn sort(mut self, sort: BoardSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Set the page size (1-50, Miro default: 20)
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Skip the first `offset` matching boards
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Request path of `GET /boards` for this query
+    pub(crate) fn path(&self) -> String {
+        let mut query = url::form_urlencoded::Serializer::new(String::new());
+        let params = [
+            ("query", self.query.clone()),
+            ("team_id", self.team_id.clone()),
+            ("project_id", self.project_id.clone()),
+            ("owner", self.owner.clone()),
+            ("sort", self.sort.map(|sort| sort.as_str().to_string())),
+            ("limit", self.limit.map(|limit| limit.to_string())),
+            ("offset", self.offset.map(|offset| offset.to_string())),
+        ];
+        for (name, value) in params {
+            if let Some(value) = value {
+                query.append_pair(name, &value);
+            }
+        }
+
+        let query = query.finish();
+        if query.is_empty() {
+            "/boards".to_string()
+        } else {
+            format!("/boards?{}", query)
+        }
+    }
+
+    /// Fetch the page of boards this query describes
+    pub async fn build(self, client: &dyn MiroApi) -> Result<Page<Board>, MiroError> {
+        client.search_boards(&self).await
+    }
+
+    /// Fetch every matching board from the query's offset on, up to `max_items`
+    pub async fn collect(
+        self,
+        client: &dyn MiroApi,
+        max_items: usize,
+    ) -> Result<Collected<Board>, MiroError> {
+        let boards = paginate(|cursor: Option<String>| {
+            let query = self.clone();
+            async move {
+                let query = match cursor {
+                    Some(cursor) => query.offset(offset_from_cursor(&cursor)?),
+                    None => query,
+                };
+                client.search_boards(&query).await
+            }
+        });
+        collect_all(boards, max_items).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -376,6 +816,16 @@ mod tests {
         assert_eq!(builder.y, 200.0);
         assert_eq!(builder.color, "yellow");
         assert_eq!(builder.parent_id, Some("frame-456".to_string()));
+        assert!(builder.tag_ids.is_empty());
+    }
+
+    #[test]
+    fn test_sticky_note_builder_tags() {
+        let builder = StickyNoteBuilder::new("board-123", "Crash on login", 0.0, 0.0)
+            .tag("tag-bug")
+            .tag("tag-urgent");
+
+        assert_eq!(builder.tag_ids, vec!["tag-bug", "tag-urgent"]);
     }
 
     #[test]
@@ -462,4 +912,100 @@ mod tests {
         assert_eq!(builder.end_cap, None);
         assert_eq!(builder.captions.len(), 0);
     }
+
+    #[test]
+    fn test_card_builder_construction() {
+        let builder = CardBuilder::new("board-123", "Fix flaky CI", 10.0, 20.0)
+            .description("From the retro")
+            .assignee("user-1")
+            .due_date("2025-03-31T17:00:00Z")
+            .theme("#2d9bf0")
+            .width(400.0)
+            .parent_id("frame-456")
+            .tag("tag-1");
+
+        assert_eq!(builder.board_id, "board-123");
+        assert_eq!(builder.data.title, Some("Fix flaky CI".to_string()));
+        assert_eq!(builder.data.description, Some("From the retro".to_string()));
+        assert_eq!(builder.data.assignee_id, Some("user-1".to_string()));
+        assert_eq!(
+            builder.data.due_date,
+            Some("2025-03-31T17:00:00Z".to_string())
+        );
+        assert_eq!(builder.theme, Some("#2d9bf0".to_string()));
+        assert_eq!(builder.width, Some(400.0));
+        assert_eq!(builder.parent_id, Some("frame-456".to_string()));
+        assert_eq!(builder.tag_ids, vec!["tag-1"]);
+    }
+
+    #[test]
+    fn test_card_builder_into_bulk_item() {
+        let item = CardBuilder::new("board-123", "Fix flaky CI", 0.0, 0.0)
+            .assignee("user-1")
+            .into_bulk_item();
+        let json = serde_json::to_value(&item).unwrap();
+
+        assert_eq!(json["type"], "card");
+        assert_eq!(json["data"]["title"], "Fix flaky CI");
+        assert_eq!(json["data"]["assigneeId"], "user-1");
+        assert!(json.get("style").is_none());
+        assert!(json.get("geometry").is_none());
+    }
+
+    #[test]
+    fn test_app_card_builder_fields() {
+        let builder = AppCardBuilder::new("board-123", "JIRA-42", 0.0, 0.0)
+            .field("Owner: Ada")
+            .custom_field(AppCardField {
+                value: Some("Blocked".to_string()),
+                fill_color: Some("#ff0000".to_string()),
+                ..Default::default()
+            })
+            .status("connected");
+
+        let fields = builder.data.fields.as_ref().unwrap();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].value, Some("Owner: Ada".to_string()));
+        assert_eq!(fields[1].fill_color, Some("#ff0000".to_string()));
+        assert_eq!(builder.data.status, Some("connected".to_string()));
+
+        let json = serde_json::to_value(builder.into_bulk_item()).unwrap();
+        assert_eq!(json["type"], "app_card");
+        assert_eq!(json["data"]["fields"][1]["fillColor"], "#ff0000");
+    }
+
+    #[test]
+    fn test_board_query_construction() {
+        let query = BoardQuery::new()
+            .query("retro")
+            .team_id("team-1")
+            .project_id("project-2")
+            .owner("user-3")
+            .sort(BoardSort::LastModified)
+            .limit(50)
+            .offset(100);
+
+        assert_eq!(query.query, Some("retro".to_string()));
+        assert_eq!(query.team_id, Some("team-1".to_string()));
+        assert_eq!(query.project_id, Some("project-2".to_string()));
+        assert_eq!(query.owner, Some("user-3".to_string()));
+        assert_eq!(query.sort, Some(BoardSort::LastModified));
+        assert_eq!(query.limit, Some(50));
+        assert_eq!(query.offset, Some(100));
+    }
+
+    #[test]
+    fn test_board_query_path() {
+        assert_eq!(BoardQuery::new().path(), "/boards");
+        assert_eq!(
+            BoardQuery::new()
+                .query("Q3 plan & review")
+                .owner("user-3")
+                .sort(BoardSort::Alphabetically)
+                .limit(50)
+                .offset(50)
+                .path(),
+            "/boards?query=Q3+plan+%26+review&owner=user-3&sort=alphabetically&limit=50&offset=50"
+        );
+    }
 }
@@ -1,13 +1,32 @@
 use crate::auth::AuthError;
+use crate::miro::builders::BoardQuery;
+use crate::miro::config::{MiroClientConfig, DEFAULT_BASE_URL};
+use crate::miro::pagination::{
+    collect_all, offset_from_cursor, paginate, Collected, Page, DEFAULT_MAX_ITEMS,
+};
+use crate::miro::retry::{is_idempotent, RateLimitInfo, RetryPolicy};
 use crate::miro::types::{
-    Board, BoardsResponse, BulkCreateRequest, BulkCreateResponse, Caption, ConnectorResponse,
-    ConnectorStyle, CreateBoardRequest, CreateBoardResponse, CreateConnectorRequest,
-    CreateFrameRequest, CreateShapeRequest, CreateStickyNoteRequest, CreateTextRequest,
-    FrameResponse, Geometry, Item, ItemsResponse, Parent, Position, ShapeResponse,
-    StickyNoteResponse, TextResponse, UpdateItemRequest,
+    AppCardData, AppCardResponse, AppCardStyle, Board, BoardMember, BoardMembersResponse,
+    BoardPolicy, BoardRole, BoardSharingPolicy, BoardsResponse, BulkCreateRequest,
+    BulkCreateResponse, Caption, CardData, CardResponse, CardStyle, ConnectorResponse,
+    ConnectorStyle, CopyBoardRequest, CreateAppCardRequest, CreateBoardRequest, CreateCardRequest,
+    CreateConnectorRequest, CreateFrameRequest, CreateShapeRequest, CreateStickyNoteRequest,
+    CreateTagRequest, CreateTextRequest, FrameResponse, Geometry, InvitationResult, Item,
+    ItemTagsResponse, ItemsResponse, MiroApiError, Parent, Position, ShapeResponse,
+    ShareBoardRequest, StickyNoteResponse, Tag, TaggedItemsResponse, TagsResponse, TextResponse,
+    UpdateBoardMemberRequest, UpdateBoardRequest, UpdateCardRequest, UpdateItemRequest,
+    UpdateTagRequest,
 };
+use futures::Stream;
 use reqwest::StatusCode;
 use serde_json::Value;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, warn};
+
+/// Most items Miro accepts in one bulk create request
+pub const MAX_BULK_ITEMS: usize = 20;
 
 /// Error types for Miro API operations
 #[derive(Debug, thiserror::Error)]
@@ -21,37 +40,175 @@ pub enum MiroError {
     #[error("JSON serialization error: {0}")]
     JsonError(#[from] serde_json::Error),
 
-    #[error("API error {status}: {message}")]
-    ApiError { status: u16, message: String },
+    #[error("API error {status}: {0}", status = .0.status)]
+    ApiError(MiroApiError),
+
+    #[error("Insufficient permissions: {0}")]
+    Forbidden(MiroApiError),
+
+    #[error("Not found: {0}")]
+    NotFound(MiroApiError),
+
+    #[error("Conflict: {0}")]
+    Conflict(MiroApiError),
 
     #[error("Unauthorized - token may be invalid or expired")]
     Unauthorized,
 
-    #[error("Rate limit exceeded")]
-    RateLimitExceeded,
+    #[error("Rate limit exceeded ({0})")]
+    RateLimitExceeded(RateLimitInfo),
 
     #[error("Invalid bulk operation: {0}")]
     BulkOperationError(String),
+
+    /// A multi-request bulk create failed after some requests succeeded
+    #[error("{error} after creating {} of {total} items", .created.len())]
+    BulkCreatePartial {
+        /// Items that were created and remain on the board
+        created: Vec<Item>,
+        total: usize,
+        error: Box<MiroError>,
+    },
+
+    #[error("Request cancelled")]
+    Cancelled,
+
+    /// Arguments rejected before any request was sent
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
+    /// A bug in this client; nothing was sent to Miro
+    #[error("Internal error: {0}")]
+    Internal(String),
+}
+
+impl MiroError {
+    /// HTTP status Miro answered with, if the request got a response
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            MiroError::ApiError(e)
+            | MiroError::Forbidden(e)
+            | MiroError::NotFound(e)
+            | MiroError::Conflict(e) => Some(e.status),
+            MiroError::Unauthorized => Some(401),
+            MiroError::RateLimitExceeded(_) => Some(429),
+            MiroError::HttpError(e) => e.status().map(|s| s.as_u16()),
+            MiroError::BulkCreatePartial { error, .. } => error.status(),
+            _ => None,
+        }
+    }
+
+    /// Parsed Miro error body, for failures that carry one
+    pub fn api_error(&self) -> Option<&MiroApiError> {
+        match self {
+            MiroError::ApiError(e)
+            | MiroError::Forbidden(e)
+            | MiroError::NotFound(e)
+            | MiroError::Conflict(e) => Some(e),
+            MiroError::BulkCreatePartial { error, .. } => error.api_error(),
+            _ => None,
+        }
+    }
+}
+
+/// Shared HTTP connection pool for Miro API calls
+///
+/// Build one per process and hand out per-request [`MiroClient`] handles with
+/// [`for_token`](Self::for_token). Cloning is cheap; all clones share the
+/// same connections, TLS sessions and HTTP/2 streams.
+#[derive(Clone)]
+pub struct MiroHttpClient {
+    http_client: reqwest::Client,
+    base_url: Arc<str>,
+    retry_policy: RetryPolicy,
+}
+
+impl MiroHttpClient {
+    /// Build the pool with the given base URL, timeouts, keep-alive and retries
+    pub fn new(config: MiroClientConfig) -> Result<Self, MiroError> {
+        let http_client = reqwest::Client::builder()
+            .user_agent(config.user_agent)
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout)
+            .pool_idle_timeout(config.pool_idle_timeout)
+            .pool_max_idle_per_host(config.pool_max_idle_per_host)
+            .tcp_keepalive(config.tcp_keepalive)
+            .http2_adaptive_window(true)
+            .http2_keep_alive_interval(config.tcp_keepalive)
+            .http2_keep_alive_while_idle(true)
+            .build()?;
+
+        Ok(Self {
+            http_client,
+            base_url: config.base_url.trim_end_matches('/').into(),
+            retry_policy: config.retry_policy,
+        })
+    }
+
+    /// Cheap per-request handle that authenticates as the caller
+    pub fn for_token(&self, bearer_token: impl Into<String>) -> MiroClient {
+        MiroClient {
+            http: self.clone(),
+            bearer_token: bearer_token.into(),
+            cancellation: None,
+        }
+    }
+
+    /// API base URL, without trailing slash
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+}
+
+impl Default for MiroHttpClient {
+    fn default() -> Self {
+        Self::new(MiroClientConfig::default()).unwrap_or_else(|_| Self {
+            http_client: reqwest::Client::default(),
+            base_url: DEFAULT_BASE_URL.into(),
+            retry_policy: RetryPolicy::default(),
+        })
+    }
 }
 
 /// Miro API client for Resource Server pattern
 /// Accepts Bearer tokens from Authorization header (managed by Claude.ai)
+///
+/// A handle on a shared [`MiroHttpClient`] plus the caller's token.
+#[derive(Clone)]
 pub struct MiroClient {
-    http_client: reqwest::Client,
+    http: MiroHttpClient,
     bearer_token: String,
+    cancellation: Option<CancellationToken>,
 }
 
 impl MiroClient {
     /// Create a new Miro API client with a bearer token
+    ///
+    /// Builds a dedicated connection pool; servers should share one
+    /// [`MiroHttpClient`] and call [`MiroHttpClient::for_token`] instead.
     pub fn new(bearer_token: String) -> Result<Self, MiroError> {
-        let http_client = reqwest::Client::builder()
-            .user_agent("miro-mcp-server/0.1.0")
-            .build()?;
+        Self::with_config(bearer_token, MiroClientConfig::default())
+    }
 
-        Ok(Self {
-            http_client,
-            bearer_token,
-        })
+    /// Create a client with custom base URL, timeouts, user agent and retries
+    pub fn with_config(bearer_token: String, config: MiroClientConfig) -> Result<Self, MiroError> {
+        Ok(MiroHttpClient::new(config)?.for_token(bearer_token))
+    }
+
+    /// Replace the default retry policy (3 retries, exponential backoff)
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.http.retry_policy = retry_policy;
+        self
+    }
+
+    /// Stop issuing requests once `token` is cancelled
+    ///
+    /// Later calls fail with [`MiroError::Cancelled`] without reaching Miro,
+    /// and pending retries are abandoned. In-flight reads are aborted, but a
+    /// write already sent is awaited so callers know whether it happened.
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
     }
 
     // ==================== Builder Convenience Methods ====================
@@ -92,6 +249,33 @@ impl MiroClient {
         crate::miro::builders::TextBuilder::new(board_id, content, x, y, width)
     }
 
+    /// Create a card builder for fluent API usage
+    pub fn card(
+        &self,
+        board_id: impl Into<String>,
+        title: impl Into<String>,
+        x: f64,
+        y: f64,
+    ) -> crate::miro::builders::CardBuilder {
+        crate::miro::builders::CardBuilder::new(board_id, title, x, y)
+    }
+
+    /// Create an app card builder for fluent API usage
+    pub fn app_card(
+        &self,
+        board_id: impl Into<String>,
+        title: impl Into<String>,
+        x: f64,
+        y: f64,
+    ) -> crate::miro::builders::AppCardBuilder {
+        crate::miro::builders::AppCardBuilder::new(board_id, title, x, y)
+    }
+
+    /// Create a board search builder for fluent API usage
+    pub fn boards(&self) -> BoardQuery {
+        BoardQuery::new()
+    }
+
     /// Create a connector builder for fluent API usage
     pub fn connector(
         &self,
@@ -122,16 +306,55 @@ impl MiroClient {
         self.request("PATCH", path, body).await
     }
 
+    /// Make an authenticated PUT request to Miro API
+    pub async fn put(&self, path: &str, body: Option<Value>) -> Result<Value, MiroError> {
+        self.request("PUT", path, body).await
+    }
+
     /// Make an authenticated DELETE request to Miro API
     pub async fn delete(&self, path: &str) -> Result<Value, MiroError> {
         self.request("DELETE", path, None).await
     }
 
-    /// List all accessible Miro boards
-    pub async fn list_boards(&self) -> Result<Vec<Board>, MiroError> {
-        let response = self.get("/boards").await?;
+    /// List all accessible Miro boards (up to `DEFAULT_MAX_ITEMS`)
+    pub async fn list_boards(&self) -> Result<Collected<Board>, MiroError> {
+        collect_all(self.list_boards_stream(None), DEFAULT_MAX_ITEMS).await
+    }
+
+    /// Fetch a single page of boards
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Page size (Miro default applies when `None`)
+    /// * `cursor` - Cursor from the previous page, `None` for the first page
+    pub async fn list_boards_page(
+        &self,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<Page<Board>, MiroError> {
+        let mut query = BoardQuery::new();
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+        if let Some(cursor) = cursor {
+            query = query.offset(offset_from_cursor(cursor)?);
+        }
+        self.search_boards(&query).await
+    }
+
+    /// Fetch the page of boards matching a search query
+    pub async fn search_boards(&self, query: &BoardQuery) -> Result<Page<Board>, MiroError> {
+        let response = self.get(&query.path()).await?;
         let boards_response: BoardsResponse = serde_json::from_value(response)?;
-        Ok(boards_response.data)
+        Ok(boards_response.into())
+    }
+
+    /// Stream all boards, following pagination cursors
+    pub fn list_boards_stream(
+        &self,
+        limit: Option<u32>,
+    ) -> impl Stream<Item = Result<Board, MiroError>> + '_ {
+        paginate(move |cursor| async move { self.list_boards_page(limit, cursor.as_deref()).await })
     }
 
     /// Create a new Miro board
@@ -143,13 +366,183 @@ impl MiroClient {
         let request_body = CreateBoardRequest { name, description };
         let json_body = serde_json::to_value(&request_body)?;
         let response = self.post("/boards", Some(json_body)).await?;
-        let board: CreateBoardResponse = serde_json::from_value(response)?;
-        Ok(Board {
-            id: board.id,
-            name: board.name,
-            description: board.description,
-            created_at: board.created_at,
-        })
+        let board: Board = serde_json::from_value(response)?;
+        Ok(board)
+    }
+
+    /// Fetch a single board with its owner, team and sharing policy
+    pub async fn get_board(&self, board_id: &str) -> Result<Board, MiroError> {
+        let response = self.get(&format!("/boards/{}", board_id)).await?;
+        let board: Board = serde_json::from_value(response)?;
+        Ok(board)
+    }
+
+    /// Rename a board or change its description
+    ///
+    /// Fields left as `None` are unchanged.
+    pub async fn update_board(
+        &self,
+        board_id: &str,
+        name: Option<String>,
+        description: Option<String>,
+    ) -> Result<Board, MiroError> {
+        let request_body = UpdateBoardRequest {
+            name,
+            description,
+            ..Default::default()
+        };
+        let json_body = serde_json::to_value(&request_body)?;
+        let path = format!("/boards/{}", board_id);
+        let response = self.patch(&path, Some(json_body)).await?;
+        let board: Board = serde_json::from_value(response)?;
+        Ok(board)
+    }
+
+    /// Copy a board with its content, returning the new board
+    ///
+    /// # Arguments
+    ///
+    /// * `board_id` - Board to copy from
+    /// * `name` / `description` - For the copy (default: the source's)
+    /// * `team_id` - Team to create the copy in (default: the source's team)
+    pub async fn copy_board(
+        &self,
+        board_id: &str,
+        name: Option<String>,
+        description: Option<String>,
+        team_id: Option<String>,
+    ) -> Result<Board, MiroError> {
+        let request_body = CopyBoardRequest {
+            name,
+            description,
+            team_id,
+        };
+        let json_body = serde_json::to_value(&request_body)?;
+        let path = copy_board_path(board_id);
+        // A PUT, but every call creates a board: never retry after a server error
+        let response = self
+            .request_with("PUT", &path, Some(json_body), false)
+            .await?;
+        let board: Board = serde_json::from_value(response)?;
+        Ok(board)
+    }
+
+    /// Delete a board and everything on it
+    pub async fn delete_board(&self, board_id: &str) -> Result<(), MiroError> {
+        self.delete(&format!("/boards/{}", board_id)).await?;
+        Ok(())
+    }
+
+    /// Change who can access the board through links, the team and the organization
+    ///
+    /// Fields of `sharing_policy` left as `None` are unchanged.
+    pub async fn update_board_sharing(
+        &self,
+        board_id: &str,
+        sharing_policy: BoardSharingPolicy,
+    ) -> Result<Board, MiroError> {
+        let request_body = UpdateBoardRequest {
+            policy: Some(BoardPolicy {
+                sharing_policy: Some(sharing_policy),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let json_body = serde_json::to_value(&request_body)?;
+        let path = format!("/boards/{}", board_id);
+        let response = self.patch(&path, Some(json_body)).await?;
+        let board: Board = serde_json::from_value(response)?;
+        Ok(board)
+    }
+
+    /// List the members of a board (up to `DEFAULT_MAX_ITEMS`)
+    pub async fn list_board_members(
+        &self,
+        board_id: &str,
+    ) -> Result<Collected<BoardMember>, MiroError> {
+        let members = paginate(move |cursor| async move {
+            self.list_board_members_page(board_id, None, cursor.as_deref())
+                .await
+        });
+        collect_all(members, DEFAULT_MAX_ITEMS).await
+    }
+
+    /// Fetch a single page of board members
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Page size (Miro default applies when `None`)
+    /// * `cursor` - Cursor from the previous page, `None` for the first page
+    pub async fn list_board_members_page(
+        &self,
+        board_id: &str,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<Page<BoardMember>, MiroError> {
+        let path = offset_page_path(format!("/boards/{}/members", board_id), limit, cursor);
+        let response = self.get(&path).await?;
+        let members_response: BoardMembersResponse = serde_json::from_value(response)?;
+        Ok(members_response.into())
+    }
+
+    /// Fetch one board member
+    pub async fn get_board_member(
+        &self,
+        board_id: &str,
+        member_id: &str,
+    ) -> Result<BoardMember, MiroError> {
+        let path = format!("/boards/{}/members/{}", board_id, member_id);
+        let response = self.get(&path).await?;
+        let member: BoardMember = serde_json::from_value(response)?;
+        Ok(member)
+    }
+
+    /// Invite users to a board by email
+    ///
+    /// Miro reports emails it could not invite in
+    /// [`InvitationResult::failed`] instead of failing the request.
+    pub async fn share_board(
+        &self,
+        board_id: &str,
+        emails: Vec<String>,
+        role: BoardRole,
+        message: Option<String>,
+    ) -> Result<InvitationResult, MiroError> {
+        let request_body = ShareBoardRequest {
+            emails,
+            role,
+            message,
+        };
+        let json_body = serde_json::to_value(&request_body)?;
+        let path = format!("/boards/{}/members", board_id);
+        let response = self.post(&path, Some(json_body)).await?;
+        let result: InvitationResult = serde_json::from_value(response)?;
+        Ok(result)
+    }
+
+    /// Change the role of a board member
+    pub async fn update_board_member(
+        &self,
+        board_id: &str,
+        member_id: &str,
+        role: BoardRole,
+    ) -> Result<BoardMember, MiroError> {
+        let json_body = serde_json::to_value(UpdateBoardMemberRequest { role })?;
+        let path = format!("/boards/{}/members/{}", board_id, member_id);
+        let response = self.patch(&path, Some(json_body)).await?;
+        let member: BoardMember = serde_json::from_value(response)?;
+        Ok(member)
+    }
+
+    /// Remove a member from a board
+    pub async fn remove_board_member(
+        &self,
+        board_id: &str,
+        member_id: &str,
+    ) -> Result<(), MiroError> {
+        self.delete(&format!("/boards/{}/members/{}", board_id, member_id))
+            .await?;
+        Ok(())
     }
 
     /// Create a sticky note on a board
@@ -340,35 +733,66 @@ impl MiroClient {
     }
 
     /// List items on a board with optional type filtering and parent filtering
+    ///
+    /// Follows pagination cursors up to `DEFAULT_MAX_ITEMS` items; `truncated`
+    /// tells whether the board has more.
     pub async fn list_items(
         &self,
         board_id: &str,
         item_types: Option<Vec<&str>>,
         parent_id: Option<&str>,
-    ) -> Result<Vec<Item>, MiroError> {
-        let mut path = format!("/boards/{}/items", board_id);
-        let mut query_params = Vec::new();
-
-        // Add type filter if provided
-        if let Some(types) = item_types {
-            let type_filter = types.join(",");
-            query_params.push(format!("type={}", type_filter));
-        }
-
-        // Add parent filter if provided
-        if let Some(parent) = parent_id {
-            query_params.push(format!("parent.id={}", parent));
-        }
-
-        // Append query string if there are parameters
-        if !query_params.is_empty() {
-            path.push('?');
-            path.push_str(&query_params.join("&"));
-        }
+    ) -> Result<Collected<Item>, MiroError> {
+        collect_all(
+            self.list_items_stream(board_id, item_types, parent_id, None),
+            DEFAULT_MAX_ITEMS,
+        )
+        .await
+    }
 
+    /// Fetch a single page of board items
+    ///
+    /// # Arguments
+    ///
+    /// * `board_id` - Board to list
+    /// * `item_types` - Only items of these types (e.g., `["sticky_note", "shape"]`)
+    /// * `parent_id` - Only children of this frame
+    /// * `limit` - Page size (Miro accepts 10-50)
+    /// * `cursor` - Cursor from the previous page, `None` for the first page
+    pub async fn list_items_page(
+        &self,
+        board_id: &str,
+        item_types: Option<&[&str]>,
+        parent_id: Option<&str>,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<Page<Item>, MiroError> {
+        let path = items_path(board_id, item_types, parent_id, limit, cursor);
         let response = self.get(&path).await?;
         let items_response: ItemsResponse = serde_json::from_value(response)?;
-        Ok(items_response.data)
+        Ok(items_response.into())
+    }
+
+    /// Stream all items on a board, following pagination cursors
+    pub fn list_items_stream<'a>(
+        &'a self,
+        board_id: &'a str,
+        item_types: Option<Vec<&'a str>>,
+        parent_id: Option<&'a str>,
+        limit: Option<u32>,
+    ) -> impl Stream<Item = Result<Item, MiroError>> + 'a {
+        paginate(move |cursor| {
+            let item_types = item_types.clone();
+            async move {
+                self.list_items_page(
+                    board_id,
+                    item_types.as_deref(),
+                    parent_id,
+                    limit,
+                    cursor.as_deref(),
+                )
+                .await
+            }
+        })
     }
 
     /// Update item properties (position, content, style, geometry, parent)
@@ -405,6 +829,241 @@ impl MiroClient {
         Ok(())
     }
 
+    /// Create a card on a board
+    pub async fn create_card(
+        &self,
+        board_id: &str,
+        card: CreateCardRequest,
+    ) -> Result<CardResponse, MiroError> {
+        let json_body = serde_json::to_value(&card)?;
+        let path = format!("/boards/{}/cards", board_id);
+        let response = self.post(&path, Some(json_body)).await?;
+        let card: CardResponse = serde_json::from_value(response)?;
+        Ok(card)
+    }
+
+    /// Fetch a card
+    pub async fn get_card(&self, board_id: &str, item_id: &str) -> Result<CardResponse, MiroError> {
+        let path = format!("/boards/{}/cards/{}", board_id, item_id);
+        let response = self.get(&path).await?;
+        let card: CardResponse = serde_json::from_value(response)?;
+        Ok(card)
+    }
+
+    /// Update a card; `None` arguments and data fields are left unchanged
+    pub async fn update_card(
+        &self,
+        board_id: &str,
+        item_id: &str,
+        data: Option<CardData>,
+        style: Option<CardStyle>,
+        position: Option<Position>,
+        parent_id: Option<String>,
+    ) -> Result<CardResponse, MiroError> {
+        let request_body = UpdateCardRequest {
+            data,
+            style,
+            position,
+            parent: Self::make_parent(parent_id),
+        };
+        let json_body = serde_json::to_value(&request_body)?;
+        let path = format!("/boards/{}/cards/{}", board_id, item_id);
+        let response = self.patch(&path, Some(json_body)).await?;
+        let card: CardResponse = serde_json::from_value(response)?;
+        Ok(card)
+    }
+
+    /// Create an app card on a board
+    pub async fn create_app_card(
+        &self,
+        board_id: &str,
+        app_card: CreateAppCardRequest,
+    ) -> Result<AppCardResponse, MiroError> {
+        let json_body = serde_json::to_value(&app_card)?;
+        let path = format!("/boards/{}/app_cards", board_id);
+        let response = self.post(&path, Some(json_body)).await?;
+        let app_card: AppCardResponse = serde_json::from_value(response)?;
+        Ok(app_card)
+    }
+
+    /// Fetch an app card
+    pub async fn get_app_card(
+        &self,
+        board_id: &str,
+        item_id: &str,
+    ) -> Result<AppCardResponse, MiroError> {
+        let path = format!("/boards/{}/app_cards/{}", board_id, item_id);
+        let response = self.get(&path).await?;
+        let app_card: AppCardResponse = serde_json::from_value(response)?;
+        Ok(app_card)
+    }
+
+    /// Update an app card; `None` arguments and data fields are left unchanged
+    pub async fn update_app_card(
+        &self,
+        board_id: &str,
+        item_id: &str,
+        data: Option<AppCardData>,
+        style: Option<AppCardStyle>,
+        position: Option<Position>,
+        parent_id: Option<String>,
+    ) -> Result<AppCardResponse, MiroError> {
+        let request_body = UpdateCardRequest {
+            data,
+            style,
+            position,
+            parent: Self::make_parent(parent_id),
+        };
+        let json_body = serde_json::to_value(&request_body)?;
+        let path = format!("/boards/{}/app_cards/{}", board_id, item_id);
+        let response = self.patch(&path, Some(json_body)).await?;
+        let app_card: AppCardResponse = serde_json::from_value(response)?;
+        Ok(app_card)
+    }
+
+    /// Create a board tag
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Tag text, unique on the board
+    /// * `fill_color` - Tag color (Miro default applies when `None`)
+    pub async fn create_tag(
+        &self,
+        board_id: &str,
+        title: String,
+        fill_color: Option<String>,
+    ) -> Result<Tag, MiroError> {
+        let json_body = serde_json::to_value(CreateTagRequest { title, fill_color })?;
+        let path = format!("/boards/{}/tags", board_id);
+        let response = self.post(&path, Some(json_body)).await?;
+        let tag: Tag = serde_json::from_value(response)?;
+        Ok(tag)
+    }
+
+    /// List the tags of a board (up to `DEFAULT_MAX_ITEMS`)
+    pub async fn list_tags(&self, board_id: &str) -> Result<Collected<Tag>, MiroError> {
+        let tags = paginate(move |cursor| async move {
+            self.list_tags_page(board_id, None, cursor.as_deref()).await
+        });
+        collect_all(tags, DEFAULT_MAX_ITEMS).await
+    }
+
+    /// Fetch a single page of board tags
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Page size (Miro default applies when `None`)
+    /// * `cursor` - Cursor from the previous page, `None` for the first page
+    pub async fn list_tags_page(
+        &self,
+        board_id: &str,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<Page<Tag>, MiroError> {
+        let path = offset_page_path(format!("/boards/{}/tags", board_id), limit, cursor);
+        let response = self.get(&path).await?;
+        let tags_response: TagsResponse = serde_json::from_value(response)?;
+        Ok(tags_response.into())
+    }
+
+    /// Fetch one board tag
+    pub async fn get_tag(&self, board_id: &str, tag_id: &str) -> Result<Tag, MiroError> {
+        let path = format!("/boards/{}/tags/{}", board_id, tag_id);
+        let response = self.get(&path).await?;
+        let tag: Tag = serde_json::from_value(response)?;
+        Ok(tag)
+    }
+
+    /// Rename a tag or change its color
+    pub async fn update_tag(
+        &self,
+        board_id: &str,
+        tag_id: &str,
+        title: Option<String>,
+        fill_color: Option<String>,
+    ) -> Result<Tag, MiroError> {
+        let json_body = serde_json::to_value(UpdateTagRequest { title, fill_color })?;
+        let path = format!("/boards/{}/tags/{}", board_id, tag_id);
+        let response = self.patch(&path, Some(json_body)).await?;
+        let tag: Tag = serde_json::from_value(response)?;
+        Ok(tag)
+    }
+
+    /// Delete a tag, detaching it from every item
+    pub async fn delete_tag(&self, board_id: &str, tag_id: &str) -> Result<(), MiroError> {
+        self.delete(&format!("/boards/{}/tags/{}", board_id, tag_id))
+            .await?;
+        Ok(())
+    }
+
+    /// Attach a tag to a sticky note or card
+    pub async fn attach_tag(
+        &self,
+        board_id: &str,
+        item_id: &str,
+        tag_id: &str,
+    ) -> Result<(), MiroError> {
+        self.post(&item_tag_path(board_id, item_id, tag_id), None)
+            .await?;
+        Ok(())
+    }
+
+    /// Detach a tag from an item; the tag stays on the board
+    pub async fn detach_tag(
+        &self,
+        board_id: &str,
+        item_id: &str,
+        tag_id: &str,
+    ) -> Result<(), MiroError> {
+        self.delete(&item_tag_path(board_id, item_id, tag_id))
+            .await?;
+        Ok(())
+    }
+
+    /// Tags attached to an item
+    pub async fn get_item_tags(
+        &self,
+        board_id: &str,
+        item_id: &str,
+    ) -> Result<Vec<Tag>, MiroError> {
+        let path = format!("/boards/{}/items/{}/tags", board_id, item_id);
+        let response = self.get(&path).await?;
+        let tags_response: ItemTagsResponse = serde_json::from_value(response)?;
+        Ok(tags_response.tags)
+    }
+
+    /// List the items carrying a tag (up to `DEFAULT_MAX_ITEMS`)
+    pub async fn list_items_by_tag(
+        &self,
+        board_id: &str,
+        tag_id: &str,
+    ) -> Result<Collected<Item>, MiroError> {
+        let items = paginate(move |cursor| async move {
+            self.list_items_by_tag_page(board_id, tag_id, None, cursor.as_deref())
+                .await
+        });
+        collect_all(items, DEFAULT_MAX_ITEMS).await
+    }
+
+    /// Fetch a single page of the items carrying a tag
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Page size (Miro default applies when `None`)
+    /// * `cursor` - Cursor from the previous page, `None` for the first page
+    pub async fn list_items_by_tag_page(
+        &self,
+        board_id: &str,
+        tag_id: &str,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<Page<Item>, MiroError> {
+        let path = tagged_items_path(board_id, tag_id, limit, cursor);
+        let response = self.get(&path).await?;
+        let items_response: TaggedItemsResponse = serde_json::from_value(response)?;
+        Ok(items_response.into())
+    }
+
     /// Bulk create multiple items in a single API call (max 20 items per request)
     pub async fn bulk_create_items(
         &self,
@@ -412,7 +1071,6 @@ impl MiroClient {
         items: Vec<crate::miro::types::BulkItemRequest>,
     ) -> Result<Vec<Item>, MiroError> {
         // Validate item count (API limit is 20 items per request)
-        const MAX_BULK_ITEMS: usize = 20;
         if items.is_empty() {
             return Err(MiroError::BulkOperationError(
                 "Items array cannot be empty".to_string(),
@@ -434,25 +1092,125 @@ impl MiroClient {
         Ok(bulk_response.data)
     }
 
-    /// Make an authenticated request to Miro API
+    /// Make an authenticated request to Miro API, retrying per the retry policy
     async fn request(
         &self,
         method: &str,
         path: &str,
         body: Option<Value>,
     ) -> Result<Value, MiroError> {
-        let url = format!("https://api.miro.com/v2{}", path);
+        self.request_with(method, path, body, is_idempotent(method))
+            .await
+    }
+
+    /// Like [`request`](Self::request), for endpoints whose method does not
+    /// tell whether repeating them is safe
+    async fn request_with(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<Value>,
+        idempotent: bool,
+    ) -> Result<Value, MiroError> {
+        let url = format!("{}{}", self.http.base_url, path);
+        let mut attempt = 0;
+
+        loop {
+            if self.is_cancelled() {
+                return Err(MiroError::Cancelled);
+            }
+
+            let started = Instant::now();
+            let sent = self.send(method, &url, body.as_ref());
+            let result = match &self.cancellation {
+                // Reads can be dropped mid-flight; writes must finish to be accounted for
+                Some(token) if idempotent => tokio::select! {
+                    _ = token.cancelled() => return Err(MiroError::Cancelled),
+                    result = sent => result,
+                },
+                _ => sent.await,
+            };
+            debug!(
+                method = %method,
+                path = %path,
+                ok = result.is_ok(),
+                error_status = result.as_ref().err().and_then(MiroError::status),
+                elapsed_ms = started.elapsed().as_millis() as u64,
+                attempt = attempt,
+                "Miro API request finished"
+            );
+            let error = match result {
+                Ok(value) => return Ok(value),
+                Err(error) => error,
+            };
+
+            // Rate-limited requests were never processed, so any method may retry
+            let rate_limit = match &error {
+                MiroError::RateLimitExceeded(info) => Some(info.clone()),
+                MiroError::ApiError(e) if e.status >= 500 && idempotent => None,
+                MiroError::HttpError(e) if idempotent && (e.is_connect() || e.is_timeout()) => None,
+                _ => return Err(error),
+            };
+
+            let Some(delay) = self
+                .http
+                .retry_policy
+                .delay_for(attempt, rate_limit.as_ref())
+            else {
+                return Err(match error {
+                    MiroError::RateLimitExceeded(info) => {
+                        MiroError::RateLimitExceeded(RateLimitInfo {
+                            retries: attempt,
+                            ..info
+                        })
+                    }
+                    error => error,
+                });
+            };
+
+            attempt += 1;
+            warn!(
+                method = %method,
+                path = %path,
+                error = %error,
+                attempt = attempt,
+                delay_ms = delay.as_millis() as u64,
+                "Retrying Miro API request"
+            );
+            match &self.cancellation {
+                Some(token) => tokio::select! {
+                    _ = token.cancelled() => return Err(MiroError::Cancelled),
+                    _ = tokio::time::sleep(delay) => {}
+                },
+                None => tokio::time::sleep(delay).await,
+            }
+        }
+    }
 
+    fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    }
+
+    /// Send a single request without retries
+    async fn send(
+        &self,
+        method: &str,
+        url: &str,
+        body: Option<&Value>,
+    ) -> Result<Value, MiroError> {
         let mut request = match method {
-            "GET" => self.http_client.get(&url),
-            "POST" => self.http_client.post(&url),
-            "PATCH" => self.http_client.patch(&url),
-            "DELETE" => self.http_client.delete(&url),
+            "GET" => self.http.http_client.get(url),
+            "POST" => self.http.http_client.post(url),
+            "PATCH" => self.http.http_client.patch(url),
+            "PUT" => self.http.http_client.put(url),
+            "DELETE" => self.http.http_client.delete(url),
             _ => {
-                return Err(MiroError::ApiError {
-                    status: 400,
-                    message: format!("Unsupported HTTP method: {}", method),
-                })
+                return Err(MiroError::Internal(format!(
+                    "Unsupported HTTP method: {}",
+                    method
+                )))
             }
         };
 
@@ -460,7 +1218,7 @@ impl MiroClient {
         request = request.bearer_auth(&self.bearer_token);
 
         if let Some(body_value) = body {
-            request = request.json(&body_value);
+            request = request.json(body_value);
         }
 
         let response = request.send().await?;
@@ -472,26 +1230,151 @@ impl MiroClient {
             }
             StatusCode::NO_CONTENT => Ok(Value::Null),
             StatusCode::UNAUTHORIZED => Err(MiroError::Unauthorized),
-            StatusCode::TOO_MANY_REQUESTS => Err(MiroError::RateLimitExceeded),
+            StatusCode::TOO_MANY_REQUESTS => Err(MiroError::RateLimitExceeded(
+                RateLimitInfo::from_headers(response.headers()),
+            )),
             status => {
-                let message = response
-                    .text()
-                    .await
-                    .unwrap_or_else(|_| "Unknown error".to_string());
-
-                Err(MiroError::ApiError {
-                    status: status.as_u16(),
-                    message,
+                let body = response.text().await.unwrap_or_default();
+                let error = MiroApiError::parse(status.as_u16(), &body);
+
+                Err(match status {
+                    StatusCode::FORBIDDEN => MiroError::Forbidden(error),
+                    StatusCode::NOT_FOUND => MiroError::NotFound(error),
+                    StatusCode::CONFLICT => MiroError::Conflict(error),
+                    _ => MiroError::ApiError(error),
                 })
             }
         }
     }
 }
 
+/// Path of an offset-paginated listing; the cursor is the offset of the page
+fn offset_page_path(path: String, limit: Option<u32>, cursor: Option<&str>) -> String {
+    let mut query = url::form_urlencoded::Serializer::new(String::new());
+    append_offset_params(&mut query, limit, cursor);
+    with_query(path, query.finish())
+}
+
+/// Path for listing the items carrying a tag (offset-paginated)
+fn tagged_items_path(
+    board_id: &str,
+    tag_id: &str,
+    limit: Option<u32>,
+    cursor: Option<&str>,
+) -> String {
+    let mut query = url::form_urlencoded::Serializer::new(String::new());
+    query.append_pair("tag_id", tag_id);
+    append_offset_params(&mut query, limit, cursor);
+    with_query(format!("/boards/{}/items", board_id), query.finish())
+}
+
+/// Path for attaching a tag to an item or detaching it
+fn item_tag_path(board_id: &str, item_id: &str, tag_id: &str) -> String {
+    let mut query = url::form_urlencoded::Serializer::new(String::new());
+    query.append_pair("tag_id", tag_id);
+    with_query(
+        format!("/boards/{}/items/{}", board_id, item_id),
+        query.finish(),
+    )
+}
+
+/// Path for copying a board
+fn copy_board_path(board_id: &str) -> String {
+    let mut query = url::form_urlencoded::Serializer::new(String::new());
+    query.append_pair("copy_from", board_id);
+    with_query("/boards".to_string(), query.finish())
+}
+
+/// Path for listing board items, with filters and pagination parameters
+fn items_path(
+    board_id: &str,
+    item_types: Option<&[&str]>,
+    parent_id: Option<&str>,
+    limit: Option<u32>,
+    cursor: Option<&str>,
+) -> String {
+    let mut query = url::form_urlencoded::Serializer::new(String::new());
+
+    // Add type filter if provided
+    if let Some(types) = item_types {
+        query.append_pair("type", &types.join(","));
+    }
+
+    // Add parent filter if provided
+    if let Some(parent) = parent_id {
+        query.append_pair("parent.id", parent);
+    }
+
+    append_page_params(&mut query, limit, cursor);
+    with_query(format!("/boards/{}/items", board_id), query.finish())
+}
+
+fn append_page_params(
+    query: &mut url::form_urlencoded::Serializer<'_, String>,
+    limit: Option<u32>,
+    cursor: Option<&str>,
+) {
+    if let Some(limit) = limit {
+        query.append_pair("limit", &limit.to_string());
+    }
+    if let Some(cursor) = cursor {
+        query.append_pair("cursor", cursor);
+    }
+}
+
+fn append_offset_params(
+    query: &mut url::form_urlencoded::Serializer<'_, String>,
+    limit: Option<u32>,
+    cursor: Option<&str>,
+) {
+    if let Some(limit) = limit {
+        query.append_pair("limit", &limit.to_string());
+    }
+    if let Some(offset) = cursor {
+        query.append_pair("offset", offset);
+    }
+}
+
+/// Append an encoded query string to a path, if non-empty
+fn with_query(mut path: String, query: String) -> String {
+    if !query.is_empty() {
+        path.push('?');
+        path.push_str(&query);
+    }
+    path
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_tag_paths_use_offsets() {
+        assert_eq!(
+            tagged_items_path("b1", "t1", Some(20), Some("40")),
+            "/boards/b1/items?tag_id=t1&limit=20&offset=40"
+        );
+        assert_eq!(
+            item_tag_path("b1", "i1", "t1"),
+            "/boards/b1/items/i1?tag_id=t1"
+        );
+    }
+
+    #[test]
+    fn test_items_path_with_filters() {
+        assert_eq!(items_path("b1", None, None, None, None), "/boards/b1/items");
+        assert_eq!(
+            items_path(
+                "b1",
+                Some(&["sticky_note", "shape"]),
+                Some("frame-1"),
+                Some(50),
+                Some("next")
+            ),
+            "/boards/b1/items?type=sticky_note%2Cshape&parent.id=frame-1&limit=50&cursor=next"
+        );
+    }
+
     #[test]
     fn test_client_creation() {
         let bearer_token = "test_bearer_token".to_string();
@@ -0,0 +1,124 @@
+use crate::config::MiroHttpConfig;
+use crate::miro::retry::RetryPolicy;
+use std::time::Duration;
+
+/// Default Miro REST API base URL
+pub const DEFAULT_BASE_URL: &str = "https://api.miro.com/v2";
+
+/// HTTP settings for [`MiroClient`](crate::miro::MiroClient)
+///
+/// Defaults target the production Miro API; tests point `base_url` at a
+/// local mock server.
+#[derive(Debug, Clone)]
+pub struct MiroClientConfig {
+    /// API base URL, without trailing slash (e.g., "https://api.miro.com/v2")
+    pub base_url: String,
+    /// Total time allowed per HTTP request
+    pub timeout: Duration,
+    /// Time allowed to establish a connection
+    pub connect_timeout: Duration,
+    /// How long an idle pooled connection is kept open
+    pub pool_idle_timeout: Duration,
+    /// Maximum idle connections kept per host
+    pub pool_max_idle_per_host: usize,
+    /// TCP and HTTP/2 keep-alive interval
+    pub tcp_keepalive: Duration,
+    /// `User-Agent` header sent with every request
+    pub user_agent: String,
+    /// Retry behavior for failed requests
+    pub retry_policy: RetryPolicy,
+}
+
+impl MiroClientConfig {
+    /// Set the API base URL
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Set the per-request and connect timeouts
+    pub fn with_timeouts(mut self, timeout: Duration, connect_timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self.connect_timeout = connect_timeout;
+        self
+    }
+
+    /// Set connection pool limits
+    pub fn with_pool(mut self, idle_timeout: Duration, max_idle_per_host: usize) -> Self {
+        self.pool_idle_timeout = idle_timeout;
+        self.pool_max_idle_per_host = max_idle_per_host;
+        self
+    }
+
+    /// Set the TCP and HTTP/2 keep-alive interval
+    pub fn with_tcp_keepalive(mut self, tcp_keepalive: Duration) -> Self {
+        self.tcp_keepalive = tcp_keepalive;
+        self
+    }
+
+    /// Set the `User-Agent` header
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Set the retry policy
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+}
+
+impl Default for MiroClientConfig {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            timeout: Duration::from_secs(30),
+            connect_timeout: Duration::from_secs(10),
+            pool_idle_timeout: Duration::from_secs(90),
+            pool_max_idle_per_host: 32,
+            tcp_keepalive: Duration::from_secs(60),
+            user_agent: concat!("miro-mcp-server/", env!("CARGO_PKG_VERSION")).to_string(),
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+}
+
+impl From<&MiroHttpConfig> for MiroClientConfig {
+    fn from(settings: &MiroHttpConfig) -> Self {
+        Self::default()
+            .with_timeouts(
+                Duration::from_secs(settings.timeout_secs),
+                Duration::from_secs(settings.connect_timeout_secs),
+            )
+            .with_pool(
+                Duration::from_secs(settings.pool_idle_timeout_secs),
+                settings.pool_max_idle_per_host,
+            )
+            .with_tcp_keepalive(Duration::from_secs(settings.tcp_keepalive_secs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base_url_trailing_slash_trimmed() {
+        let config = MiroClientConfig::default().with_base_url("http://127.0.0.1:8080/v2/");
+        assert_eq!(config.base_url, "http://127.0.0.1:8080/v2");
+    }
+
+    #[test]
+    fn test_from_server_settings() {
+        let settings = MiroHttpConfig {
+            timeout_secs: 5,
+            pool_max_idle_per_host: 4,
+            ..Default::default()
+        };
+        let config = MiroClientConfig::from(&settings);
+        assert_eq!(config.timeout, Duration::from_secs(5));
+        assert_eq!(config.pool_max_idle_per_host, 4);
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+    }
+}
@@ -1,7 +1,21 @@
+pub mod api;
 pub mod builders;
 pub mod client;
+pub mod config;
+pub mod pagination;
+pub mod retry;
 pub mod types;
 
-pub use builders::{ConnectorBuilder, ShapeBuilder, StickyNoteBuilder, TextBuilder};
-pub use client::{MiroClient, MiroError};
-pub use types::{Board, BoardsResponse, CreateBoardRequest, CreateBoardResponse};
+pub use api::MiroApi;
+pub use builders::{
+    AppCardBuilder, BoardQuery, CardBuilder, ConnectorBuilder, ShapeBuilder, StickyNoteBuilder,
+    TextBuilder,
+};
+pub use client::{MiroClient, MiroError, MiroHttpClient, MAX_BULK_ITEMS};
+pub use config::MiroClientConfig;
+pub use pagination::{collect_all, paginate, Collected, Page, DEFAULT_MAX_ITEMS};
+pub use retry::{RateLimitInfo, RetryPolicy};
+pub use types::{
+    Board, BoardSort, BoardsResponse, CopyBoardRequest, CreateBoardRequest, MiroApiError,
+    MiroFieldError, UpdateBoardRequest,
+};
@@ -0,0 +1,240 @@
+use crate::miro::client::MiroError;
+use crate::miro::types::{
+    Board, BoardMember, BoardMembersResponse, BoardsResponse, Item, ItemsResponse, Tag,
+    TaggedItemsResponse, TagsResponse,
+};
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use std::future::Future;
+use tracing::warn;
+
+/// Default safety cap for helpers that collect every page into memory
+pub const DEFAULT_MAX_ITEMS: usize = 1000;
+
+/// One page of a cursor-paginated Miro listing
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    /// Items on this page
+    pub data: Vec<T>,
+    /// Cursor for the next page (`None` on the last page)
+    pub cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Page of an offset-paginated listing
+    ///
+    /// The next page's offset serves as cursor, so offset listings work with
+    /// [`paginate`] too.
+    pub fn from_offset(data: Vec<T>, offset: usize, total: usize) -> Self {
+        let next = offset + data.len();
+        Self {
+            cursor: (!data.is_empty() && next < total).then(|| next.to_string()),
+            data,
+        }
+    }
+}
+
+/// Offset encoded in a cursor of an offset-paginated listing
+pub fn offset_from_cursor(cursor: &str) -> Result<u32, MiroError> {
+    cursor
+        .parse()
+        .map_err(|_| MiroError::InvalidInput(format!("Invalid page cursor: {}", cursor)))
+}
+
+impl From<BoardsResponse> for Page<Board> {
+    fn from(response: BoardsResponse) -> Self {
+        Self::from_offset(response.data, response.offset, response.total)
+    }
+}
+
+impl From<ItemsResponse> for Page<Item> {
+    fn from(response: ItemsResponse) -> Self {
+        Self {
+            data: response.data,
+            cursor: response.cursor,
+        }
+    }
+}
+
+impl From<TagsResponse> for Page<Tag> {
+    fn from(response: TagsResponse) -> Self {
+        Self::from_offset(response.data, response.offset, response.total)
+    }
+}
+
+impl From<TaggedItemsResponse> for Page<Item> {
+    fn from(response: TaggedItemsResponse) -> Self {
+        Self::from_offset(response.data, response.offset, response.total)
+    }
+}
+
+impl From<BoardMembersResponse> for Page<BoardMember> {
+    fn from(response: BoardMembersResponse) -> Self {
+        Self::from_offset(response.data, response.offset, response.total)
+    }
+}
+
+/// Stream every item of a cursor-paginated listing
+///
+/// `fetch` is called with `None` for the first page and then with each
+/// returned cursor until a page comes back without one. Pages are fetched
+/// lazily, so dropping the stream stops further requests. The stream ends
+/// after the first error.
+pub fn paginate<'a, T, F, Fut>(fetch: F) -> impl Stream<Item = Result<T, MiroError>> + 'a
+where
+    T: 'a,
+    F: FnMut(Option<String>) -> Fut + 'a,
+    Fut: Future<Output = Result<Page<T>, MiroError>> + 'a,
+{
+    // State: fetcher and the cursor to request next (`None` once exhausted)
+    let initial: (F, Option<Option<String>>) = (fetch, Some(None));
+
+    stream::try_unfold(initial, |(mut fetch, next)| async move {
+        let Some(cursor) = next else {
+            return Ok::<_, MiroError>(None);
+        };
+
+        let page = fetch(cursor.clone()).await?;
+        let next = match page.cursor {
+            Some(next) if !next.is_empty() && Some(&next) != cursor.as_ref() => Some(Some(next)),
+            _ => None,
+        };
+
+        let items = stream::iter(page.data.into_iter().map(Ok));
+        Ok(Some((items, (fetch, next))))
+    })
+    .try_flatten()
+}
+
+/// Items of a listing gathered by [`collect_all`]
+#[derive(Debug, Clone)]
+pub struct Collected<T> {
+    pub items: Vec<T>,
+    /// True when the listing has more items than the cap let through
+    pub truncated: bool,
+}
+
+/// Collect a paginated stream, stopping after `max_items`
+///
+/// The cap protects against unbounded memory use on very large boards. One
+/// item past the cap is read to tell whether the listing was cut short;
+/// no further pages are fetched after that.
+pub async fn collect_all<T, S>(stream: S, max_items: usize) -> Result<Collected<T>, MiroError>
+where
+    S: Stream<Item = Result<T, MiroError>>,
+{
+    let mut items: Vec<T> = stream
+        .take(max_items.saturating_add(1))
+        .try_collect()
+        .await?;
+
+    let truncated = items.len() > max_items;
+    if truncated {
+        items.truncate(max_items);
+        warn!(max_items = max_items, "Pagination stopped at item cap");
+    }
+
+    Ok(Collected { items, truncated })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Fake listing of `pages` pages with `per_page` numbers each
+    fn fake_pages(
+        pages: usize,
+        per_page: usize,
+        calls: &AtomicUsize,
+    ) -> impl Stream<Item = Result<usize, MiroError>> + '_ {
+        paginate(move |cursor: Option<String>| async move {
+            calls.fetch_add(1, Ordering::SeqCst);
+            let page: usize = cursor.map(|c| c.parse().unwrap()).unwrap_or(0);
+            let start = page * per_page;
+            Ok(Page {
+                data: (start..start + per_page).collect(),
+                cursor: (page + 1 < pages).then(|| (page + 1).to_string()),
+            })
+        })
+    }
+
+    #[tokio::test]
+    async fn test_paginate_follows_cursors() {
+        let calls = AtomicUsize::new(0);
+        let items: Vec<usize> = fake_pages(3, 2, &calls).try_collect().await.unwrap();
+
+        assert_eq!(items, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_offset_page_cursor_is_next_offset() {
+        let page = Page::from_offset(vec![1, 2], 4, 10);
+        assert_eq!(page.cursor.as_deref(), Some("6"));
+
+        assert!(Page::from_offset(vec![1, 2], 8, 10).cursor.is_none());
+        assert!(Page::<i32>::from_offset(vec![], 0, 10).cursor.is_none());
+    }
+
+    #[test]
+    fn test_unparsable_cursor_is_invalid_input() {
+        assert_eq!(offset_from_cursor("6").unwrap(), 6);
+        assert!(matches!(
+            offset_from_cursor("next"),
+            Err(MiroError::InvalidInput(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_collect_all_stops_fetching_at_cap() {
+        let calls = AtomicUsize::new(0);
+        let collected = collect_all(fake_pages(100, 10, &calls), 25).await.unwrap();
+
+        assert_eq!(collected.items.len(), 25);
+        assert!(collected.truncated);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_collect_all_at_exact_cap_is_complete() {
+        let calls = AtomicUsize::new(0);
+        let collected = collect_all(fake_pages(2, 10, &calls), 20).await.unwrap();
+
+        assert_eq!(collected.items.len(), 20);
+        assert!(!collected.truncated);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_on_error() {
+        let stream = paginate(|cursor: Option<String>| async move {
+            match cursor {
+                None => Ok(Page {
+                    data: vec![1, 2],
+                    cursor: Some("next".to_string()),
+                }),
+                Some(_) => Err(MiroError::RateLimitExceeded(Default::default())),
+            }
+        });
+
+        let result: Result<Vec<i32>, _> = stream.try_collect().await;
+        assert!(matches!(result, Err(MiroError::RateLimitExceeded(_))));
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_on_repeated_cursor() {
+        let calls = AtomicUsize::new(0);
+        let stream = paginate(|_cursor: Option<String>| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async {
+                Ok(Page {
+                    data: vec![1],
+                    cursor: Some("same".to_string()),
+                })
+            }
+        });
+
+        let items: Vec<i32> = stream.try_collect().await.unwrap();
+        assert_eq!(items, vec![1, 1]);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
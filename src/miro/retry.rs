@@ -0,0 +1,295 @@
+use rand::Rng;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Rate-limit state reported by Miro on a response
+///
+/// Parsed from `Retry-After`, `X-RateLimit-Remaining` and `X-RateLimit-Reset`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// How long the server asked us to wait (`Retry-After`)
+    pub retry_after: Option<Duration>,
+    /// Credits left in the current window (`X-RateLimit-Remaining`)
+    pub remaining: Option<u64>,
+    /// When the window resets, Unix timestamp in seconds (`X-RateLimit-Reset`)
+    pub reset_at: Option<u64>,
+    /// Retries already attempted before giving up
+    pub retries: u32,
+}
+
+impl RateLimitInfo {
+    /// Extract rate-limit headers from a response
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::trim)
+        };
+
+        Self {
+            retry_after: header(RETRY_AFTER.as_str()).and_then(parse_retry_after),
+            remaining: header("x-ratelimit-remaining").and_then(|v| v.parse().ok()),
+            reset_at: header("x-ratelimit-reset").and_then(|v| v.parse().ok()),
+            retries: 0,
+        }
+    }
+
+    /// How long to wait before the next request is likely to succeed
+    ///
+    /// `Retry-After` wins; otherwise, when no credits remain, the time until
+    /// the rate-limit window resets.
+    pub fn wait_hint(&self) -> Option<Duration> {
+        if let Some(retry_after) = self.retry_after {
+            return Some(retry_after);
+        }
+        match (self.remaining, self.reset_at) {
+            (Some(0), Some(reset_at)) => Some(Duration::from_secs(reset_at.saturating_sub(now()))),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for RateLimitInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(wait) = self.wait_hint() {
+            parts.push(format!("retry after {}s", wait.as_secs().max(1)));
+        }
+        if let Some(remaining) = self.remaining {
+            parts.push(format!("{} credits remaining", remaining));
+        }
+        parts.push(format!("{} retries attempted", self.retries));
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+/// Retry policy for Miro API calls
+///
+/// - HTTP 429 is retried for every method (the request was not processed)
+/// - HTTP 5xx and transport errors are retried for idempotent methods only
+/// - Delays grow exponentially from `initial_backoff` with random jitter,
+///   unless Miro's rate-limit headers say how long to wait
+/// - A server-requested wait longer than `max_backoff` is not slept through;
+///   the error is returned so the caller can decide
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+}
+
+impl RetryPolicy {
+    /// Policy that never retries
+    pub fn none() -> Self {
+        Self::default().with_max_retries(0)
+    }
+
+    /// Set how many times a failed request is retried
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set the exponential backoff bounds
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Enable or disable random jitter on computed delays
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Delay before retry number `attempt` (0-based), or `None` to give up
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - Retries already made
+    /// * `rate_limit` - Headers of the failed response, if any
+    pub fn delay_for(&self, attempt: u32, rate_limit: Option<&RateLimitInfo>) -> Option<Duration> {
+        if attempt >= self.max_retries {
+            return None;
+        }
+
+        if let Some(hint) = rate_limit.and_then(RateLimitInfo::wait_hint) {
+            return (hint <= self.max_backoff).then_some(hint);
+        }
+
+        let exponent = attempt.min(16);
+        let delay = self
+            .initial_backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff);
+
+        if self.jitter {
+            // "Equal jitter": keep at least half the delay, randomize the rest
+            Some(delay.mul_f64(rand::thread_rng().gen_range(0.5..=1.0)))
+        } else {
+            Some(delay)
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+}
+
+/// Whether a request with this HTTP method can safely be sent twice
+pub fn is_idempotent(method: &str) -> bool {
+    matches!(method, "GET" | "HEAD" | "PUT" | "DELETE" | "OPTIONS")
+}
+
+/// Parse `Retry-After` as delta-seconds or an HTTP-date
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let seconds = u64::try_from(date.timestamp()).ok()?.saturating_sub(now());
+    Some(Duration::from_secs(seconds))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::default()
+            .with_backoff(Duration::from_millis(100), Duration::from_secs(5))
+            .with_jitter(false)
+    }
+
+    #[test]
+    fn test_exponential_backoff_capped() {
+        let policy = policy().with_max_retries(10);
+        assert_eq!(policy.delay_for(0, None), Some(Duration::from_millis(100)));
+        assert_eq!(policy.delay_for(1, None), Some(Duration::from_millis(200)));
+        assert_eq!(policy.delay_for(3, None), Some(Duration::from_millis(800)));
+        assert_eq!(policy.delay_for(9, None), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_gives_up_after_max_retries() {
+        let policy = policy().with_max_retries(2);
+        assert!(policy.delay_for(1, None).is_some());
+        assert!(policy.delay_for(2, None).is_none());
+        assert!(RetryPolicy::none().delay_for(0, None).is_none());
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let policy = policy().with_jitter(true);
+        for _ in 0..50 {
+            let delay = policy.delay_for(2, None).unwrap();
+            assert!(delay >= Duration::from_millis(200) && delay <= Duration::from_millis(400));
+        }
+    }
+
+    #[test]
+    fn test_retry_after_header_wins() {
+        let rate_limit = RateLimitInfo {
+            retry_after: Some(Duration::from_secs(2)),
+            ..Default::default()
+        };
+        assert_eq!(
+            policy().delay_for(0, Some(&rate_limit)),
+            Some(Duration::from_secs(2))
+        );
+    }
+
+    #[test]
+    fn test_wait_longer_than_max_backoff_gives_up() {
+        let rate_limit = RateLimitInfo {
+            retry_after: Some(Duration::from_secs(60)),
+            ..Default::default()
+        };
+        assert!(policy().delay_for(0, Some(&rate_limit)).is_none());
+    }
+
+    #[test]
+    fn test_parse_rate_limit_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        headers.insert("X-RateLimit-Remaining", HeaderValue::from_static("0"));
+        headers.insert("X-RateLimit-Reset", HeaderValue::from_static("1700000000"));
+
+        let info = RateLimitInfo::from_headers(&headers);
+        assert_eq!(info.retry_after, Some(Duration::from_secs(7)));
+        assert_eq!(info.remaining, Some(0));
+        assert_eq!(info.reset_at, Some(1_700_000_000));
+        assert_eq!(info.wait_hint(), Some(Duration::from_secs(7)));
+    }
+
+    #[test]
+    fn test_reset_used_when_credits_exhausted() {
+        let info = RateLimitInfo {
+            remaining: Some(0),
+            reset_at: Some(now() + 10),
+            ..Default::default()
+        };
+        let wait = info.wait_hint().unwrap();
+        assert!(wait <= Duration::from_secs(10) && wait >= Duration::from_secs(9));
+
+        let with_credits = RateLimitInfo {
+            remaining: Some(5),
+            reset_at: Some(now() + 10),
+            ..Default::default()
+        };
+        assert!(with_credits.wait_hint().is_none());
+    }
+
+    #[test]
+    fn test_parse_retry_after_http_date() {
+        let in_past = "Wed, 21 Oct 2015 07:28:00 GMT";
+        assert_eq!(parse_retry_after(in_past), Some(Duration::ZERO));
+        assert!(parse_retry_after("soon").is_none());
+    }
+
+    #[test]
+    fn test_display_tells_how_long_to_wait() {
+        let info = RateLimitInfo {
+            retry_after: Some(Duration::from_secs(12)),
+            remaining: Some(0),
+            reset_at: None,
+            retries: 3,
+        };
+        assert_eq!(
+            info.to_string(),
+            "retry after 12s, 0 credits remaining, 3 retries attempted"
+        );
+    }
+
+    #[test]
+    fn test_idempotent_methods() {
+        assert!(is_idempotent("GET"));
+        assert!(is_idempotent("DELETE"));
+        assert!(!is_idempotent("POST"));
+        assert!(!is_idempotent("PATCH"));
+    }
+}
@@ -1,27 +1,124 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Represents a parent frame reference
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Parent {
     pub id: String,
 }
 
 /// Represents a Miro board
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
 pub struct Board {
     pub id: String,
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(alias = "createdAt")]
     pub created_at: String,
+    #[serde(default, alias = "modifiedAt", skip_serializing_if = "Option::is_none")]
+    pub modified_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<BoardUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<BoardTeam>,
+    /// Who can access, copy and share the board
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy: Option<BoardPolicy>,
+    /// Link that opens the board in the browser
+    #[serde(default, alias = "viewLink", skip_serializing_if = "Option::is_none")]
+    pub view_link: Option<String>,
+}
+
+/// User referenced by a board (owner, creator)
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+pub struct BoardUser {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Team a board belongs to
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+pub struct BoardTeam {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Board access settings
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardPolicy {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions_policy: Option<BoardPermissionsPolicy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sharing_policy: Option<BoardSharingPolicy>,
+}
+
+/// Who may use collaboration tools, copy the board and share it
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardPermissionsPolicy {
+    /// e.g. `all_editors`, `board_owners_and_coowners`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collaboration_tools_start_access: Option<String>,
+    /// e.g. `anyone`, `team_members`, `team_editors`, `board_owner`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub copy_access: Option<String>,
+    /// e.g. `team_members_with_editing_rights`, `owner_and_coowners`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sharing_access: Option<String>,
+}
+
+/// Access granted through links, the organization and the team
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardSharingPolicy {
+    /// Anyone with the link: `private`, `view`, `comment` or `edit`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub access: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invite_to_account_and_board_link_access: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_access: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_access: Option<String>,
 }
 
-/// API response for list boards endpoint
+/// API response for list boards endpoint (offset-paginated)
 #[derive(Debug, Deserialize)]
 pub struct BoardsResponse {
     pub data: Vec<Board>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cursor: Option<String>,
+    #[serde(default)]
+    pub total: usize,
+    #[serde(default)]
+    pub offset: usize,
+}
+
+/// Order of board search results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BoardSort {
+    /// Miro's default order
+    #[default]
+    Default,
+    LastModified,
+    LastOpened,
+    LastCreated,
+    Alphabetically,
+}
+
+impl BoardSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BoardSort::Default => "default",
+            BoardSort::LastModified => "last_modified",
+            BoardSort::LastOpened => "last_opened",
+            BoardSort::LastCreated => "last_created",
+            BoardSort::Alphabetically => "alphabetically",
+        }
+    }
 }
 
 /// Request body for creating a board
@@ -32,18 +129,111 @@ pub struct CreateBoardRequest {
     pub description: Option<String>,
 }
 
-/// Response body for single board creation
-#[derive(Debug, Deserialize)]
-pub struct CreateBoardResponse {
+/// Request body for changing a board (`PATCH /boards/{id}`)
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateBoardRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<BoardPolicy>,
+}
+
+/// Role of a board member
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum BoardRole {
+    Viewer,
+    Commenter,
+    Editor,
+    Coowner,
+    /// Only reported by Miro; ownership cannot be granted
+    Owner,
+}
+
+impl BoardRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BoardRole::Viewer => "viewer",
+            BoardRole::Commenter => "commenter",
+            BoardRole::Editor => "editor",
+            BoardRole::Coowner => "coowner",
+            BoardRole::Owner => "owner",
+        }
+    }
+}
+
+/// A user with access to a board
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct BoardMember {
     pub id: String,
+    #[serde(default)]
     pub name: String,
+    pub role: BoardRole,
+}
+
+/// API response for the board members endpoint (offset-paginated)
+#[derive(Debug, Deserialize)]
+pub struct BoardMembersResponse {
+    pub data: Vec<BoardMember>,
+    #[serde(default)]
+    pub total: usize,
+    #[serde(default)]
+    pub offset: usize,
+}
+
+/// Request body for sharing a board (`POST /boards/{id}/members`)
+#[derive(Debug, Serialize)]
+pub struct ShareBoardRequest {
+    pub emails: Vec<String>,
+    pub role: BoardRole,
+    /// Text of the invitation email
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Request body for changing a member's role
+#[derive(Debug, Serialize)]
+pub struct UpdateBoardMemberRequest {
+    pub role: BoardRole,
+}
+
+/// Outcome of sharing a board
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+pub struct InvitationResult {
+    /// IDs of the users invited
+    #[serde(default)]
+    pub successful: Vec<u64>,
+    #[serde(default)]
+    pub failed: Vec<InvitationFailure>,
+}
+
+/// An email Miro did not invite
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct InvitationFailure {
+    pub email: String,
+    #[serde(default)]
+    pub reason: String,
+}
+
+/// Request body for copying a board (`PUT /boards?copy_from={id}`)
+///
+/// Unset fields are taken from the source board.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyBoardRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    pub created_at: String,
+    /// Team that owns the copy (default: the source board's team)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
 }
 
 /// Position for visual elements
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Position {
     pub x: f64,
     pub y: f64,
@@ -52,7 +242,7 @@ pub struct Position {
 }
 
 /// Geometry dimensions for visual elements
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Geometry {
     pub width: f64,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -60,7 +250,7 @@ pub struct Geometry {
 }
 
 /// Sticky note data payload
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct StickyNoteData {
     pub content: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -68,7 +258,7 @@ pub struct StickyNoteData {
 }
 
 /// Sticky note style configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct StickyNoteStyle {
     #[serde(rename = "fillColor")]
     pub fill_color: String,
@@ -86,7 +276,7 @@ pub struct CreateStickyNoteRequest {
 }
 
 /// Response for sticky note creation
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct StickyNoteResponse {
     pub id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -97,10 +287,13 @@ pub struct StickyNoteResponse {
     pub position: Option<Position>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub geometry: Option<Geometry>,
+    /// IDs of the board tags attached to the note
+    #[serde(rename = "tagIds", default, skip_serializing_if = "Vec::is_empty")]
+    pub tag_ids: Vec<String>,
 }
 
 /// Shape data payload
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ShapeData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content: Option<String>,
@@ -108,7 +301,7 @@ pub struct ShapeData {
 }
 
 /// Shape style configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ShapeStyle {
     #[serde(rename = "fillColor")]
     pub fill_color: String,
@@ -130,7 +323,7 @@ pub struct CreateShapeRequest {
 }
 
 /// Response for shape creation
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ShapeResponse {
     pub id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -144,7 +337,7 @@ pub struct ShapeResponse {
 }
 
 /// Text data payload
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TextData {
     pub content: String,
 }
@@ -160,7 +353,7 @@ pub struct CreateTextRequest {
 }
 
 /// Response for text creation
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TextResponse {
     pub id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -172,7 +365,7 @@ pub struct TextResponse {
 }
 
 /// Frame data payload
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FrameData {
     pub title: String,
     #[serde(rename = "type")]
@@ -180,7 +373,7 @@ pub struct FrameData {
 }
 
 /// Frame style configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FrameStyle {
     #[serde(rename = "fillColor")]
     pub fill_color: String,
@@ -198,7 +391,7 @@ pub struct CreateFrameRequest {
 }
 
 /// Response for frame creation
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FrameResponse {
     pub id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -211,8 +404,160 @@ pub struct FrameResponse {
     pub geometry: Option<Geometry>,
 }
 
+// ==================== Cards ====================
+
+/// Card data payload
+///
+/// Unknown fields are rejected so that bulk items of other types are not
+/// taken for cards; the same goes for [`AppCardData`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CardData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Miro user ID of the person the card is assigned to
+    #[serde(rename = "assigneeId", skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<String>,
+    /// Due date, ISO 8601 (e.g., 2025-03-31T17:00:00Z)
+    #[serde(rename = "dueDate", skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<String>,
+}
+
+/// Card style configuration
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct CardStyle {
+    /// Hex color of the card's left border (e.g., #2d9bf0)
+    #[serde(rename = "cardTheme", skip_serializing_if = "Option::is_none")]
+    pub card_theme: Option<String>,
+}
+
+/// Request body for creating a card
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateCardRequest {
+    pub data: CardData,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<CardStyle>,
+    pub position: Position,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geometry: Option<Geometry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Parent>,
+}
+
+/// Response for card creation, retrieval and update
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CardResponse {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<CardData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<CardStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<Position>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geometry: Option<Geometry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Parent>,
+    /// IDs of the board tags attached to the card
+    #[serde(rename = "tagIds", default, skip_serializing_if = "Vec::is_empty")]
+    pub tag_ids: Vec<String>,
+}
+
+/// Custom field shown on an app card
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct AppCardField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// Hex background color of the field
+    #[serde(rename = "fillColor", skip_serializing_if = "Option::is_none")]
+    pub fill_color: Option<String>,
+    /// Hex text color of the field
+    #[serde(rename = "textColor", skip_serializing_if = "Option::is_none")]
+    pub text_color: Option<String>,
+    #[serde(rename = "iconUrl", skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+    /// Icon shape: round or square
+    #[serde(rename = "iconShape", skip_serializing_if = "Option::is_none")]
+    pub icon_shape: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tooltip: Option<String>,
+}
+
+/// App card data payload
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AppCardData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Custom fields, in display order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<AppCardField>>,
+    /// Connection to the app's source item: disconnected, connected or disabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Whether the card belongs to the app that created it (read-only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owned: Option<bool>,
+}
+
+/// App card style configuration
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct AppCardStyle {
+    /// Hex color of the card's border
+    #[serde(rename = "fillColor", skip_serializing_if = "Option::is_none")]
+    pub fill_color: Option<String>,
+}
+
+/// Request body for creating an app card
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateAppCardRequest {
+    pub data: AppCardData,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<AppCardStyle>,
+    pub position: Position,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geometry: Option<Geometry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Parent>,
+}
+
+/// Response for app card creation, retrieval and update
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AppCardResponse {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<AppCardData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<AppCardStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<Position>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geometry: Option<Geometry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Parent>,
+}
+
+/// Request body for updating a card or app card (partial update)
+///
+/// `None` fields, including those inside `data`, are left unchanged.
+#[derive(Debug, Serialize)]
+pub struct UpdateCardRequest<D, S> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<D>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<S>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<Position>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Parent>,
+}
+
 /// Connector style configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ConnectorStyle {
     #[serde(rename = "strokeColor", skip_serializing_if = "Option::is_none")]
     pub stroke_color: Option<String>,
@@ -225,7 +570,7 @@ pub struct ConnectorStyle {
 }
 
 /// Caption for a connector
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Caption {
     pub content: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -246,7 +591,7 @@ pub struct CreateConnectorRequest {
 }
 
 /// Response for connector creation
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ConnectorResponse {
     pub id: String,
     #[serde(rename = "startItem", skip_serializing_if = "Option::is_none")]
@@ -260,7 +605,7 @@ pub struct ConnectorResponse {
 }
 
 /// Generic item response that can represent any item type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Item {
     pub id: String,
     #[serde(rename = "type")]
@@ -289,6 +634,64 @@ pub struct ItemsResponse {
     pub cursor: Option<String>,
 }
 
+/// Response for listing the items that carry a tag (offset-paginated)
+#[derive(Debug, Deserialize)]
+pub struct TaggedItemsResponse {
+    pub data: Vec<Item>,
+    #[serde(default)]
+    pub total: usize,
+    #[serde(default)]
+    pub offset: usize,
+}
+
+// ==================== Tags ====================
+
+/// Board tag, attachable to sticky notes and cards
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Tag {
+    pub id: String,
+    pub title: String,
+    #[serde(rename = "fillColor", skip_serializing_if = "Option::is_none")]
+    pub fill_color: Option<String>,
+}
+
+/// Response for list board tags endpoint (offset-paginated)
+#[derive(Debug, Deserialize)]
+pub struct TagsResponse {
+    pub data: Vec<Tag>,
+    #[serde(default)]
+    pub total: usize,
+    #[serde(default)]
+    pub offset: usize,
+}
+
+/// Response for the tags of one item
+#[derive(Debug, Deserialize)]
+pub struct ItemTagsResponse {
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+}
+
+/// Request body for creating a tag
+///
+/// Miro tag colors: red, light_green, cyan, yellow, magenta, green, blue,
+/// gray, violet, dark_green, dark_blue, black
+#[derive(Debug, Serialize)]
+pub struct CreateTagRequest {
+    pub title: String,
+    #[serde(rename = "fillColor", skip_serializing_if = "Option::is_none")]
+    pub fill_color: Option<String>,
+}
+
+/// Request body for updating a tag (partial update)
+#[derive(Debug, Serialize)]
+pub struct UpdateTagRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(rename = "fillColor", skip_serializing_if = "Option::is_none")]
+    pub fill_color: Option<String>,
+}
+
 /// Request body for updating an item (partial update)
 #[derive(Debug, Serialize)]
 pub struct UpdateItemRequest {
@@ -305,7 +708,7 @@ pub struct UpdateItemRequest {
 }
 
 /// Item definition for bulk creation - supports all item types
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum BulkItemRequest {
     /// Sticky note item
@@ -351,6 +754,32 @@ pub enum BulkItemRequest {
         #[serde(skip_serializing_if = "Option::is_none")]
         parent: Option<Parent>,
     },
+    /// Card item
+    Card {
+        #[serde(rename = "type")]
+        item_type: String, // must be "card"
+        data: CardData,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        style: Option<CardStyle>,
+        position: Position,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        geometry: Option<Geometry>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        parent: Option<Parent>,
+    },
+    /// App card item
+    AppCard {
+        #[serde(rename = "type")]
+        item_type: String, // must be "app_card"
+        data: AppCardData,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        style: Option<AppCardStyle>,
+        position: Position,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        geometry: Option<Geometry>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        parent: Option<Parent>,
+    },
 }
 
 /// Request body for bulk creating items
@@ -365,6 +794,80 @@ pub struct BulkCreateResponse {
     pub data: Vec<Item>,
 }
 
+/// Error body Miro returns with non-2xx responses
+///
+/// ```json
+/// {"status": 400, "code": "invalidParameters", "message": "...", "type": "error",
+///  "context": {"fields": [{"field": "data.content", "message": "..."}]}}
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MiroApiError {
+    /// HTTP status of the response
+    #[serde(default)]
+    pub status: u16,
+    /// Machine-readable error code (e.g. `invalidParameters`, `notFound`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(default)]
+    pub message: String,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub error_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<MiroErrorContext>,
+}
+
+/// Extra details of a Miro error
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MiroErrorContext {
+    /// Per-field validation failures
+    #[serde(default)]
+    pub fields: Vec<MiroFieldError>,
+}
+
+/// One invalid request field reported by Miro
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MiroFieldError {
+    /// Path of the field, e.g. `data.content`
+    pub field: String,
+    #[serde(default)]
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl MiroApiError {
+    /// Parse a response body; bodies that are not Miro JSON become the message
+    pub fn parse(status: u16, body: &str) -> Self {
+        let mut error = serde_json::from_str::<Self>(body).unwrap_or_else(|_| Self {
+            message: body.trim().to_string(),
+            ..Default::default()
+        });
+        error.status = status;
+        if error.message.is_empty() {
+            error.message = "Unknown error".to_string();
+        }
+        error
+    }
+
+    /// Field-level validation failures (empty when Miro sent none)
+    pub fn fields(&self) -> &[MiroFieldError] {
+        self.context.as_ref().map_or(&[], |c| c.fields.as_slice())
+    }
+}
+
+impl std::fmt::Display for MiroApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)?;
+        if let Some(code) = &self.code {
+            write!(f, " ({})", code)?;
+        }
+        for field in self.fields() {
+            write!(f, "; {}: {}", field.field, field.message)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -376,6 +879,7 @@ mod tests {
             name: "Test Board".to_string(),
             description: Some("A test board".to_string()),
             created_at: "2025-01-01T00:00:00Z".to_string(),
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&board).unwrap();
@@ -398,6 +902,45 @@ mod tests {
         assert_eq!(board.description, Some("Test description".to_string()));
     }
 
+    #[test]
+    fn test_board_deserialization_from_miro() {
+        let json = r#"{
+            "id": "uXjVOfjkmAk=",
+            "type": "board",
+            "name": "Sprint 14",
+            "description": "",
+            "createdAt": "2025-03-01T09:00:00Z",
+            "modifiedAt": "2025-03-02T10:30:00Z",
+            "owner": { "id": "3074457350804038700", "type": "user", "name": "Ada" },
+            "team": { "id": "3074457350804038600", "type": "team", "name": "Platform" },
+            "policy": {
+                "permissionsPolicy": { "copyAccess": "team_editors", "sharingAccess": "owner_and_coowners" },
+                "sharingPolicy": { "access": "private", "teamAccess": "edit" }
+            },
+            "viewLink": "https://miro.com/app/board/uXjVOfjkmAk=",
+            "links": { "self": "https://api.miro.com/v2/boards/uXjVOfjkmAk=" }
+        }"#;
+
+        let board: Board = serde_json::from_str(json).unwrap();
+        assert_eq!(board.created_at, "2025-03-01T09:00:00Z");
+        assert_eq!(board.modified_at.as_deref(), Some("2025-03-02T10:30:00Z"));
+        assert_eq!(board.owner.unwrap().name.as_deref(), Some("Ada"));
+        assert_eq!(board.team.unwrap().id, "3074457350804038600");
+        let policy = board.policy.unwrap();
+        assert_eq!(
+            policy.permissions_policy.unwrap().copy_access.as_deref(),
+            Some("team_editors")
+        );
+        assert_eq!(
+            policy.sharing_policy.unwrap().team_access.as_deref(),
+            Some("edit")
+        );
+        assert_eq!(
+            board.view_link.as_deref(),
+            Some("https://miro.com/app/board/uXjVOfjkmAk=")
+        );
+    }
+
     #[test]
     fn test_create_board_request() {
         let request = CreateBoardRequest {
@@ -868,6 +1411,45 @@ mod tests {
         assert!(json.contains("light_gray"));
     }
 
+    #[test]
+    fn test_bulk_item_request_cards_deserialization() {
+        let items: Vec<BulkItemRequest> = serde_json::from_value(serde_json::json!([
+            {
+                "type": "card",
+                "data": { "title": "Fix flaky CI", "assigneeId": "3074457", "dueDate": "2025-03-31T17:00:00Z" },
+                "position": { "x": 0.0, "y": 0.0 }
+            },
+            {
+                "type": "app_card",
+                "data": { "title": "JIRA-42", "fields": [{ "value": "In progress" }], "status": "connected" },
+                "position": { "x": 400.0, "y": 0.0 }
+            }
+        ]))
+        .unwrap();
+
+        match &items[0] {
+            BulkItemRequest::Card { data, .. } => {
+                assert_eq!(data.assignee_id.as_deref(), Some("3074457"));
+                assert_eq!(data.due_date.as_deref(), Some("2025-03-31T17:00:00Z"));
+            }
+            other => panic!("expected card, got {:?}", other),
+        }
+        match &items[1] {
+            BulkItemRequest::AppCard { data, .. } => {
+                assert_eq!(
+                    data.fields.as_ref().unwrap()[0].value.as_deref(),
+                    Some("In progress")
+                );
+            }
+            other => panic!("expected app card, got {:?}", other),
+        }
+
+        let json = serde_json::to_value(&items[0]).unwrap();
+        assert_eq!(json["type"], "card");
+        assert_eq!(json["data"]["dueDate"], "2025-03-31T17:00:00Z");
+        assert!(json.get("style").is_none());
+    }
+
     #[test]
     fn test_bulk_create_request_serialization() {
         let items = vec![
@@ -1026,4 +1608,29 @@ mod tests {
         assert_eq!(items[1].id, "item-1"); // 14:30
         assert_eq!(items[2].id, "item-3"); // 15:30
     }
+
+    #[test]
+    fn test_parse_miro_api_error() {
+        let body = r#"{
+            "status": 400,
+            "code": "invalidParameters",
+            "message": "Invalid parameters",
+            "type": "error",
+            "context": {"fields": [{"field": "data.content", "message": "must not be blank", "reason": "blank"}]}
+        }"#;
+        let error = MiroApiError::parse(400, body);
+        assert_eq!(error.code.as_deref(), Some("invalidParameters"));
+        assert_eq!(error.error_type.as_deref(), Some("error"));
+        assert_eq!(error.fields()[0].field, "data.content");
+        assert_eq!(
+            error.to_string(),
+            "Invalid parameters (invalidParameters); data.content: must not be blank"
+        );
+
+        // Proxies and load balancers answer with plain text
+        let error = MiroApiError::parse(502, "Bad Gateway\n");
+        assert_eq!((error.status, error.message.as_str()), (502, "Bad Gateway"));
+        assert!(error.code.is_none() && error.fields().is_empty());
+        assert_eq!(MiroApiError::parse(500, "").message, "Unknown error");
+    }
 }
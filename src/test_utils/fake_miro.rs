@@ -0,0 +1,1357 @@
+use crate::miro::{MiroClient, MiroClientConfig, MiroHttpClient, RetryPolicy};
+use axum::extract::{Path, Query, Request, State};
+use axum::http::StatusCode;
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinHandle;
+
+/// Owner of every board created through the fake
+const OWNER_ID: &str = "3458764500000000001";
+
+/// Roles that can be granted by sharing
+const ASSIGNABLE_ROLES: &[&str] = &["viewer", "commenter", "editor", "coowner"];
+
+/// Item types that can be created through the fake
+const ITEM_TYPES: &[&str] = &["sticky_note", "shape", "text", "frame", "card", "app_card"];
+
+/// Connection states of app cards
+const APP_CARD_STATUSES: &[&str] = &["disconnected", "connected", "disabled"];
+
+/// Maximum items accepted by one bulk-create request
+const MAX_BULK_ITEMS: usize = 20;
+
+/// Miro-style error response (`{"status", "code", "message", "type": "error"}`)
+struct ApiError {
+    status: StatusCode,
+    code: &'static str,
+    message: String,
+    /// `context.fields` entries: (field path, message)
+    fields: Vec<(String, String)>,
+}
+
+impl ApiError {
+    fn new(status: StatusCode, code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            code,
+            message: message.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Validation failure of one request field
+    fn invalid_field(field: impl Into<String>, message: impl Into<String>) -> Self {
+        let (field, message) = (field.into(), message.into());
+        Self {
+            fields: vec![(field.clone(), message.clone())],
+            ..Self::invalid(format!("{}: {}", field, message))
+        }
+    }
+
+    fn board_not_found(board_id: &str) -> Self {
+        Self::new(
+            StatusCode::NOT_FOUND,
+            "notFound",
+            format!("Board {} not found", board_id),
+        )
+    }
+
+    fn member_not_found(member_id: &str) -> Self {
+        Self::new(
+            StatusCode::NOT_FOUND,
+            "notFound",
+            format!("Member {} not found", member_id),
+        )
+    }
+
+    fn tag_not_found(tag_id: &str) -> Self {
+        Self::new(
+            StatusCode::NOT_FOUND,
+            "notFound",
+            format!("Tag {} not found", tag_id),
+        )
+    }
+
+    fn item_not_found(item_id: &str) -> Self {
+        Self::new(
+            StatusCode::NOT_FOUND,
+            "notFound",
+            format!("Item {} not found", item_id),
+        )
+    }
+
+    fn invalid(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, "invalidParameters", message)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let mut body = json!({
+            "status": self.status.as_u16(),
+            "code": self.code,
+            "message": self.message,
+            "type": "error",
+        });
+        if !self.fields.is_empty() {
+            let fields: Vec<Value> = self
+                .fields
+                .iter()
+                .map(|(field, message)| json!({ "field": field, "message": message }))
+                .collect();
+            body["context"] = json!({ "fields": fields });
+        }
+        (self.status, Json(body)).into_response()
+    }
+}
+
+type ApiResult<T> = Result<T, ApiError>;
+
+#[derive(Default)]
+struct FakeBoard {
+    board: Value,
+    /// Items by ID, in creation order of their IDs
+    items: BTreeMap<String, Value>,
+    connectors: BTreeMap<String, Value>,
+    /// Members by ID; like Miro's, they carry a display name but no email
+    members: BTreeMap<String, Value>,
+    /// Member IDs by the email they were invited with
+    member_emails: BTreeMap<String, String>,
+    tags: BTreeMap<String, Value>,
+}
+
+#[derive(Default)]
+struct Store {
+    next_id: u64,
+    boards: BTreeMap<String, FakeBoard>,
+    /// Request counts by "METHOD /path" (path without query)
+    requests: HashMap<String, usize>,
+}
+
+impl Store {
+    fn next_id(&mut self) -> String {
+        self.next_id += 1;
+        // Zero-padded so BTreeMap order matches creation order
+        format!("{:019}", 3_458_764_500_000_000_000 + self.next_id)
+    }
+
+    fn board_mut(&mut self, board_id: &str) -> ApiResult<&mut FakeBoard> {
+        self.boards
+            .get_mut(board_id)
+            .ok_or_else(|| ApiError::board_not_found(board_id))
+    }
+}
+
+type SharedStore = Arc<Mutex<Store>>;
+
+/// In-process fake of the Miro REST API v2
+///
+/// Keeps boards, members, tags, items (with parents) and connectors in memory and serves
+/// the endpoints [`MiroClient`] calls, including type/parent filters,
+/// cursor pagination and bulk-create validation. Errors use Miro's JSON
+/// error body. Any `Bearer` token is accepted.
+///
+/// ```no_run
+/// # use miro_mcp_server::test_utils::FakeMiroServer;
+/// # async fn example() {
+/// let server = FakeMiroServer::start().await;
+/// let board_id = server.seed_board("Retro");
+/// let client = server.client("any-token");
+/// let items = client.list_items(&board_id, None, None).await.unwrap().items;
+/// assert!(items.is_empty());
+/// # }
+/// ```
+pub struct FakeMiroServer {
+    addr: SocketAddr,
+    store: SharedStore,
+    handle: JoinHandle<()>,
+}
+
+impl FakeMiroServer {
+    /// Start the fake on an ephemeral localhost port
+    pub async fn start() -> Self {
+        let store = SharedStore::default();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind fake Miro server");
+        let addr = listener.local_addr().expect("fake Miro server address");
+
+        let app = router(store.clone());
+        let handle = tokio::spawn(async move {
+            axum::serve(listener, app).await.expect("fake Miro server");
+        });
+
+        Self {
+            addr,
+            store,
+            handle,
+        }
+    }
+
+    /// Base URL to use as `MiroClientConfig::base_url`
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Client pointed at this fake, without retries
+    pub fn client(&self, bearer_token: &str) -> MiroClient {
+        self.http_client().for_token(bearer_token)
+    }
+
+    /// Connection pool pointed at this fake, without retries (for `McpHandler`)
+    pub fn http_client(&self) -> MiroHttpClient {
+        let config = MiroClientConfig::default()
+            .with_base_url(self.base_url())
+            .with_retry_policy(RetryPolicy::none());
+        MiroHttpClient::new(config).expect("fake Miro client")
+    }
+
+    /// Create a board directly in the store, returning its ID
+    pub fn seed_board(&self, name: &str) -> String {
+        let mut store = self.store.lock().unwrap();
+        insert_board(&mut store, name.to_string(), None)["id"]
+            .as_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Current items of a board (connectors excluded)
+    pub fn items(&self, board_id: &str) -> Vec<Value> {
+        let store = self.store.lock().unwrap();
+        store
+            .boards
+            .get(board_id)
+            .map(|b| b.items.values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Current connectors of a board
+    pub fn connectors(&self, board_id: &str) -> Vec<Value> {
+        let store = self.store.lock().unwrap();
+        store
+            .boards
+            .get(board_id)
+            .map(|b| b.connectors.values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// How many requests hit `method path` (e.g., `"GET /boards"`)
+    pub fn request_count(&self, method_and_path: &str) -> usize {
+        let store = self.store.lock().unwrap();
+        store.requests.get(method_and_path).copied().unwrap_or(0)
+    }
+}
+
+impl Drop for FakeMiroServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+fn router(store: SharedStore) -> Router {
+    Router::new()
+        .route(
+            "/boards",
+            get(list_boards).post(create_board).put(copy_board),
+        )
+        .route(
+            "/boards/:board_id",
+            get(get_board).patch(update_board).delete(delete_board),
+        )
+        .route(
+            "/boards/:board_id/items",
+            get(list_items).post(bulk_create_items),
+        )
+        .route(
+            "/boards/:board_id/items/:item_id",
+            post(attach_tag).patch(update_item).delete(delete_item),
+        )
+        .route("/boards/:board_id/items/:item_id/tags", get(item_tags))
+        .route("/boards/:board_id/tags", get(list_tags).post(create_tag))
+        .route(
+            "/boards/:board_id/tags/:tag_id",
+            get(get_tag).patch(update_tag).delete(delete_tag),
+        )
+        .route(
+            "/boards/:board_id/members",
+            get(list_members).post(share_board),
+        )
+        .route(
+            "/boards/:board_id/members/:member_id",
+            get(get_member).patch(update_member).delete(remove_member),
+        )
+        .route("/boards/:board_id/sticky_notes", post(create_sticky_note))
+        .route("/boards/:board_id/shapes", post(create_shape))
+        .route("/boards/:board_id/texts", post(create_text))
+        .route("/boards/:board_id/frames", post(create_frame))
+        .route("/boards/:board_id/cards", post(create_card))
+        .route(
+            "/boards/:board_id/cards/:item_id",
+            get(get_card).patch(update_card),
+        )
+        .route("/boards/:board_id/app_cards", post(create_app_card))
+        .route(
+            "/boards/:board_id/app_cards/:item_id",
+            get(get_app_card).patch(update_app_card),
+        )
+        .route("/boards/:board_id/connectors", post(create_connector))
+        .layer(middleware::from_fn_with_state(
+            store.clone(),
+            record_and_authorize,
+        ))
+        .with_state(store)
+}
+
+/// Count requests and reject those without a Bearer token, like Miro does
+async fn record_and_authorize(
+    State(store): State<SharedStore>,
+    request: Request,
+    next: Next,
+) -> Response {
+    {
+        let key = format!("{} {}", request.method(), request.uri().path());
+        let mut store = store.lock().unwrap();
+        *store.requests.entry(key).or_default() += 1;
+    }
+
+    let authorized = request
+        .headers()
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("Bearer ") && v.len() > "Bearer ".len());
+    if !authorized {
+        return ApiError::new(
+            StatusCode::UNAUTHORIZED,
+            "tokenNotProvided",
+            "No authorization token was provided",
+        )
+        .into_response();
+    }
+
+    next.run(request).await
+}
+
+// ==================== Boards ====================
+
+#[derive(Deserialize)]
+struct BoardsQuery {
+    query: Option<String>,
+    team_id: Option<String>,
+    project_id: Option<String>,
+    owner: Option<String>,
+    sort: Option<String>,
+    limit: Option<usize>,
+    offset: Option<usize>,
+}
+
+#[derive(Deserialize)]
+struct OffsetQuery {
+    limit: Option<usize>,
+    offset: Option<usize>,
+}
+
+#[derive(Deserialize)]
+struct CopyQuery {
+    copy_from: String,
+}
+
+#[derive(Deserialize)]
+struct ItemsQuery {
+    #[serde(rename = "type")]
+    item_type: Option<String>,
+    #[serde(rename = "parent.id")]
+    parent_id: Option<String>,
+    limit: Option<usize>,
+    cursor: Option<String>,
+    tag_id: Option<String>,
+    offset: Option<usize>,
+}
+
+#[derive(Deserialize)]
+struct TagQuery {
+    tag_id: Option<String>,
+}
+
+fn now() -> String {
+    chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+fn insert_board(store: &mut Store, name: String, description: Option<String>) -> Value {
+    let id = store.next_id();
+    let now = now();
+    let board = json!({
+        "id": id,
+        "type": "board",
+        "name": name,
+        "description": description.unwrap_or_default(),
+        "createdAt": now,
+        "modifiedAt": now,
+        "owner": { "id": OWNER_ID, "type": "user", "name": "Fake User" },
+        "team": { "id": "3458764500000000002", "type": "team", "name": "Fake Team" },
+        "policy": {
+            "permissionsPolicy": {
+                "collaborationToolsStartAccess": "all_editors",
+                "copyAccess": "anyone",
+                "sharingAccess": "team_members_with_editing_rights"
+            },
+            "sharingPolicy": {
+                "access": "private",
+                "inviteToAccountAndBoardLinkAccess": "no_access",
+                "organizationAccess": "private",
+                "teamAccess": "edit"
+            }
+        },
+        "viewLink": format!("https://miro.com/app/board/{}/", id),
+    });
+    let owner =
+        json!({ "id": OWNER_ID, "name": "Fake User", "role": "owner", "type": "board_member" });
+    store.boards.insert(
+        id,
+        FakeBoard {
+            board: board.clone(),
+            members: BTreeMap::from([(OWNER_ID.to_string(), owner)]),
+            ..Default::default()
+        },
+    );
+    board
+}
+
+async fn list_boards(
+    State(store): State<SharedStore>,
+    Query(query): Query<BoardsQuery>,
+) -> ApiResult<Json<Value>> {
+    let store = store.lock().unwrap();
+    let text = query.query.as_deref().map(str::to_lowercase);
+    let matches = |board: &Value, field: &str, wanted: &Option<String>| {
+        wanted
+            .as_deref()
+            .is_none_or(|wanted| board[field]["id"].as_str() == Some(wanted))
+    };
+    let mut boards: Vec<Value> = store
+        .boards
+        .values()
+        .map(|b| b.board.clone())
+        .filter(|board| {
+            text.as_deref().is_none_or(|text| {
+                ["name", "description"].iter().any(|field| {
+                    board[*field]
+                        .as_str()
+                        .is_some_and(|value| value.to_lowercase().contains(text))
+                })
+            })
+        })
+        .filter(|board| matches(board, "team", &query.team_id))
+        .filter(|board| matches(board, "project", &query.project_id))
+        .filter(|board| matches(board, "owner", &query.owner))
+        .collect();
+
+    // Newest first; ties keep the most recently created board first
+    let mut newest_first = |field: &str| {
+        boards.reverse();
+        boards.sort_by(|a, b| b[field].as_str().cmp(&a[field].as_str()));
+    };
+    match query.sort.as_deref() {
+        None | Some("default") => {}
+        Some("last_modified") | Some("last_opened") => newest_first("modifiedAt"),
+        Some("last_created") => newest_first("createdAt"),
+        Some("alphabetically") => {
+            boards.sort_by_key(|board| board["name"].as_str().unwrap_or_default().to_lowercase())
+        }
+        Some(other) => return Err(ApiError::invalid(format!("Invalid sort: {}", other))),
+    }
+
+    // Miro pages boards by offset
+    let offset = query.offset.map(|offset| offset.to_string());
+    paginate(boards, query.limit, offset.as_deref(), 1..=50, 20)
+}
+
+async fn create_board(
+    State(store): State<SharedStore>,
+    Json(body): Json<Value>,
+) -> ApiResult<(StatusCode, Json<Value>)> {
+    let name = body
+        .get("name")
+        .and_then(Value::as_str)
+        .filter(|n| !n.is_empty())
+        .ok_or_else(|| ApiError::invalid("Board name is required"))?;
+    let description = body
+        .get("description")
+        .and_then(Value::as_str)
+        .map(String::from);
+
+    let mut store = store.lock().unwrap();
+    let board = insert_board(&mut store, name.to_string(), description);
+    Ok((StatusCode::CREATED, Json(board)))
+}
+
+async fn get_board(
+    State(store): State<SharedStore>,
+    Path(board_id): Path<String>,
+) -> ApiResult<Json<Value>> {
+    let mut store = store.lock().unwrap();
+    Ok(Json(store.board_mut(&board_id)?.board.clone()))
+}
+
+async fn update_board(
+    State(store): State<SharedStore>,
+    Path(board_id): Path<String>,
+    Json(body): Json<Value>,
+) -> ApiResult<Json<Value>> {
+    if body.get("name").and_then(Value::as_str) == Some("") {
+        return Err(ApiError::invalid_field("name", "must not be empty"));
+    }
+
+    let mut store = store.lock().unwrap();
+    let board = &mut store.board_mut(&board_id)?.board;
+    for field in ["name", "description"] {
+        if let Some(value) = body.get(field).filter(|v| v.is_string()) {
+            board[field] = value.clone();
+        }
+    }
+    for policy in ["permissionsPolicy", "sharingPolicy"] {
+        if let Some(changes) = body["policy"][policy].as_object() {
+            for (field, value) in changes {
+                board["policy"][policy][field] = value.clone();
+            }
+        }
+    }
+    board["modifiedAt"] = json!(now());
+    Ok(Json(board.clone()))
+}
+
+/// Copy a board with fresh IDs for its items, keeping parents and connectors
+async fn copy_board(
+    State(store): State<SharedStore>,
+    Query(query): Query<CopyQuery>,
+    Json(body): Json<Value>,
+) -> ApiResult<(StatusCode, Json<Value>)> {
+    let mut store = store.lock().unwrap();
+    let source = store.board_mut(&query.copy_from)?;
+    let field = |field: &str| {
+        body.get(field)
+            .or_else(|| source.board.get(field))
+            .and_then(Value::as_str)
+            .map(String::from)
+    };
+    let (name, description) = (field("name").unwrap_or_default(), field("description"));
+    let items: Vec<Value> = source.items.values().cloned().collect();
+    let connectors: Vec<Value> = source.connectors.values().cloned().collect();
+    let tags: Vec<Value> = source.tags.values().cloned().collect();
+
+    let mut board = insert_board(&mut store, name, description);
+    if let Some(team_id) = body.get("teamId").and_then(Value::as_str) {
+        board["team"] = json!({ "id": team_id, "type": "team" });
+    }
+    let new_ids: HashMap<String, String> = items
+        .iter()
+        .chain(&connectors)
+        .chain(&tags)
+        .map(|value| (value["id"].as_str().unwrap().to_string(), store.next_id()))
+        .collect();
+    let copy = store.board_mut(board["id"].as_str().unwrap())?;
+    copy.board = board.clone();
+    for mut item in items {
+        let id = new_ids[item["id"].as_str().unwrap()].clone();
+        item["id"] = json!(id);
+        if let Some(parent_id) = item["parent"]["id"].as_str() {
+            item["parent"]["id"] = json!(new_ids[parent_id]);
+        }
+        if let Some(tag_ids) = item.get_mut("tagIds").and_then(Value::as_array_mut) {
+            for tag_id in tag_ids {
+                *tag_id = json!(new_ids[tag_id.as_str().unwrap()]);
+            }
+        }
+        copy.items.insert(id, item);
+    }
+    for mut tag in tags {
+        let id = new_ids[tag["id"].as_str().unwrap()].clone();
+        tag["id"] = json!(id);
+        copy.tags.insert(id, tag);
+    }
+    for mut connector in connectors {
+        let id = new_ids[connector["id"].as_str().unwrap()].clone();
+        connector["id"] = json!(id);
+        for end in ["startItem", "endItem"] {
+            connector[end] = json!(new_ids[connector[end].as_str().unwrap()]);
+        }
+        copy.connectors.insert(id, connector);
+    }
+
+    Ok((StatusCode::CREATED, Json(board)))
+}
+
+async fn delete_board(
+    State(store): State<SharedStore>,
+    Path(board_id): Path<String>,
+) -> ApiResult<StatusCode> {
+    let mut store = store.lock().unwrap();
+    store
+        .boards
+        .remove(&board_id)
+        .ok_or_else(|| ApiError::board_not_found(&board_id))?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ==================== Members ====================
+
+fn assignable_role(body: &Value) -> ApiResult<&str> {
+    body.get("role")
+        .and_then(Value::as_str)
+        .filter(|role| ASSIGNABLE_ROLES.contains(role))
+        .ok_or_else(|| {
+            ApiError::invalid_field("role", "must be viewer, commenter, editor or coowner")
+        })
+}
+
+fn member_mut<'a>(board: &'a mut FakeBoard, member_id: &str) -> ApiResult<&'a mut Value> {
+    board
+        .members
+        .get_mut(member_id)
+        .ok_or_else(|| ApiError::member_not_found(member_id))
+}
+
+async fn list_members(
+    State(store): State<SharedStore>,
+    Path(board_id): Path<String>,
+    Query(query): Query<OffsetQuery>,
+) -> ApiResult<Json<Value>> {
+    let mut store = store.lock().unwrap();
+    let members: Vec<Value> = store
+        .board_mut(&board_id)?
+        .members
+        .values()
+        .cloned()
+        .collect();
+    // Miro pages members by offset
+    let offset = query.offset.map(|offset| offset.to_string());
+    paginate(members, query.limit, offset.as_deref(), 1..=50, 20)
+}
+
+async fn share_board(
+    State(store): State<SharedStore>,
+    Path(board_id): Path<String>,
+    Json(body): Json<Value>,
+) -> ApiResult<Json<Value>> {
+    let role = assignable_role(&body)?;
+    let emails: Vec<&str> = body
+        .get("emails")
+        .and_then(Value::as_array)
+        .filter(|emails| !emails.is_empty())
+        .ok_or_else(|| ApiError::invalid_field("emails", "is required"))?
+        .iter()
+        .filter_map(Value::as_str)
+        .collect();
+
+    let mut store = store.lock().unwrap();
+    store.board_mut(&board_id)?;
+    let (mut successful, mut failed) = (Vec::new(), Vec::new());
+    for email in emails {
+        let Some((local, _)) = email.split_once('@') else {
+            failed.push(json!({ "email": email, "reason": "Invalid email address" }));
+            continue;
+        };
+        let key = email.to_lowercase();
+        let existing = store.boards[&board_id].member_emails.get(&key).cloned();
+        let id = existing.unwrap_or_else(|| store.next_id());
+        let board = store.board_mut(&board_id)?;
+        match board.members.get_mut(&id) {
+            // Sharing again with a member changes their role
+            Some(member) => member["role"] = json!(role),
+            None => {
+                // Named like a Miro profile (ada@… is "Ada"), not after the email
+                let mut chars = local.chars();
+                let name: String = chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default();
+                let member =
+                    json!({ "id": id, "name": name, "role": role, "type": "board_member" });
+                board.members.insert(id.clone(), member);
+                board.member_emails.insert(key, id.clone());
+            }
+        }
+        successful.push(json!(id.parse::<u64>().unwrap()));
+    }
+
+    Ok(Json(json!({ "successful": successful, "failed": failed })))
+}
+
+async fn get_member(
+    State(store): State<SharedStore>,
+    Path((board_id, member_id)): Path<(String, String)>,
+) -> ApiResult<Json<Value>> {
+    let mut store = store.lock().unwrap();
+    let board = store.board_mut(&board_id)?;
+    Ok(Json(member_mut(board, &member_id)?.clone()))
+}
+
+async fn update_member(
+    State(store): State<SharedStore>,
+    Path((board_id, member_id)): Path<(String, String)>,
+    Json(body): Json<Value>,
+) -> ApiResult<Json<Value>> {
+    let role = assignable_role(&body)?;
+    let mut store = store.lock().unwrap();
+    let member = member_mut(store.board_mut(&board_id)?, &member_id)?;
+    if member["role"] == "owner" {
+        return Err(ApiError::invalid(
+            "The board owner's role cannot be changed",
+        ));
+    }
+    member["role"] = json!(role);
+    Ok(Json(member.clone()))
+}
+
+async fn remove_member(
+    State(store): State<SharedStore>,
+    Path((board_id, member_id)): Path<(String, String)>,
+) -> ApiResult<StatusCode> {
+    let mut store = store.lock().unwrap();
+    let board = store.board_mut(&board_id)?;
+    if member_mut(board, &member_id)?["role"] == "owner" {
+        return Err(ApiError::invalid("The board owner cannot be removed"));
+    }
+    board.members.remove(&member_id);
+    board.member_emails.retain(|_, id| *id != member_id);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ==================== Items ====================
+
+/// Page through `values` with an offset cursor, enforcing Miro's limit range
+fn paginate(
+    values: Vec<Value>,
+    limit: Option<usize>,
+    cursor: Option<&str>,
+    allowed: std::ops::RangeInclusive<usize>,
+    default_limit: usize,
+) -> ApiResult<Json<Value>> {
+    let limit = limit.unwrap_or(default_limit);
+    if !allowed.contains(&limit) {
+        return Err(ApiError::invalid(format!(
+            "limit must be between {} and {}",
+            allowed.start(),
+            allowed.end()
+        )));
+    }
+
+    let offset = match cursor {
+        Some(cursor) => cursor
+            .parse::<usize>()
+            .ok()
+            .filter(|offset| *offset <= values.len())
+            .ok_or_else(|| ApiError::invalid(format!("Invalid cursor: {}", cursor)))?,
+        None => 0,
+    };
+
+    let end = (offset + limit).min(values.len());
+    let mut body = json!({
+        "data": values[offset..end],
+        "total": values.len(),
+        "size": end - offset,
+        "limit": limit,
+        "offset": offset,
+    });
+    if end < values.len() {
+        body["cursor"] = json!(end.to_string());
+    }
+    Ok(Json(body))
+}
+
+async fn list_items(
+    State(store): State<SharedStore>,
+    Path(board_id): Path<String>,
+    Query(query): Query<ItemsQuery>,
+) -> ApiResult<Json<Value>> {
+    let mut store = store.lock().unwrap();
+    let board = store.board_mut(&board_id)?;
+
+    let types: Option<Vec<&str>> = query.item_type.as_deref().map(|t| t.split(',').collect());
+    let items: Vec<Value> = board
+        .items
+        .values()
+        .filter(|item| {
+            types
+                .as_ref()
+                .is_none_or(|types| types.contains(&item["type"].as_str().unwrap_or_default()))
+        })
+        .filter(|item| {
+            query
+                .parent_id
+                .as_deref()
+                .is_none_or(|parent| item["parent"]["id"] == parent)
+        })
+        .filter(|item| {
+            query
+                .tag_id
+                .as_deref()
+                .is_none_or(|tag_id| has_tag(item, tag_id))
+        })
+        .cloned()
+        .collect();
+
+    // Items by tag are paged by offset, like Miro does
+    if query.tag_id.is_some() {
+        let offset = query.offset.map(|offset| offset.to_string());
+        return paginate(items, query.limit, offset.as_deref(), 1..=50, 20);
+    }
+    paginate(items, query.limit, query.cursor.as_deref(), 10..=50, 10)
+}
+
+/// Validate an item body and store it as `item_type`
+fn insert_item(
+    board: &mut FakeBoard,
+    id: String,
+    item_type: &str,
+    body: &Value,
+) -> ApiResult<Value> {
+    if !body.get("data").is_some_and(Value::is_object) {
+        return Err(ApiError::invalid(format!(
+            "{}: data is required",
+            item_type
+        )));
+    }
+    if !body.get("position").is_some_and(Value::is_object) {
+        return Err(ApiError::invalid(format!(
+            "{}: position is required",
+            item_type
+        )));
+    }
+    if let Some(parent_id) = body["parent"]["id"].as_str() {
+        let is_frame = board
+            .items
+            .get(parent_id)
+            .is_some_and(|parent| parent["type"] == "frame");
+        if !is_frame {
+            return Err(ApiError::invalid(format!(
+                "Parent {} is not a frame on this board",
+                parent_id
+            )));
+        }
+    }
+    validate_card_data(item_type, &body["data"])?;
+
+    let now = now();
+    let mut item = Map::new();
+    item.insert("id".to_string(), json!(id));
+    item.insert("type".to_string(), json!(item_type));
+    for field in ["data", "style", "position", "geometry", "parent"] {
+        if let Some(value) = body.get(field).filter(|v| !v.is_null()) {
+            item.insert(field.to_string(), value.clone());
+        }
+    }
+    item.insert("createdAt".to_string(), json!(now));
+    item.insert("modifiedAt".to_string(), json!(now));
+
+    let item = Value::Object(item);
+    board.items.insert(id, item.clone());
+    Ok(item)
+}
+
+/// Check the card fields Miro validates: due dates and app card statuses
+fn validate_card_data(item_type: &str, data: &Value) -> ApiResult<()> {
+    match item_type {
+        "card" => {
+            if let Some(due_date) = data.get("dueDate").and_then(Value::as_str) {
+                if chrono::DateTime::parse_from_rfc3339(due_date).is_err() {
+                    return Err(ApiError::invalid_field(
+                        "data.dueDate",
+                        "must be an ISO 8601 date-time",
+                    ));
+                }
+            }
+        }
+        "app_card" => {
+            if let Some(status) = data.get("status").and_then(Value::as_str) {
+                if !APP_CARD_STATUSES.contains(&status) {
+                    return Err(ApiError::invalid_field(
+                        "data.status",
+                        format!("must be one of {}", APP_CARD_STATUSES.join(", ")),
+                    ));
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+async fn create_item(
+    store: SharedStore,
+    board_id: String,
+    item_type: &str,
+    body: Value,
+) -> ApiResult<(StatusCode, Json<Value>)> {
+    let mut store = store.lock().unwrap();
+    let id = store.next_id();
+    let board = store.board_mut(&board_id)?;
+    let item = insert_item(board, id, item_type, &body)?;
+    Ok((StatusCode::CREATED, Json(item)))
+}
+
+async fn create_sticky_note(
+    State(store): State<SharedStore>,
+    Path(board_id): Path<String>,
+    Json(body): Json<Value>,
+) -> ApiResult<(StatusCode, Json<Value>)> {
+    create_item(store, board_id, "sticky_note", body).await
+}
+
+async fn create_shape(
+    State(store): State<SharedStore>,
+    Path(board_id): Path<String>,
+    Json(body): Json<Value>,
+) -> ApiResult<(StatusCode, Json<Value>)> {
+    create_item(store, board_id, "shape", body).await
+}
+
+async fn create_text(
+    State(store): State<SharedStore>,
+    Path(board_id): Path<String>,
+    Json(body): Json<Value>,
+) -> ApiResult<(StatusCode, Json<Value>)> {
+    create_item(store, board_id, "text", body).await
+}
+
+async fn create_frame(
+    State(store): State<SharedStore>,
+    Path(board_id): Path<String>,
+    Json(body): Json<Value>,
+) -> ApiResult<(StatusCode, Json<Value>)> {
+    create_item(store, board_id, "frame", body).await
+}
+
+async fn create_card(
+    State(store): State<SharedStore>,
+    Path(board_id): Path<String>,
+    Json(body): Json<Value>,
+) -> ApiResult<(StatusCode, Json<Value>)> {
+    create_item(store, board_id, "card", body).await
+}
+
+async fn create_app_card(
+    State(store): State<SharedStore>,
+    Path(board_id): Path<String>,
+    Json(body): Json<Value>,
+) -> ApiResult<(StatusCode, Json<Value>)> {
+    create_item(store, board_id, "app_card", body).await
+}
+
+/// Item `item_id` if it is of `item_type`; Miro answers 404 otherwise
+fn typed_item<'a>(board: &'a FakeBoard, item_id: &str, item_type: &str) -> ApiResult<&'a Value> {
+    board
+        .items
+        .get(item_id)
+        .filter(|item| item["type"] == item_type)
+        .ok_or_else(|| ApiError::item_not_found(item_id))
+}
+
+async fn get_card(
+    State(store): State<SharedStore>,
+    Path((board_id, item_id)): Path<(String, String)>,
+) -> ApiResult<Json<Value>> {
+    let mut store = store.lock().unwrap();
+    let board = store.board_mut(&board_id)?;
+    Ok(Json(typed_item(board, &item_id, "card")?.clone()))
+}
+
+async fn update_card(
+    State(store): State<SharedStore>,
+    Path((board_id, item_id)): Path<(String, String)>,
+    Json(body): Json<Value>,
+) -> ApiResult<Json<Value>> {
+    let mut store = store.lock().unwrap();
+    let board = store.board_mut(&board_id)?;
+    typed_item(board, &item_id, "card")?;
+    validate_card_data("card", &body["data"])?;
+    patch_item(board, &item_id, &body).map(Json)
+}
+
+async fn get_app_card(
+    State(store): State<SharedStore>,
+    Path((board_id, item_id)): Path<(String, String)>,
+) -> ApiResult<Json<Value>> {
+    let mut store = store.lock().unwrap();
+    let board = store.board_mut(&board_id)?;
+    Ok(Json(typed_item(board, &item_id, "app_card")?.clone()))
+}
+
+async fn update_app_card(
+    State(store): State<SharedStore>,
+    Path((board_id, item_id)): Path<(String, String)>,
+    Json(body): Json<Value>,
+) -> ApiResult<Json<Value>> {
+    let mut store = store.lock().unwrap();
+    let board = store.board_mut(&board_id)?;
+    typed_item(board, &item_id, "app_card")?;
+    validate_card_data("app_card", &body["data"])?;
+    patch_item(board, &item_id, &body).map(Json)
+}
+
+async fn bulk_create_items(
+    State(store): State<SharedStore>,
+    Path(board_id): Path<String>,
+    Json(body): Json<Value>,
+) -> ApiResult<(StatusCode, Json<Value>)> {
+    let items = body
+        .get("items")
+        .and_then(Value::as_array)
+        .ok_or_else(|| ApiError::invalid("items array is required"))?;
+    if items.is_empty() || items.len() > MAX_BULK_ITEMS {
+        return Err(ApiError::invalid(format!(
+            "Bulk create accepts 1 to {} items, got {}",
+            MAX_BULK_ITEMS,
+            items.len()
+        )));
+    }
+
+    let mut store = store.lock().unwrap();
+    let ids: Vec<String> = items.iter().map(|_| store.next_id()).collect();
+    let board = store.board_mut(&board_id)?;
+
+    // All-or-nothing: validate every item against a scratch copy first
+    let mut scratch = FakeBoard {
+        items: board.items.clone(),
+        ..Default::default()
+    };
+    let mut created = Vec::with_capacity(items.len());
+    for (index, (item, id)) in items.iter().zip(ids).enumerate() {
+        let item_type = item["type"]
+            .as_str()
+            .filter(|t| ITEM_TYPES.contains(t))
+            .ok_or_else(|| ApiError::invalid(format!("items[{}]: unsupported item type", index)))?;
+        let stored = insert_item(&mut scratch, id, item_type, item)
+            .map_err(|e| ApiError::invalid(format!("items[{}]: {}", index, e.message)))?;
+        created.push(stored);
+    }
+
+    board.items = scratch.items;
+    Ok((StatusCode::CREATED, Json(json!({ "data": created }))))
+}
+
+async fn update_item(
+    State(store): State<SharedStore>,
+    Path((board_id, item_id)): Path<(String, String)>,
+    Json(body): Json<Value>,
+) -> ApiResult<Json<Value>> {
+    let mut store = store.lock().unwrap();
+    let board = store.board_mut(&board_id)?;
+    patch_item(board, &item_id, &body).map(Json)
+}
+
+/// Apply a partial update to an item, merging object fields
+fn patch_item(board: &mut FakeBoard, item_id: &str, body: &Value) -> ApiResult<Value> {
+    if let Some(parent_id) = body["parent"]["id"].as_str() {
+        let is_frame = parent_id != item_id
+            && board
+                .items
+                .get(parent_id)
+                .is_some_and(|parent| parent["type"] == "frame");
+        if !is_frame {
+            return Err(ApiError::invalid(format!(
+                "Parent {} is not a frame on this board",
+                parent_id
+            )));
+        }
+    }
+
+    let item = board
+        .items
+        .get_mut(item_id)
+        .ok_or_else(|| ApiError::item_not_found(item_id))?;
+
+    for field in ["data", "style", "position", "geometry"] {
+        match (item.get_mut(field), body.get(field)) {
+            (_, None) | (_, Some(Value::Null)) => {}
+            // Partial update: merge object fields
+            (Some(Value::Object(current)), Some(Value::Object(update))) => {
+                for (key, value) in update {
+                    current.insert(key.clone(), value.clone());
+                }
+            }
+            (_, Some(value)) => {
+                item[field] = value.clone();
+            }
+        }
+    }
+    if let Some(parent) = body.get("parent").filter(|v| !v.is_null()) {
+        item["parent"] = parent.clone();
+    }
+    item["modifiedAt"] = json!(now());
+
+    Ok(item.clone())
+}
+
+async fn delete_item(
+    State(store): State<SharedStore>,
+    Path((board_id, item_id)): Path<(String, String)>,
+    Query(query): Query<TagQuery>,
+) -> ApiResult<StatusCode> {
+    let mut store = store.lock().unwrap();
+    let board = store.board_mut(&board_id)?;
+
+    // `?tag_id=` detaches a tag instead of deleting the item
+    if let Some(tag_id) = query.tag_id {
+        let item = board
+            .items
+            .get_mut(&item_id)
+            .ok_or_else(|| ApiError::item_not_found(&item_id))?;
+        if let Some(tag_ids) = item.get_mut("tagIds").and_then(Value::as_array_mut) {
+            tag_ids.retain(|id| *id != tag_id.as_str());
+        }
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
+    board
+        .items
+        .remove(&item_id)
+        .ok_or_else(|| ApiError::item_not_found(&item_id))?;
+
+    // Children leave the deleted frame; attached connectors go away
+    for item in board.items.values_mut() {
+        if item["parent"]["id"] == item_id.as_str() {
+            if let Some(item) = item.as_object_mut() {
+                item.remove("parent");
+            }
+        }
+    }
+    board.connectors.retain(|_, connector| {
+        connector["startItem"] != item_id.as_str() && connector["endItem"] != item_id.as_str()
+    });
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ==================== Tags ====================
+
+/// Item types Miro lets carry tags
+const TAGGABLE_TYPES: &[&str] = &["sticky_note", "card"];
+
+/// Most tags Miro allows on one item
+const MAX_ITEM_TAGS: usize = 8;
+
+fn has_tag(item: &Value, tag_id: &str) -> bool {
+    item["tagIds"]
+        .as_array()
+        .is_some_and(|ids| ids.iter().any(|id| id == tag_id))
+}
+
+fn tag_mut<'a>(board: &'a mut FakeBoard, tag_id: &str) -> ApiResult<&'a mut Value> {
+    board
+        .tags
+        .get_mut(tag_id)
+        .ok_or_else(|| ApiError::tag_not_found(tag_id))
+}
+
+/// Check a tag title is given and not taken by another tag of the board
+fn validate_tag_title(board: &FakeBoard, title: &str, tag_id: Option<&str>) -> ApiResult<()> {
+    if title.is_empty() || title.chars().count() > 120 {
+        return Err(ApiError::invalid_field(
+            "title",
+            "must be 1 to 120 characters",
+        ));
+    }
+    let taken = board
+        .tags
+        .values()
+        .any(|tag| tag["title"] == title && Some(tag["id"].as_str().unwrap_or_default()) != tag_id);
+    if taken {
+        return Err(ApiError::invalid_field(
+            "title",
+            format!("a tag titled {} already exists", title),
+        ));
+    }
+    Ok(())
+}
+
+async fn list_tags(
+    State(store): State<SharedStore>,
+    Path(board_id): Path<String>,
+    Query(query): Query<OffsetQuery>,
+) -> ApiResult<Json<Value>> {
+    let mut store = store.lock().unwrap();
+    let tags: Vec<Value> = store.board_mut(&board_id)?.tags.values().cloned().collect();
+    let offset = query.offset.map(|offset| offset.to_string());
+    paginate(tags, query.limit, offset.as_deref(), 1..=50, 20)
+}
+
+async fn create_tag(
+    State(store): State<SharedStore>,
+    Path(board_id): Path<String>,
+    Json(body): Json<Value>,
+) -> ApiResult<(StatusCode, Json<Value>)> {
+    let mut store = store.lock().unwrap();
+    let id = store.next_id();
+    let board = store.board_mut(&board_id)?;
+    let title = body["title"].as_str().unwrap_or_default();
+    validate_tag_title(board, title, None)?;
+
+    let tag = json!({
+        "id": id,
+        "type": "tag",
+        "title": title,
+        "fillColor": body["fillColor"].as_str().unwrap_or("red"),
+    });
+    board.tags.insert(id, tag.clone());
+    Ok((StatusCode::CREATED, Json(tag)))
+}
+
+async fn get_tag(
+    State(store): State<SharedStore>,
+    Path((board_id, tag_id)): Path<(String, String)>,
+) -> ApiResult<Json<Value>> {
+    let mut store = store.lock().unwrap();
+    let board = store.board_mut(&board_id)?;
+    Ok(Json(tag_mut(board, &tag_id)?.clone()))
+}
+
+async fn update_tag(
+    State(store): State<SharedStore>,
+    Path((board_id, tag_id)): Path<(String, String)>,
+    Json(body): Json<Value>,
+) -> ApiResult<Json<Value>> {
+    let mut store = store.lock().unwrap();
+    let board = store.board_mut(&board_id)?;
+    tag_mut(board, &tag_id)?;
+    if let Some(title) = body.get("title").and_then(Value::as_str) {
+        validate_tag_title(board, title, Some(&tag_id))?;
+    }
+
+    let tag = tag_mut(board, &tag_id)?;
+    for field in ["title", "fillColor"] {
+        if let Some(value) = body.get(field).filter(|v| !v.is_null()) {
+            tag[field] = value.clone();
+        }
+    }
+    Ok(Json(tag.clone()))
+}
+
+async fn delete_tag(
+    State(store): State<SharedStore>,
+    Path((board_id, tag_id)): Path<(String, String)>,
+) -> ApiResult<StatusCode> {
+    let mut store = store.lock().unwrap();
+    let board = store.board_mut(&board_id)?;
+    board
+        .tags
+        .remove(&tag_id)
+        .ok_or_else(|| ApiError::tag_not_found(&tag_id))?;
+    for item in board.items.values_mut() {
+        if let Some(tag_ids) = item.get_mut("tagIds").and_then(Value::as_array_mut) {
+            tag_ids.retain(|id| *id != tag_id.as_str());
+        }
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn attach_tag(
+    State(store): State<SharedStore>,
+    Path((board_id, item_id)): Path<(String, String)>,
+    Query(query): Query<TagQuery>,
+) -> ApiResult<StatusCode> {
+    let tag_id = query
+        .tag_id
+        .ok_or_else(|| ApiError::invalid("tag_id is required"))?;
+    let mut store = store.lock().unwrap();
+    let board = store.board_mut(&board_id)?;
+    tag_mut(board, &tag_id)?;
+    let item = board
+        .items
+        .get_mut(&item_id)
+        .ok_or_else(|| ApiError::item_not_found(&item_id))?;
+
+    let item_type = item["type"].as_str().unwrap_or_default();
+    if !TAGGABLE_TYPES.contains(&item_type) {
+        return Err(ApiError::invalid(format!(
+            "Tags can only be attached to sticky notes and cards, not {}",
+            item_type
+        )));
+    }
+    if !has_tag(item, &tag_id) {
+        let tag_ids = item
+            .as_object_mut()
+            .expect("items are objects")
+            .entry("tagIds")
+            .or_insert_with(|| json!([]))
+            .as_array_mut()
+            .expect("tagIds is an array");
+        if tag_ids.len() >= MAX_ITEM_TAGS {
+            return Err(ApiError::invalid(format!(
+                "An item can carry at most {} tags",
+                MAX_ITEM_TAGS
+            )));
+        }
+        tag_ids.push(json!(tag_id));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn item_tags(
+    State(store): State<SharedStore>,
+    Path((board_id, item_id)): Path<(String, String)>,
+) -> ApiResult<Json<Value>> {
+    let mut store = store.lock().unwrap();
+    let board = store.board_mut(&board_id)?;
+    let item = board
+        .items
+        .get(&item_id)
+        .ok_or_else(|| ApiError::item_not_found(&item_id))?;
+    let tags: Vec<Value> = board
+        .tags
+        .values()
+        .filter(|tag| has_tag(item, tag["id"].as_str().unwrap_or_default()))
+        .cloned()
+        .collect();
+    Ok(Json(json!({ "tags": tags })))
+}
+
+// ==================== Connectors ====================
+
+async fn create_connector(
+    State(store): State<SharedStore>,
+    Path(board_id): Path<String>,
+    Json(body): Json<Value>,
+) -> ApiResult<(StatusCode, Json<Value>)> {
+    let mut store = store.lock().unwrap();
+    let id = store.next_id();
+    let board = store.board_mut(&board_id)?;
+
+    let endpoint = |field: &str| -> ApiResult<String> {
+        let item_id = body
+            .get(field)
+            .and_then(Value::as_str)
+            .ok_or_else(|| ApiError::invalid_field(field, "is required"))?;
+        if !board.items.contains_key(item_id) {
+            return Err(ApiError::invalid_field(
+                field,
+                format!("{} does not exist on this board", item_id),
+            ));
+        }
+        Ok(item_id.to_string())
+    };
+    let start_item = endpoint("startItem")?;
+    let end_item = endpoint("endItem")?;
+
+    let mut connector = json!({
+        "id": id,
+        "type": "connector",
+        "startItem": start_item,
+        "endItem": end_item,
+        "createdAt": now(),
+    });
+    for field in ["style", "captions"] {
+        if let Some(value) = body.get(field).filter(|v| !v.is_null()) {
+            connector[field] = value.clone();
+        }
+    }
+
+    board.connectors.insert(id, connector.clone());
+    Ok((StatusCode::CREATED, Json(connector)))
+}
@@ -0,0 +1,5 @@
+//! Test helpers, enabled with the `test-utils` feature
+
+pub mod fake_miro;
+
+pub use fake_miro::FakeMiroServer;
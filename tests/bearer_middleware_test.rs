@@ -1,10 +1,13 @@
 use axum::http::{Request, StatusCode};
 use axum::Router;
 use miro_mcp_server::http_server::create_app_adr002;
-use miro_mcp_server::{Config, TokenValidator};
+use miro_mcp_server::Config;
+use serde_json::json;
 use std::sync::Arc;
 use tower::ServiceExt;
 
+mod common;
+
 fn get_test_config() -> Config {
     Config {
         client_id: "test_client_id".to_string(),
@@ -13,17 +16,15 @@ fn get_test_config() -> Config {
         encryption_key: [0u8; 32],
         port: 3010,
         base_url: Some("https://test.example.com".to_string()),
+        token_validation: Default::default(),
+        miro_http: Default::default(),
+        mcp: Default::default(),
     }
 }
 
 fn create_test_app() -> Router {
     let config = Arc::new(get_test_config());
-    let token_validator = Arc::new(TokenValidator::new(
-        config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| "https://test.example.com".to_string()),
-    ));
+    let token_validator = Arc::new(common::token_validator());
 
     create_app_adr002(token_validator, config)
 }
@@ -67,3 +68,119 @@ async fn test_oauth_metadata_no_auth_required() {
 // Tests verify:
 // 1. Public routes work without auth (health, metadata)
 // 2. Protected routes require Bearer token with valid JWT
+
+async fn post_mcp_with_auth(authorization: Option<&str>) -> axum::response::Response {
+    let mut builder = Request::builder().method("POST").uri("/mcp");
+    if let Some(value) = authorization {
+        builder = builder.header("authorization", value);
+    }
+
+    create_test_app()
+        .oneshot(
+            builder
+                .body(axum::body::Body::from(
+                    r#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#,
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+}
+
+fn www_authenticate(response: &axum::response::Response) -> String {
+    response
+        .headers()
+        .get("www-authenticate")
+        .expect("missing WWW-Authenticate header")
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+fn signed_jwt(aud: &str, exp: u64) -> String {
+    common::sign(&json!({ "sub": "user123", "aud": aud, "exp": exp }))
+}
+
+fn in_one_hour() -> u64 {
+    common::now() + 3600
+}
+
+#[tokio::test]
+async fn test_missing_token_challenge_has_no_error_code() {
+    let response = post_mcp_with_auth(None).await;
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let challenge = www_authenticate(&response);
+    assert!(challenge.contains("resource_metadata="));
+    assert!(!challenge.contains("error="));
+}
+
+#[tokio::test]
+async fn test_wrong_scheme_is_invalid_token() {
+    let response = post_mcp_with_auth(Some("Basic dXNlcjpwYXNz")).await;
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert!(www_authenticate(&response).contains("error=\"invalid_token\""));
+}
+
+#[tokio::test]
+async fn test_expired_token_challenge() {
+    let token = signed_jwt("https://test.example.com", 1000);
+    let response = post_mcp_with_auth(Some(&format!("Bearer {}", token))).await;
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let challenge = www_authenticate(&response);
+    assert!(challenge.contains("error=\"invalid_token\""));
+    assert!(challenge.contains("error_description=\"The access token expired\""));
+}
+
+#[tokio::test]
+async fn test_wrong_audience_challenge() {
+    let token = signed_jwt("https://other.example.com", in_one_hour());
+    let response = post_mcp_with_auth(Some(&format!("Bearer {}", token))).await;
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let challenge = www_authenticate(&response);
+    assert!(challenge.contains("error=\"invalid_token\""));
+    assert!(challenge.contains("audience"));
+}
+
+#[tokio::test]
+async fn test_unsigned_token_rejected() {
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+
+    let header = URL_SAFE_NO_PAD.encode(r#"{"alg":"EdDSA","kid":"test-ed25519"}"#);
+    let claims = URL_SAFE_NO_PAD.encode(
+        json!({ "sub": "admin", "aud": "https://test.example.com", "exp": in_one_hour() })
+            .to_string(),
+    );
+    let token = format!("{}.{}.fake_signature", header, claims);
+    let response = post_mcp_with_auth(Some(&format!("Bearer {}", token))).await;
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert!(www_authenticate(&response).contains("error=\"invalid_token\""));
+}
+
+#[tokio::test]
+async fn test_valid_token_reaches_handler() {
+    let token = signed_jwt("https://test.example.com", in_one_hour());
+    let response = post_mcp_with_auth(Some(&format!("Bearer {}", token))).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get("www-authenticate").is_none());
+}
+
+#[tokio::test]
+async fn test_unknown_route_is_not_found_not_unauthorized() {
+    let response = create_test_app()
+        .oneshot(
+            Request::builder()
+                .uri("/does-not-exist")
+                .body(axum::body::Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
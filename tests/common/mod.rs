@@ -0,0 +1,80 @@
+//! Shared helpers for integration tests: locally generated signing keys
+//! and JWTs that `TokenValidator` accepts.
+
+#![allow(dead_code)]
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use miro_mcp_server::auth::{JwksProvider, JwksSource};
+use miro_mcp_server::TokenValidator;
+use ring::rand::SystemRandom;
+use ring::signature::{Ed25519KeyPair, KeyPair};
+use serde_json::{json, Value};
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const RESOURCE_URL: &str = "https://test.example.com";
+pub const TEST_KID: &str = "test-ed25519";
+
+/// Ed25519 key pair generated once per test binary
+pub struct SigningKey {
+    encoding_key: EncodingKey,
+    public_x: String,
+}
+
+pub fn signing_key() -> &'static SigningKey {
+    static KEY: OnceLock<SigningKey> = OnceLock::new();
+    KEY.get_or_init(|| {
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        let pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+        SigningKey {
+            encoding_key: EncodingKey::from_ed_der(pkcs8.as_ref()),
+            public_x: URL_SAFE_NO_PAD.encode(pair.public_key().as_ref()),
+        }
+    })
+}
+
+/// Public JWKS containing the test signing key
+pub fn jwk_set() -> JwkSet {
+    serde_json::from_value(json!({
+        "keys": [{
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "alg": "EdDSA",
+            "kid": TEST_KID,
+            "x": signing_key().public_x,
+        }]
+    }))
+    .unwrap()
+}
+
+/// Validator for `RESOURCE_URL` trusting the test signing key
+pub fn token_validator() -> TokenValidator {
+    TokenValidator::new(RESOURCE_URL.to_string())
+        .with_jwks(JwksProvider::new(JwksSource::Static(jwk_set())))
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// Sign arbitrary claims with the test key
+pub fn sign(claims: &Value) -> String {
+    let mut header = Header::new(Algorithm::EdDSA);
+    header.kid = Some(TEST_KID.to_string());
+    encode(&header, claims, &signing_key().encoding_key).unwrap()
+}
+
+/// Signed token for `RESOURCE_URL` with the given scopes, valid for one hour
+pub fn token_with_scopes(sub: &str, scopes: &str) -> String {
+    sign(&json!({
+        "sub": sub,
+        "aud": RESOURCE_URL,
+        "exp": now() + 3600,
+        "scope": scopes,
+    }))
+}
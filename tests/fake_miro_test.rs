@@ -0,0 +1,1380 @@
+use miro_mcp_server::auth::UserInfo;
+use miro_mcp_server::mcp::notifier;
+use miro_mcp_server::mcp::protocol::error_codes;
+use miro_mcp_server::mcp::protocol::{Content, JsonRpcRequest};
+use miro_mcp_server::mcp::resources;
+use miro_mcp_server::mcp::session::{Session, SessionStore, DEFAULT_EVENT_BUFFER};
+use miro_mcp_server::mcp::tools::call_tool;
+use miro_mcp_server::mcp::McpHandler;
+use miro_mcp_server::miro::types::{BoardRole, BoardSort, Geometry, Position};
+use miro_mcp_server::miro::{
+    AppCardBuilder, CardBuilder, MiroError, StickyNoteBuilder, DEFAULT_MAX_ITEMS,
+};
+use miro_mcp_server::test_utils::FakeMiroServer;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+fn text_of(result: &miro_mcp_server::mcp::protocol::CallToolResult) -> Value {
+    match &result.content[0] {
+        Content::Text { text } => serde_json::from_str(text).unwrap_or(Value::String(text.clone())),
+    }
+}
+
+#[tokio::test]
+async fn test_boards_paginate_with_cursor() {
+    let server = FakeMiroServer::start().await;
+    let client = server.client("token");
+    for name in ["One", "Two", "Three"] {
+        client.create_board(name.to_string(), None).await.unwrap();
+    }
+
+    let first = client.list_boards_page(Some(2), None).await.unwrap();
+    assert_eq!(first.data.len(), 2);
+    let cursor = first.cursor.expect("more boards");
+
+    let second = client
+        .list_boards_page(Some(2), Some(&cursor))
+        .await
+        .unwrap();
+    assert_eq!(second.data.len(), 1);
+    assert!(second.cursor.is_none());
+    assert_eq!(second.data[0].name, "Three");
+
+    assert_eq!(client.list_boards().await.unwrap().items.len(), 3);
+}
+
+#[tokio::test]
+async fn test_boards_searched_past_the_first_page() {
+    let server = FakeMiroServer::start().await;
+    let client = server.client("token");
+    for sprint in 1..=24 {
+        client
+            .create_board(format!("Sprint {} retro", sprint), None)
+            .await
+            .unwrap();
+    }
+    client
+        .create_board("Roadmap".to_string(), Some("Retro actions".to_string()))
+        .await
+        .unwrap();
+    client
+        .create_board("Onboarding".to_string(), None)
+        .await
+        .unwrap();
+
+    // Beyond Miro's default page of 20, following offsets
+    let all = call_tool(&client, "list_boards", json!({ "query": "RETRO" }))
+        .await
+        .unwrap();
+    assert_eq!(text_of(&all)["boards"].as_array().unwrap().len(), 25);
+    assert!(text_of(&all).get("next_offset").is_none());
+
+    let first = call_tool(
+        &client,
+        "list_boards",
+        json!({ "query": "retro", "sort": "alphabetically", "limit": 2 }),
+    )
+    .await
+    .unwrap();
+    let first = text_of(&first);
+    let names: Vec<_> = first["boards"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|b| b["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, vec!["Roadmap", "Sprint 1 retro"]);
+    assert_eq!(first["next_offset"], 2);
+
+    let next = client
+        .boards()
+        .query("retro")
+        .sort(BoardSort::Alphabetically)
+        .limit(2)
+        .offset(2)
+        .build(&client)
+        .await
+        .unwrap();
+    let names: Vec<_> = next.data.iter().map(|b| b.name.as_str()).collect();
+    assert_eq!(names, vec!["Sprint 10 retro", "Sprint 11 retro"]);
+
+    let owned = client
+        .boards()
+        .owner("someone-else")
+        .collect(&client, 100)
+        .await
+        .unwrap();
+    assert!(owned.items.is_empty());
+}
+
+#[tokio::test]
+async fn test_list_boards_continues_past_the_cap() {
+    let server = FakeMiroServer::start().await;
+    for i in 0..=DEFAULT_MAX_ITEMS {
+        server.seed_board(&format!("Team board {}", i));
+    }
+    let client = server.client("token");
+
+    let first = text_of(&call_tool(&client, "list_boards", json!({})).await.unwrap());
+    assert_eq!(first["boards"].as_array().unwrap().len(), DEFAULT_MAX_ITEMS);
+    assert_eq!(first["next_offset"], DEFAULT_MAX_ITEMS);
+
+    let rest = text_of(
+        &call_tool(
+            &client,
+            "list_boards",
+            json!({ "offset": first["next_offset"] }),
+        )
+        .await
+        .unwrap(),
+    );
+    assert_eq!(rest["boards"].as_array().unwrap().len(), 1);
+    assert_eq!(rest["next_offset"], Value::Null);
+}
+
+#[tokio::test]
+async fn test_board_copied_from_template_then_renamed_and_deleted() {
+    let server = FakeMiroServer::start().await;
+    let template_id = server.seed_board("Sprint template");
+    let client = server.client("token");
+    let frame = client
+        .create_frame(
+            &template_id,
+            "Goals".to_string(),
+            0.0,
+            0.0,
+            800.0,
+            600.0,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    client
+        .create_sticky_note(
+            &template_id,
+            "Goal".to_string(),
+            10.0,
+            10.0,
+            "yellow".to_string(),
+            Some(frame.id.clone()),
+        )
+        .await
+        .unwrap();
+
+    let template = client.get_board(&template_id).await.unwrap();
+    assert_eq!(template.name, "Sprint template");
+    assert!(template.owner.is_some() && template.view_link.is_some());
+
+    let result = call_tool(
+        &client,
+        "copy_board",
+        json!({ "board_id": template_id, "name": "Sprint 15", "team_id": "team-2" }),
+    )
+    .await
+    .unwrap();
+    assert!(!result.is_error);
+    let sprint = text_of(&result);
+    let sprint_id = sprint["id"].as_str().unwrap().to_string();
+    assert_ne!(sprint_id, template_id);
+    assert_eq!(sprint["name"], "Sprint 15");
+    assert_eq!(sprint["team"]["id"], "team-2");
+
+    // Content is copied with fresh IDs, and children stay in their frame
+    let items = server.items(&sprint_id);
+    assert_eq!(items.len(), 2);
+    let copied_frame = items.iter().find(|i| i["type"] == "frame").unwrap();
+    let copied_note = items.iter().find(|i| i["type"] == "sticky_note").unwrap();
+    assert_ne!(copied_frame["id"], frame.id.as_str());
+    assert_eq!(copied_note["parent"]["id"], copied_frame["id"]);
+    assert_eq!(server.items(&template_id).len(), 2);
+
+    let renamed = client
+        .update_board(&sprint_id, None, Some("Goals for sprint 15".to_string()))
+        .await
+        .unwrap();
+    assert_eq!(renamed.name, "Sprint 15");
+    assert_eq!(renamed.description.as_deref(), Some("Goals for sprint 15"));
+
+    let result = call_tool(&client, "delete_board", json!({ "board_id": sprint_id }))
+        .await
+        .unwrap();
+    assert_eq!(text_of(&result)["deleted"], sprint_id.as_str());
+    assert!(matches!(
+        client.get_board(&sprint_id).await,
+        Err(MiroError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_share_board_reaches_members_named_unlike_their_email() {
+    let server = FakeMiroServer::start().await;
+    let board_id = server.seed_board("Squad board");
+    let client = server.client("token");
+    let share = |role: &str, dry_run: bool| {
+        json!({
+            "board_id": board_id,
+            "emails": ["ada@example.com"],
+            "role": role,
+            "dry_run": dry_run
+        })
+    };
+    call_tool(&client, "share_board", share("editor", false))
+        .await
+        .unwrap();
+    let members = client.list_board_members(&board_id).await.unwrap().items;
+    let ada = members
+        .iter()
+        .find(|m| m.role == BoardRole::Editor)
+        .unwrap();
+    assert_eq!(ada.name, "Ada");
+
+    let preview = text_of(
+        &call_tool(&client, "share_board", share("viewer", true))
+            .await
+            .unwrap(),
+    );
+    assert_eq!(
+        preview["changes"],
+        json!([{ "who": "ada@example.com", "from": "unknown", "to": "viewer" }])
+    );
+
+    // The same role again still goes to Miro; nothing is filtered out
+    for role in ["editor", "viewer"] {
+        let shared = text_of(
+            &call_tool(&client, "share_board", share(role, false))
+                .await
+                .unwrap(),
+        );
+        assert_eq!(shared["changes"][0]["to"], role);
+    }
+    let member = client.get_board_member(&board_id, &ada.id).await.unwrap();
+    assert_eq!(member.role, BoardRole::Viewer);
+    assert_eq!(
+        client
+            .list_board_members(&board_id)
+            .await
+            .unwrap()
+            .items
+            .len(),
+        members.len()
+    );
+}
+
+#[tokio::test]
+async fn test_squad_onboarded_with_dry_run_previews() {
+    let server = FakeMiroServer::start().await;
+    let board_id = server.seed_board("Squad board");
+    let client = server.client("token");
+    let share = |dry_run: bool| {
+        json!({
+            "board_id": board_id,
+            "emails": ["ada@example.com", "grace@example.com", "not-an-email"],
+            "role": "editor",
+            "dry_run": dry_run
+        })
+    };
+
+    let preview = text_of(
+        &call_tool(&client, "share_board", share(true))
+            .await
+            .unwrap(),
+    );
+    assert_eq!(preview["dry_run"], true);
+    assert_eq!(preview["changes"].as_array().unwrap().len(), 3);
+    assert_eq!(
+        client
+            .list_board_members(&board_id)
+            .await
+            .unwrap()
+            .items
+            .len(),
+        1
+    );
+
+    let shared = text_of(
+        &call_tool(&client, "share_board", share(false))
+            .await
+            .unwrap(),
+    );
+    assert_eq!(
+        shared["changes"][0],
+        json!({ "who": "ada@example.com", "from": "unknown", "to": "editor" })
+    );
+    assert_eq!(shared["changes"].as_array().unwrap().len(), 2);
+    assert_eq!(shared["failed"][0]["email"], "not-an-email");
+
+    // Members page by offset
+    let first = client
+        .list_board_members_page(&board_id, Some(2), None)
+        .await
+        .unwrap();
+    assert_eq!(first.data.len(), 2);
+    assert_eq!(first.cursor.as_deref(), Some("2"));
+    let members = client.list_board_members(&board_id).await.unwrap().items;
+    assert_eq!(members.len(), 3);
+    let ada = members.iter().find(|m| m.name == "Ada").unwrap();
+
+    // Members carry no email: a preview cannot tell who is one already
+    let reshare = text_of(
+        &call_tool(
+            &client,
+            "share_board",
+            json!({
+                "board_id": board_id,
+                "emails": ["ada@example.com", "grace@example.com", "alan@example.com"],
+                "role": "viewer",
+                "dry_run": true
+            }),
+        )
+        .await
+        .unwrap(),
+    );
+    assert_eq!(
+        reshare["changes"],
+        json!([
+            { "who": "ada@example.com", "from": "unknown", "to": "viewer" },
+            { "who": "grace@example.com", "from": "unknown", "to": "viewer" },
+            { "who": "alan@example.com", "from": "unknown", "to": "viewer" }
+        ])
+    );
+    assert_eq!(
+        client
+            .get_board_member(&board_id, &ada.id)
+            .await
+            .unwrap()
+            .role,
+        BoardRole::Editor
+    );
+
+    let update = |dry_run: bool| json!({ "board_id": board_id, "member_id": ada.id, "role": "viewer", "dry_run": dry_run });
+    let preview = text_of(
+        &call_tool(&client, "update_board_member", update(true))
+            .await
+            .unwrap(),
+    );
+    assert_eq!(preview["changes"][0]["from"], "editor");
+    assert_eq!(preview["changes"][0]["to"], "viewer");
+    call_tool(&client, "update_board_member", update(false))
+        .await
+        .unwrap();
+    let member = client.get_board_member(&board_id, &ada.id).await.unwrap();
+    assert_eq!(member.role, BoardRole::Viewer);
+
+    let removed = text_of(
+        &call_tool(
+            &client,
+            "remove_board_member",
+            json!({ "board_id": board_id, "member_id": ada.id }),
+        )
+        .await
+        .unwrap(),
+    );
+    assert_eq!(removed["changes"][0]["to"], Value::Null);
+    assert!(matches!(
+        client.get_board_member(&board_id, &ada.id).await,
+        Err(MiroError::NotFound(_))
+    ));
+
+    // Opening the board to anyone with the link
+    let sharing = |dry_run: bool| {
+        json!({
+            "board_id": board_id,
+            "sharing_policy": { "access": "view", "teamAccess": "edit" },
+            "dry_run": dry_run
+        })
+    };
+    let preview = text_of(
+        &call_tool(&client, "update_board_sharing", sharing(true))
+            .await
+            .unwrap(),
+    );
+    assert_eq!(
+        preview["changes"],
+        json!([{ "who": "anyone_with_link", "from": null, "to": "view" }])
+    );
+    call_tool(&client, "update_board_sharing", sharing(false))
+        .await
+        .unwrap();
+    let board = client.get_board(&board_id).await.unwrap();
+    let sharing_policy = board.policy.unwrap().sharing_policy.unwrap();
+    assert_eq!(sharing_policy.access.as_deref(), Some("view"));
+
+    // Ownership cannot be granted
+    let result = call_tool(
+        &client,
+        "share_board",
+        json!({ "board_id": board_id, "emails": ["x@example.com"], "role": "owner" }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        result.structured_content.unwrap()["error"]["kind"],
+        "validation"
+    );
+}
+
+#[tokio::test]
+async fn test_retro_action_items_tracked_as_cards() {
+    let server = FakeMiroServer::start().await;
+    let board_id = server.seed_board("Sprint 12 retro");
+    let client = server.client("token");
+    let owner = client.list_board_members(&board_id).await.unwrap().items[0]
+        .id
+        .clone();
+    let tag = client
+        .create_tag(&board_id, "action".to_string(), None)
+        .await
+        .unwrap();
+
+    let created = text_of(
+        &call_tool(
+            &client,
+            "create_card",
+            json!({
+                "board_id": board_id,
+                "title": "Fix flaky CI job",
+                "x": 0.0,
+                "y": 0.0,
+                "assignee_id": owner,
+                "due_date": "2025-03-31T17:00:00Z",
+                "tag_ids": [tag.id]
+            }),
+        )
+        .await
+        .unwrap(),
+    );
+    assert_eq!(created["data"]["assigneeId"], owner.as_str());
+    assert_eq!(created["tagIds"], json!([tag.id]));
+    let card_id = created["id"].as_str().unwrap();
+
+    // The owner asks for another week
+    call_tool(
+        &client,
+        "update_card",
+        json!({ "board_id": board_id, "item_id": card_id, "due_date": "2025-04-07T17:00:00Z" }),
+    )
+    .await
+    .unwrap();
+    let card = client.get_card(&board_id, card_id).await.unwrap();
+    let data = card.data.unwrap();
+    assert_eq!(data.due_date.as_deref(), Some("2025-04-07T17:00:00Z"));
+    assert_eq!(data.title.as_deref(), Some("Fix flaky CI job"));
+    assert_eq!(data.assignee_id, Some(owner.clone()));
+
+    let result = call_tool(
+        &client,
+        "update_card",
+        json!({ "board_id": board_id, "item_id": card_id, "due_date": "next week" }),
+    )
+    .await
+    .unwrap();
+    assert!(result.is_error);
+
+    // The rest of the action items in one request
+    let items = vec![
+        CardBuilder::new(&board_id, "Write ADR for caching", 400.0, 0.0)
+            .assignee(&owner)
+            .into_bulk_item(),
+        AppCardBuilder::new(&board_id, "JIRA-42", 800.0, 0.0)
+            .field("Owner: Ada")
+            .field("Due: 2025-03-31")
+            .into_bulk_item(),
+    ];
+    let bulk = client.bulk_create_items(&board_id, items).await.unwrap();
+    let types: Vec<_> = bulk.iter().map(|item| item.item_type.as_str()).collect();
+    assert_eq!(types, vec!["card", "app_card"]);
+
+    let app_card = text_of(
+        &call_tool(
+            &client,
+            "update_app_card",
+            json!({ "board_id": board_id, "item_id": bulk[1].id, "status": "connected" }),
+        )
+        .await
+        .unwrap(),
+    );
+    assert_eq!(app_card["data"]["status"], "connected");
+    assert_eq!(app_card["data"]["fields"][0]["value"], "Owner: Ada");
+
+    // Cards and app cards are only reachable through their own endpoints
+    assert!(matches!(
+        client.get_app_card(&board_id, card_id).await,
+        Err(MiroError::NotFound(_))
+    ));
+    let cards = client
+        .list_items(&board_id, Some(vec!["card"]), None)
+        .await
+        .unwrap()
+        .items;
+    assert_eq!(cards.len(), 2);
+}
+
+#[tokio::test]
+async fn test_stickies_triaged_with_tags() {
+    let server = FakeMiroServer::start().await;
+    let board_id = server.seed_board("Feedback");
+    let client = server.client("token");
+
+    let mut tag_ids = Vec::new();
+    for (title, color) in [("bug", "red"), ("feature", "green"), ("question", "yellow")] {
+        let tag = text_of(
+            &call_tool(
+                &client,
+                "create_tag",
+                json!({ "board_id": board_id, "title": title, "fill_color": color }),
+            )
+            .await
+            .unwrap(),
+        );
+        assert_eq!(tag["fillColor"], color);
+        tag_ids.push(tag["id"].as_str().unwrap().to_string());
+    }
+    let (bug, feature, question) = (&tag_ids[0], &tag_ids[1], &tag_ids[2]);
+    assert!(client
+        .create_tag(&board_id, "bug".to_string(), None)
+        .await
+        .is_err());
+
+    let crash = text_of(
+        &call_tool(
+            &client,
+            "create_sticky_note",
+            json!({ "board_id": board_id, "content": "Crash on login", "x": 0.0, "y": 0.0, "tag_ids": [bug] }),
+        )
+        .await
+        .unwrap(),
+    );
+    assert_eq!(crash["tagIds"], json!([bug]));
+    let crash_id = crash["id"].as_str().unwrap();
+    let dark_mode = StickyNoteBuilder::new(&board_id, "Dark mode?", 250.0, 0.0)
+        .tag(feature)
+        .tag(question)
+        .build(&client)
+        .await
+        .unwrap();
+    assert_eq!(dark_mode.tag_ids, vec![feature.clone(), question.clone()]);
+
+    // Triage: the question turns out to be a feature request only
+    let detached = text_of(
+        &call_tool(
+            &client,
+            "detach_tag",
+            json!({ "board_id": board_id, "item_id": dark_mode.id, "tag_id": question }),
+        )
+        .await
+        .unwrap(),
+    );
+    assert_eq!(detached["tags"].as_array().unwrap().len(), 1);
+    assert_eq!(detached["tags"][0]["title"], "feature");
+
+    let bugs = text_of(
+        &call_tool(
+            &client,
+            "list_items_by_tag",
+            json!({ "board_id": board_id, "tag_id": bug }),
+        )
+        .await
+        .unwrap(),
+    );
+    assert_eq!(bugs["items"].as_array().unwrap().len(), 1);
+    assert_eq!(bugs["items"][0]["id"], crash_id);
+    assert!(client
+        .list_items_by_tag(&board_id, question)
+        .await
+        .unwrap()
+        .items
+        .is_empty());
+
+    // Only sticky notes and cards carry tags
+    let shape = client
+        .create_shape(
+            &board_id,
+            "rectangle".to_string(),
+            "light_blue".to_string(),
+            0.0,
+            300.0,
+            100.0,
+            100.0,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    let result = call_tool(
+        &client,
+        "attach_tag",
+        json!({ "board_id": board_id, "item_id": shape.id, "tag_id": bug }),
+    )
+    .await
+    .unwrap();
+    assert!(result.is_error);
+
+    call_tool(
+        &client,
+        "update_tag",
+        json!({ "board_id": board_id, "tag_id": bug, "title": "defect" }),
+    )
+    .await
+    .unwrap();
+    call_tool(
+        &client,
+        "delete_tag",
+        json!({ "board_id": board_id, "tag_id": question }),
+    )
+    .await
+    .unwrap();
+    let titles: Vec<_> = client
+        .list_tags(&board_id)
+        .await
+        .unwrap()
+        .items
+        .into_iter()
+        .map(|tag| tag.title)
+        .collect();
+    assert_eq!(titles, vec!["defect", "feature"]);
+    assert_eq!(
+        client.get_item_tags(&board_id, crash_id).await.unwrap()[0].title,
+        "defect"
+    );
+}
+
+#[tokio::test]
+async fn test_items_filtered_by_type_and_parent() {
+    let server = FakeMiroServer::start().await;
+    let board_id = server.seed_board("Retro");
+    let client = server.client("token");
+
+    let frame = client
+        .create_frame(
+            &board_id,
+            "Ideas".to_string(),
+            0.0,
+            0.0,
+            800.0,
+            600.0,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    client
+        .create_sticky_note(
+            &board_id,
+            "In frame".to_string(),
+            10.0,
+            10.0,
+            "yellow".to_string(),
+            Some(frame.id.clone()),
+        )
+        .await
+        .unwrap();
+    client
+        .create_sticky_note(
+            &board_id,
+            "Loose".to_string(),
+            900.0,
+            0.0,
+            "yellow".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    client
+        .create_text(&board_id, "Title".to_string(), 0.0, -100.0, 200.0, None)
+        .await
+        .unwrap();
+
+    let notes = client
+        .list_items(&board_id, Some(vec!["sticky_note"]), None)
+        .await
+        .unwrap()
+        .items;
+    assert_eq!(notes.len(), 2);
+
+    let in_frame = client
+        .list_items(&board_id, None, Some(&frame.id))
+        .await
+        .unwrap()
+        .items;
+    assert_eq!(in_frame.len(), 1);
+    assert_eq!(in_frame[0].parent.as_ref().unwrap().id, frame.id);
+
+    let all = client
+        .list_items(&board_id, None, None)
+        .await
+        .unwrap()
+        .items;
+    assert_eq!(all.len(), 4);
+}
+
+#[tokio::test]
+async fn test_items_follow_cursor_across_pages() {
+    let server = FakeMiroServer::start().await;
+    let board_id = server.seed_board("Big");
+    let client = server.client("token");
+    for i in 0..25 {
+        client
+            .create_sticky_note(
+                &board_id,
+                format!("Note {}", i),
+                i as f64,
+                0.0,
+                "yellow".to_string(),
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    let page = client
+        .list_items_page(&board_id, None, None, Some(10), None)
+        .await
+        .unwrap();
+    assert_eq!(page.data.len(), 10);
+    assert!(page.cursor.is_some());
+
+    let items = client
+        .list_items(&board_id, None, None)
+        .await
+        .unwrap()
+        .items;
+    assert_eq!(items.len(), 25);
+    assert_eq!(
+        server.request_count(&format!("GET /boards/{}/items", board_id)),
+        4
+    );
+}
+
+#[tokio::test]
+async fn test_update_merges_and_parent_must_be_frame() {
+    let server = FakeMiroServer::start().await;
+    let board_id = server.seed_board("Edit");
+    let client = server.client("token");
+    let note = client
+        .create_sticky_note(
+            &board_id,
+            "Before".to_string(),
+            0.0,
+            0.0,
+            "yellow".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    client
+        .update_item(
+            &board_id,
+            &note.id,
+            Some(Position {
+                x: 50.0,
+                y: 60.0,
+                origin: None,
+            }),
+            Some(json!({ "content": "After" })),
+            None,
+            Some(Geometry {
+                width: 300.0,
+                height: None,
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let stored = &server.items(&board_id)[0];
+    assert_eq!(stored["data"]["content"], "After");
+    assert_eq!(stored["data"]["shape"], "square");
+    assert_eq!(stored["position"]["x"], 50.0);
+    assert_eq!(stored["geometry"]["width"], 300.0);
+
+    let err = client
+        .update_item(
+            &board_id,
+            &note.id,
+            None,
+            None,
+            None,
+            None,
+            Some(note.id.clone()),
+        )
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("invalidParameters"));
+}
+
+#[tokio::test]
+async fn test_delete_removes_item_and_attached_connectors() {
+    let server = FakeMiroServer::start().await;
+    let board_id = server.seed_board("Flow");
+    let client = server.client("token");
+    let a = client
+        .create_text(&board_id, "A".to_string(), 0.0, 0.0, 100.0, None)
+        .await
+        .unwrap();
+    let b = client
+        .create_text(&board_id, "B".to_string(), 200.0, 0.0, 100.0, None)
+        .await
+        .unwrap();
+    client
+        .create_connector(
+            &board_id,
+            a.id.clone(),
+            b.id.clone(),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(server.connectors(&board_id).len(), 1);
+
+    client.delete_item(&board_id, &a.id).await.unwrap();
+    assert_eq!(server.items(&board_id).len(), 1);
+    assert!(server.connectors(&board_id).is_empty());
+
+    let err = client.delete_item(&board_id, &a.id).await.unwrap_err();
+    let MiroError::NotFound(error) = err else {
+        panic!("expected NotFound, got {:?}", err);
+    };
+    assert_eq!(error.code.as_deref(), Some("notFound"));
+}
+
+#[tokio::test]
+async fn test_connector_requires_existing_endpoints() {
+    let server = FakeMiroServer::start().await;
+    let board_id = server.seed_board("Flow");
+    let client = server.client("token");
+
+    let err = client
+        .create_connector(
+            &board_id,
+            "missing-1".to_string(),
+            "missing-2".to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap_err();
+    let error = err.api_error().expect("Miro error body");
+    assert_eq!(error.status, 400);
+    assert_eq!(error.fields()[0].field, "startItem");
+}
+
+#[tokio::test]
+async fn test_bulk_create_is_validated_and_atomic() {
+    let server = FakeMiroServer::start().await;
+    let board_id = server.seed_board("Bulk");
+    let url = format!("{}/boards/{}/items", server.base_url(), board_id);
+    let http = reqwest::Client::new();
+    let item = |item_type: &str| {
+        json!({
+            "type": item_type,
+            "data": { "content": "x" },
+            "position": { "x": 0.0, "y": 0.0 },
+        })
+    };
+
+    let too_many: Vec<Value> = (0..21).map(|_| item("sticky_note")).collect();
+    let response = http
+        .post(&url)
+        .bearer_auth("token")
+        .json(&json!({ "items": too_many }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 400);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["status"], 400);
+    assert_eq!(body["code"], "invalidParameters");
+    assert_eq!(body["type"], "error");
+
+    let response = http
+        .post(&url)
+        .bearer_auth("token")
+        .json(&json!({ "items": [item("sticky_note"), item("hologram")] }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 400);
+    let body: Value = response.json().await.unwrap();
+    assert!(body["message"].as_str().unwrap().starts_with("items[1]"));
+    assert!(server.items(&board_id).is_empty());
+
+    let response = http
+        .post(&url)
+        .bearer_auth("token")
+        .json(&json!({ "items": [item("sticky_note"), item("text")] }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 201);
+    assert_eq!(server.items(&board_id).len(), 2);
+}
+
+#[tokio::test]
+async fn test_missing_token_and_unknown_board() {
+    let server = FakeMiroServer::start().await;
+    let http = reqwest::Client::new();
+
+    let response = http
+        .get(format!("{}/boards", server.base_url()))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 401);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["code"], "tokenNotProvided");
+
+    let err = server
+        .client("token")
+        .list_items("no-such-board", None, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, MiroError::NotFound(_)));
+    assert_eq!(err.status(), Some(404));
+}
+
+#[tokio::test]
+async fn test_tools_run_against_fake() {
+    let server = FakeMiroServer::start().await;
+    let board_id = server.seed_board("Tools");
+    let client = server.client("token");
+
+    let result = call_tool(
+        &client,
+        "create_sticky_note",
+        json!({ "board_id": board_id, "content": "Hello", "x": 1.0, "y": 2.0 }),
+    )
+    .await
+    .unwrap();
+    assert!(!result.is_error);
+    let note_id = text_of(&result)["id"].as_str().unwrap().to_string();
+
+    let result = call_tool(&client, "list_items", json!({ "board_id": board_id }))
+        .await
+        .unwrap();
+    assert_eq!(text_of(&result)["items"][0]["id"], note_id.as_str());
+    let structured = result.structured_content.as_ref().unwrap();
+    assert_eq!(structured["items"][0]["data"]["content"], "Hello");
+
+    let result = call_tool(
+        &client,
+        "delete_item",
+        json!({ "board_id": board_id, "item_id": "unknown" }),
+    )
+    .await
+    .unwrap();
+    assert!(result.is_error);
+    let error = &result.structured_content.as_ref().unwrap()["error"];
+    assert_eq!(error["kind"], "not_found");
+    assert_eq!(error["status"], 404);
+    assert_eq!(error["retryable"], false);
+
+    // A token Miro rejects is a protocol error, not a tool result
+    let err = call_tool(
+        &server.client(""),
+        "list_items",
+        json!({ "board_id": board_id }),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.code, error_codes::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_resources_read_against_fake() {
+    let server = FakeMiroServer::start().await;
+    let board_id = server.seed_board("Resources");
+    let client = server.client("token");
+    let frame = client
+        .create_frame(
+            &board_id,
+            "Ideas".to_string(),
+            0.0,
+            0.0,
+            800.0,
+            600.0,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    client
+        .create_sticky_note(
+            &board_id,
+            "<p>Ship it</p>".to_string(),
+            10.0,
+            10.0,
+            "yellow".to_string(),
+            Some(frame.id.clone()),
+        )
+        .await
+        .unwrap();
+    client
+        .create_text(&board_id, "Title".to_string(), 0.0, -100.0, 200.0, None)
+        .await
+        .unwrap();
+
+    let listed = resources::list_resources(&client).await.unwrap();
+    let uris: Vec<&str> = listed.iter().map(|r| r.uri.as_str()).collect();
+    assert_eq!(
+        uris,
+        [
+            "miro://boards".to_string(),
+            format!("miro://boards/{}", board_id)
+        ]
+    );
+
+    let notes_uri = format!("miro://boards/{}/items?type=sticky_note", board_id);
+    let read = resources::read_resource(&client, &notes_uri).await.unwrap();
+    assert_eq!(read.contents[0].mime_type, "application/json");
+    let notes: Value = serde_json::from_str(&read.contents[0].text).unwrap();
+    assert_eq!(notes.as_array().unwrap().len(), 1);
+    assert_eq!(notes[0]["text"], "Ship it");
+    assert_eq!(notes[0]["parent_id"], json!(frame.id));
+
+    let board = resources::read_resource(&client, &format!("miro://boards/{}", board_id))
+        .await
+        .unwrap();
+    let markdown = &board.contents[0].text;
+    assert_eq!(board.contents[0].mime_type, "text/markdown");
+    assert!(markdown.starts_with("# Resources\n"));
+    assert!(markdown.contains(&format!("## Ideas (`{}`)", frame.id)));
+    assert!(markdown.contains("## Outside frames"));
+
+    let missing = resources::read_resource(&client, "miro://boards/404/items")
+        .await
+        .unwrap_err();
+    assert_eq!(missing.code, error_codes::RESOURCE_NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_subscription_notifies_when_items_change() {
+    let server = FakeMiroServer::start().await;
+    let board_id = server.seed_board("Workshop");
+    let client = server.client("token");
+
+    let session = Session::new("facilitator", Duration::from_millis(50));
+    let mut notifications = session.take_notifications().unwrap();
+    let uri = format!("miro://boards/{}", board_id);
+    session
+        .subscriptions()
+        .subscribe(&uri, Arc::new(client.clone()))
+        .await
+        .unwrap();
+    assert_eq!(session.subscriptions().uris(), vec![uri.clone()]);
+
+    // Nothing changed: no notification
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert!(notifications.try_recv().is_none());
+
+    client
+        .create_sticky_note(
+            &board_id,
+            "Added by a human".to_string(),
+            0.0,
+            0.0,
+            "yellow".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let notification = tokio::time::timeout(Duration::from_secs(5), notifications.recv())
+        .await
+        .expect("change detected")
+        .unwrap();
+    assert_eq!(notification.method, "notifications/resources/updated");
+    assert_eq!(notification.params.unwrap()["uri"], json!(uri));
+
+    assert!(session.subscriptions().unsubscribe(&uri));
+    assert!(session.subscriptions().uris().is_empty());
+
+    let missing = session
+        .subscriptions()
+        .subscribe("miro://boards/404", Arc::new(client))
+        .await
+        .unwrap_err();
+    assert_eq!(missing.code, error_codes::RESOURCE_NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_watcher_waits_for_undelivered_update() {
+    let server = FakeMiroServer::start().await;
+    let board_id = server.seed_board("Unread");
+    let client = server.client("token");
+    let items_path = format!("GET /boards/{}/items", board_id);
+
+    // The client never opens its notification stream
+    let session = Session::new("facilitator", Duration::from_millis(20));
+    let uri = format!("miro://boards/{}", board_id);
+    session
+        .subscriptions()
+        .subscribe(&uri, Arc::new(client.clone()))
+        .await
+        .unwrap();
+    let update =
+        JsonRpcRequest::notification("notifications/resources/updated", json!({ "uri": uri }));
+
+    let add_sticky = |content: &'static str| {
+        let client = client.clone();
+        let board_id = board_id.clone();
+        async move {
+            client
+                .create_sticky_note(
+                    &board_id,
+                    content.to_string(),
+                    0.0,
+                    0.0,
+                    "yellow".to_string(),
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+    };
+    add_sticky("First").await;
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !session.notifier().is_queued(&update) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("change detected");
+
+    // Further changes neither poll Miro nor queue a second update
+    let polls = server.request_count(&items_path);
+    add_sticky("Second").await;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(server.request_count(&items_path), polls);
+
+    let mut notifications = session.take_notifications().unwrap();
+    assert_eq!(notifications.try_recv(), Some(update.clone()));
+    assert!(notifications.try_recv().is_none());
+
+    // Once delivered, polling resumes and reports the second change
+    let notification = tokio::time::timeout(Duration::from_secs(5), notifications.recv())
+        .await
+        .expect("change detected after delivery")
+        .unwrap();
+    assert_eq!(notification, update);
+}
+
+#[tokio::test]
+async fn test_subscription_sees_changes_past_the_item_cap() {
+    let server = FakeMiroServer::start().await;
+    let board_id = server.seed_board("Big room planning");
+    let client = server.client("token");
+    for batch in 0..51 {
+        let cards = (0..20)
+            .map(|i| {
+                CardBuilder::new(&board_id, format!("Story {}", batch * 20 + i), 0.0, 0.0)
+                    .into_bulk_item()
+            })
+            .collect();
+        client.bulk_create_items(&board_id, cards).await.unwrap();
+    }
+    assert!(server.items(&board_id).len() > DEFAULT_MAX_ITEMS);
+
+    // Listing stops at the cap and says so
+    let listed = client.list_items(&board_id, None, None).await.unwrap();
+    assert_eq!(listed.items.len(), DEFAULT_MAX_ITEMS);
+    assert!(listed.truncated);
+    let result = call_tool(&client, "list_items", json!({ "board_id": board_id }))
+        .await
+        .unwrap();
+    assert_eq!(text_of(&result)["truncated"], true);
+
+    let session = Session::new("facilitator", Duration::from_millis(20));
+    let mut notifications = session.take_notifications().unwrap();
+    let uri = format!("miro://boards/{}", board_id);
+    session
+        .subscriptions()
+        .subscribe(&uri, Arc::new(client.clone()))
+        .await
+        .unwrap();
+
+    // Listed after the first thousand items
+    CardBuilder::new(&board_id, "Late story", 0.0, 0.0)
+        .build(&client)
+        .await
+        .unwrap();
+
+    let notification = tokio::time::timeout(Duration::from_secs(5), notifications.recv())
+        .await
+        .expect("change past the cap detected")
+        .unwrap();
+    assert_eq!(notification.params.unwrap()["uri"], json!(uri));
+}
+
+#[tokio::test]
+async fn test_reaper_stops_watchers_of_abandoned_sessions() {
+    let server = FakeMiroServer::start().await;
+    let board_id = server.seed_board("Abandoned");
+    let sessions = Arc::new(
+        SessionStore::new(Duration::from_secs(3600)).with_idle_timeout(Duration::from_secs(60)),
+    );
+    let session = sessions.create("facilitator");
+    let uri = format!("miro://boards/{}", board_id);
+    session
+        .subscriptions()
+        .subscribe(&uri, Arc::new(server.client("token")))
+        .await
+        .unwrap();
+    let items_path = format!("GET /boards/{}/items", board_id);
+    let polls = server.request_count(&items_path);
+
+    // The client goes away: no more requests, and the store is never used again
+    tokio::time::pause();
+    SessionStore::spawn_reaper(&sessions);
+    tokio::time::sleep(Duration::from_secs(90)).await;
+
+    assert!(session.is_closed());
+    assert!(session.subscriptions().uris().is_empty());
+
+    // Past the poll interval, the board is no longer polled
+    tokio::time::sleep(Duration::from_secs(2 * 3600)).await;
+    assert_eq!(server.request_count(&items_path), polls);
+}
+
+#[tokio::test]
+async fn test_cancelled_bulk_create_reports_created_items() {
+    let server = FakeMiroServer::start().await;
+    let board_id = server.seed_board("Bulk");
+    let handler = McpHandler::with_miro_http(server.http_client());
+    let user = UserInfo::new("alice".to_string(), None, vec!["boards:write".to_string()]);
+    let items: Vec<Value> = (0..100)
+        .map(|i| {
+            json!({
+                "type": "sticky_note",
+                "data": { "content": format!("Note {}", i) },
+                "style": { "fillColor": "light_yellow" },
+                "position": { "x": 0.0, "y": 0.0 },
+                "geometry": { "width": 200.0 },
+            })
+        })
+        .collect();
+    let request: JsonRpcRequest = serde_json::from_value(json!({
+        "jsonrpc": "2.0",
+        "id": 7,
+        "method": "tools/call",
+        "params": {
+            "name": "bulk_create_items",
+            "arguments": { "board_id": board_id, "items": items },
+            "_meta": { "progressToken": "bulk-1" },
+        },
+    }))
+    .unwrap();
+
+    let (notifier, mut progress) = notifier::channel(DEFAULT_EVENT_BUFFER);
+    let call = async {
+        // Owning the sender closes the channel once the call finishes
+        let notifier = notifier;
+        handler
+            .handle_with_progress(request, "token", &user, None, &notifier)
+            .await
+    };
+    let cancel = async {
+        // Cancel as soon as the first chunk is reported
+        let first = progress
+            .recv()
+            .await
+            .expect("progress before the call finished");
+        assert_eq!(first.method, "notifications/progress");
+        let params = first.params.unwrap();
+        assert_eq!(params["progressToken"], "bulk-1");
+        assert_eq!(params["progress"], 20.0);
+        assert_eq!(params["total"], 100.0);
+
+        let cancelled: JsonRpcRequest = serde_json::from_value(json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/cancelled",
+            "params": { "requestId": 7, "reason": "user aborted" },
+        }))
+        .unwrap();
+        assert!(handler.handle(cancelled, "token", &user).await.is_none());
+    };
+    let (response, ()) = tokio::join!(call, cancel);
+
+    let result = response.unwrap().result.unwrap();
+    let output = &result["structuredContent"];
+    assert_eq!(output["cancelled"], true);
+    let created = output["items"].as_array().unwrap().len();
+    assert!((20..100).contains(&created), "created {}", created);
+    assert_eq!(server.items(&board_id).len(), created);
+}
+
+#[tokio::test]
+async fn test_failed_bulk_chunk_reports_created_items() {
+    let server = FakeMiroServer::start().await;
+    let board_id = server.seed_board("Bulk");
+    let client = server.client("token");
+    let items: Vec<Value> = (0..25)
+        .map(|i| {
+            let mut item = json!({
+                "type": "sticky_note",
+                "data": { "content": format!("Note {}", i) },
+                "style": { "fillColor": "light_yellow" },
+                "position": { "x": 0.0, "y": 0.0 },
+                "geometry": { "width": 200.0 },
+            });
+            // Miro rejects the second chunk: its parent is not on the board
+            if i == 22 {
+                item["parent"] = json!({ "id": "missing-frame" });
+            }
+            item
+        })
+        .collect();
+
+    let result = call_tool(
+        &client,
+        "bulk_create_items",
+        json!({ "board_id": board_id, "items": items }),
+    )
+    .await
+    .unwrap();
+
+    assert!(result.is_error);
+    let structured = result.structured_content.unwrap();
+    assert_eq!(structured["error"]["kind"], "validation");
+    assert_eq!(structured["error"]["status"], 400);
+    let created: Vec<&str> = structured["created"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["id"].as_str().unwrap())
+        .collect();
+    let on_board = server.items(&board_id);
+    assert_eq!(created.len(), 20);
+    assert_eq!(
+        created,
+        on_board
+            .iter()
+            .map(|item| item["id"].as_str().unwrap())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn test_cancelled_client_does_not_reach_miro() {
+    let server = FakeMiroServer::start().await;
+    let token = CancellationToken::new();
+    let client = server.client("token").with_cancellation(token.clone());
+    client.list_boards().await.unwrap();
+
+    token.cancel();
+    assert!(matches!(
+        client.list_boards().await,
+        Err(MiroError::Cancelled)
+    ));
+    assert_eq!(server.request_count("GET /boards"), 1);
+}
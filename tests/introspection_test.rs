@@ -0,0 +1,116 @@
+use miro_mcp_server::auth::{AuthError, IntrospectionClient};
+use miro_mcp_server::config::{TokenValidationConfig, TokenValidationMode};
+use miro_mcp_server::TokenValidator;
+use serde_json::json;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+mod common;
+
+async fn introspection_server(token: &str, response: serde_json::Value, calls: u64) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/introspect"))
+        .and(body_string_contains(format!("token={}", token)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response))
+        .expect(calls)
+        .mount(&server)
+        .await;
+    server
+}
+
+fn active_response() -> serde_json::Value {
+    json!({
+        "active": true,
+        "sub": "user123",
+        "team_id": "team456",
+        "scope": "boards:read",
+        "exp": common::now() + 3600,
+    })
+}
+
+#[tokio::test]
+async fn test_introspection_mode_validates_opaque_token() {
+    let server = introspection_server("opaque-abc", active_response(), 1).await;
+    let config = TokenValidationConfig {
+        mode: TokenValidationMode::Introspection,
+        introspection_url: Some(format!("{}/introspect", server.uri())),
+        ..Default::default()
+    };
+    let validator = TokenValidator::from_config(common::RESOURCE_URL.to_string(), &config);
+
+    let user_info = validator.validate("opaque-abc").await.unwrap();
+    assert_eq!(user_info.user_id, "user123");
+    assert_eq!(user_info.team_id.as_deref(), Some("team456"));
+    assert_eq!(user_info.scopes, vec!["boards:read"]);
+
+    // Served from the LRU cache: the endpoint is hit only once
+    let cached = validator.validate("opaque-abc").await.unwrap();
+    assert_eq!(cached.user_id, "user123");
+}
+
+#[tokio::test]
+async fn test_introspection_mode_rejects_inactive_token() {
+    let server = introspection_server("revoked", json!({ "active": false }), 1).await;
+    let validator = TokenValidator::new(common::RESOURCE_URL.to_string())
+        .with_mode(TokenValidationMode::Introspection)
+        .with_introspection(IntrospectionClient::new(format!(
+            "{}/introspect",
+            server.uri()
+        )));
+
+    let result = validator.validate("revoked").await;
+    assert!(matches!(result, Err(AuthError::TokenInvalid)));
+}
+
+#[tokio::test]
+async fn test_fallback_mode_uses_jwt_when_valid() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(active_response()))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let validator = common::token_validator()
+        .with_mode(TokenValidationMode::JwtThenIntrospection)
+        .with_introspection(IntrospectionClient::new(format!(
+            "{}/introspect",
+            server.uri()
+        )));
+
+    let token = common::token_with_scopes("jwt-user", "boards:write");
+    let user_info = validator.validate(&token).await.unwrap();
+    assert_eq!(user_info.user_id, "jwt-user");
+}
+
+#[tokio::test]
+async fn test_fallback_mode_introspects_opaque_token() {
+    let server = introspection_server("opaque-xyz", active_response(), 1).await;
+    let validator = common::token_validator()
+        .with_mode(TokenValidationMode::JwtThenIntrospection)
+        .with_introspection(IntrospectionClient::new(format!(
+            "{}/introspect",
+            server.uri()
+        )));
+
+    let user_info = validator.validate("opaque-xyz").await.unwrap();
+    assert_eq!(user_info.user_id, "user123");
+}
+
+#[tokio::test]
+async fn test_jwt_mode_never_introspects() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(active_response()))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let validator = common::token_validator().with_introspection(IntrospectionClient::new(
+        format!("{}/introspect", server.uri()),
+    ));
+
+    let result = validator.validate("opaque-token").await;
+    assert!(matches!(result, Err(AuthError::InvalidTokenFormat)));
+}
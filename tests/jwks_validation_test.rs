@@ -0,0 +1,98 @@
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use miro_mcp_server::auth::{AuthError, JwksProvider, JwksSource};
+use miro_mcp_server::config::TokenValidationConfig;
+use miro_mcp_server::TokenValidator;
+use serde_json::json;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const RESOURCE_URL: &str = "https://test.example.com";
+const RSA_PRIVATE_KEY: &[u8] = include_bytes!("fixtures/rsa_test_key.pem");
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+fn rs256_token(kid: &str, sub: &str) -> String {
+    let exp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 3600;
+    let mut header = Header::new(Algorithm::RS256);
+    header.kid = Some(kid.to_string());
+    encode(
+        &header,
+        &json!({ "sub": sub, "aud": RESOURCE_URL, "exp": exp, "scope": "boards:read" }),
+        &EncodingKey::from_rsa_pem(RSA_PRIVATE_KEY).unwrap(),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_rs256_token_verified_with_jwks_file() {
+    let config = TokenValidationConfig {
+        jwks_file: Some(fixture("rsa_test_jwks.json")),
+        ..Default::default()
+    };
+    let validator = TokenValidator::from_config(RESOURCE_URL.to_string(), &config);
+
+    let user_info = validator
+        .validate(&rs256_token("rsa-test-1", "user-rsa"))
+        .await
+        .unwrap();
+    assert_eq!(user_info.user_id, "user-rsa");
+    assert_eq!(user_info.scopes, vec!["boards:read"]);
+}
+
+#[tokio::test]
+async fn test_rs256_token_verified_with_jwks_url() {
+    let server = MockServer::start().await;
+    let jwks: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(fixture("rsa_test_jwks.json")).unwrap())
+            .unwrap();
+    Mock::given(method("GET"))
+        .and(path("/.well-known/jwks.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(jwks))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let validator = TokenValidator::new(RESOURCE_URL.to_string()).with_jwks(JwksProvider::new(
+        JwksSource::Url(format!("{}/.well-known/jwks.json", server.uri())),
+    ));
+
+    // Two distinct tokens, one JWKS fetch (keys are cached)
+    assert!(validator
+        .validate(&rs256_token("rsa-test-1", "a"))
+        .await
+        .is_ok());
+    assert!(validator
+        .validate(&rs256_token("rsa-test-1", "b"))
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+async fn test_unknown_kid_rejected() {
+    let validator = TokenValidator::new(RESOURCE_URL.to_string()).with_jwks(JwksProvider::new(
+        JwksSource::File(fixture("rsa_test_jwks.json")),
+    ));
+
+    let result = validator.validate(&rs256_token("rotated-away", "x")).await;
+    assert!(matches!(result, Err(AuthError::TokenValidationFailed(_))));
+}
+
+#[tokio::test]
+async fn test_missing_jwks_file_rejects_tokens() {
+    let validator = TokenValidator::new(RESOURCE_URL.to_string()).with_jwks(JwksProvider::new(
+        JwksSource::File(fixture("does_not_exist.json")),
+    ));
+
+    let result = validator.validate(&rs256_token("rsa-test-1", "x")).await;
+    assert!(matches!(result, Err(AuthError::TokenValidationFailed(msg)) if msg.contains("JWKS")));
+}
@@ -0,0 +1,349 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use common::RESOURCE_URL;
+use miro_mcp_server::http_server::create_app_adr002;
+use miro_mcp_server::Config;
+use serde_json::Value;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+mod common;
+
+fn get_test_config() -> Config {
+    Config {
+        client_id: "test_client_id".to_string(),
+        client_secret: "test_client_secret".to_string(),
+        redirect_uri: "https://claude.ai/api/mcp/auth_callback".to_string(),
+        encryption_key: [0u8; 32],
+        port: 3000,
+        base_url: Some(RESOURCE_URL.to_string()),
+        token_validation: Default::default(),
+        miro_http: Default::default(),
+        mcp: Default::default(),
+    }
+}
+
+fn create_test_app() -> Router {
+    let config = Arc::new(get_test_config());
+    let token_validator = Arc::new(common::token_validator());
+    create_app_adr002(token_validator, config)
+}
+
+fn test_token() -> String {
+    common::token_with_scopes("user123", "boards:read boards:write")
+}
+
+async fn post_mcp(app: Router, token: Option<&str>, body: &str) -> (StatusCode, Option<Value>) {
+    let (status, _, json) = post_mcp_full(app, token, body).await;
+    (status, json)
+}
+
+async fn post_mcp_full(
+    app: Router,
+    token: Option<&str>,
+    body: &str,
+) -> (StatusCode, Option<String>, Option<Value>) {
+    let mut builder = Request::builder()
+        .method("POST")
+        .uri("/mcp")
+        .header("content-type", "application/json");
+    if let Some(token) = token {
+        builder = builder.header("authorization", format!("Bearer {}", token));
+    }
+
+    let response = app
+        .oneshot(builder.body(Body::from(body.to_string())).unwrap())
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let www_authenticate = response
+        .headers()
+        .get("www-authenticate")
+        .map(|v| v.to_str().unwrap().to_string());
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json = serde_json::from_slice(&bytes).ok();
+    (status, www_authenticate, json)
+}
+
+#[tokio::test]
+async fn test_mcp_requires_bearer_token() {
+    let (status, _) = post_mcp(
+        create_test_app(),
+        None,
+        r#"{"jsonrpc":"2.0","id":1,"method":"initialize"}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_mcp_rejects_invalid_token() {
+    let (status, _) = post_mcp(
+        create_test_app(),
+        Some("not-a-jwt"),
+        r#"{"jsonrpc":"2.0","id":1,"method":"initialize"}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_mcp_full_flow_initialize_list_call() {
+    let token = test_token();
+
+    // initialize
+    let (status, body) = post_mcp(
+        create_test_app(),
+        Some(&token),
+        r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"protocolVersion":"2025-06-18","capabilities":{},"clientInfo":{"name":"test","version":"1.0"}}}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let body = body.unwrap();
+    assert_eq!(body["jsonrpc"], "2.0");
+    assert_eq!(body["id"], 1);
+    assert_eq!(body["result"]["protocolVersion"], "2025-06-18");
+    assert_eq!(body["result"]["serverInfo"]["name"], "miro-mcp-server");
+
+    // notifications/initialized
+    let (status, _) = post_mcp(
+        create_test_app(),
+        Some(&token),
+        r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+
+    // tools/list
+    let (status, body) = post_mcp(
+        create_test_app(),
+        Some(&token),
+        r#"{"jsonrpc":"2.0","id":2,"method":"tools/list"}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let tools = body.unwrap()["result"]["tools"].as_array().unwrap().clone();
+    let names: Vec<&str> = tools.iter().map(|t| t["name"].as_str().unwrap()).collect();
+    assert!(names.contains(&"list_boards"));
+    assert!(names.contains(&"create_sticky_note"));
+    assert!(tools.iter().all(|t| t["inputSchema"]["type"] == "object"));
+
+    // tools/call with invalid arguments never reaches Miro
+    let (status, body) = post_mcp(
+        create_test_app(),
+        Some(&token),
+        r#"{"jsonrpc":"2.0","id":3,"method":"tools/call","params":{"name":"create_board","arguments":{}}}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let body = body.unwrap();
+    assert_eq!(body["id"], 3);
+    assert_eq!(body["result"]["isError"], true);
+    assert_eq!(
+        body["result"]["structuredContent"]["error"]["kind"],
+        "invalid_arguments"
+    );
+}
+
+#[tokio::test]
+async fn test_mcp_parse_error() {
+    let (status, body) = post_mcp(create_test_app(), Some(&test_token()), "{not json").await;
+    assert_eq!(status, StatusCode::OK);
+    let body = body.unwrap();
+    assert_eq!(body["error"]["code"], -32700);
+    assert_eq!(body["id"], Value::Null);
+}
+
+#[tokio::test]
+async fn test_mcp_method_not_found() {
+    let (_, body) = post_mcp(
+        create_test_app(),
+        Some(&test_token()),
+        r#"{"jsonrpc":"2.0","id":"abc","method":"does/not/exist"}"#,
+    )
+    .await;
+    let body = body.unwrap();
+    assert_eq!(body["id"], "abc");
+    assert_eq!(body["error"]["code"], -32601);
+}
+
+#[tokio::test]
+async fn test_mcp_read_only_token_sees_read_tools_only() {
+    let token = common::token_with_scopes("reader", "boards:read");
+    let (status, body) = post_mcp(
+        create_test_app(),
+        Some(&token),
+        r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let tools = body.unwrap()["result"]["tools"].as_array().unwrap().clone();
+    let names: Vec<&str> = tools.iter().map(|t| t["name"].as_str().unwrap()).collect();
+    assert!(names.contains(&"list_boards"));
+    assert!(!names.contains(&"create_sticky_note"));
+}
+
+#[tokio::test]
+async fn test_mcp_tool_call_without_scope_is_forbidden() {
+    let token = common::token_with_scopes("reader", "boards:read");
+    let (status, www_authenticate, body) = post_mcp_full(
+        create_test_app(),
+        Some(&token),
+        r#"{"jsonrpc":"2.0","id":5,"method":"tools/call","params":{"name":"create_board","arguments":{"name":"x"}}}"#,
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let www_authenticate = www_authenticate.unwrap();
+    assert!(www_authenticate.contains("error=\"insufficient_scope\""));
+    assert!(www_authenticate.contains("scope=\"boards:write\""));
+    assert_eq!(body.unwrap()["error"], "insufficient_scope");
+}
+
+async fn send(
+    app: &Router,
+    method: &str,
+    token: &str,
+    session_id: Option<&str>,
+    body: &str,
+) -> axum::response::Response {
+    let mut builder = Request::builder()
+        .method(method)
+        .uri("/mcp")
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token));
+    if let Some(session_id) = session_id {
+        builder = builder.header("mcp-session-id", session_id);
+    }
+    app.clone()
+        .oneshot(builder.body(Body::from(body.to_string())).unwrap())
+        .await
+        .unwrap()
+}
+
+async fn json_body(response: axum::response::Response) -> Value {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+#[tokio::test]
+async fn test_mcp_initialize_starts_session_bound_to_user() {
+    let app = create_test_app();
+    let token = test_token();
+
+    let response = send(
+        &app,
+        "POST",
+        &token,
+        None,
+        r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"protocolVersion":"2025-06-18"}}"#,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let session_id = response
+        .headers()
+        .get("mcp-session-id")
+        .expect("session id issued")
+        .to_str()
+        .unwrap()
+        .to_string();
+    assert_eq!(
+        json_body(response).await["result"]["capabilities"]["resources"]["subscribe"],
+        true
+    );
+
+    // Session requests work for the owner
+    let response = send(
+        &app,
+        "POST",
+        &token,
+        Some(&session_id),
+        r#"{"jsonrpc":"2.0","id":2,"method":"ping"}"#,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // ...but the session id is useless with another user's token
+    let other = common::token_with_scopes("someone-else", "boards:read");
+    let response = send(
+        &app,
+        "POST",
+        &other,
+        Some(&session_id),
+        r#"{"jsonrpc":"2.0","id":3,"method":"ping"}"#,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let response = send(&app, "GET", &other, Some(&session_id), "").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_mcp_sse_stream_requires_session() {
+    let app = create_test_app();
+    let response = send(&app, "GET", &test_token(), None, "").await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = send(&app, "GET", &test_token(), Some("unknown"), "").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_mcp_subscribe_without_session_is_rejected() {
+    let response = send(
+        &create_test_app(),
+        "POST",
+        &test_token(),
+        None,
+        r#"{"jsonrpc":"2.0","id":4,"method":"resources/subscribe","params":{"uri":"miro://boards/b1"}}"#,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(json_body(response).await["error"]["code"], -32600);
+}
+
+#[tokio::test]
+async fn test_mcp_delete_terminates_session() {
+    let app = create_test_app();
+    let token = test_token();
+    let response = send(
+        &app,
+        "POST",
+        &token,
+        None,
+        r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"protocolVersion":"2025-06-18"}}"#,
+    )
+    .await;
+    let session_id = response.headers()["mcp-session-id"]
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    // Only the owner can end it
+    let other = common::token_with_scopes("someone-else", "boards:read");
+    let response = send(&app, "DELETE", &other, Some(&session_id), "").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = send(&app, "DELETE", &token, Some(&session_id), "").await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = send(
+        &app,
+        "POST",
+        &token,
+        Some(&session_id),
+        r#"{"jsonrpc":"2.0","id":2,"method":"ping"}"#,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let response = send(&app, "DELETE", &token, None, "").await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,221 @@
+use futures::TryStreamExt;
+use miro_mcp_server::mcp::tools::call_tool;
+use miro_mcp_server::miro::{MiroClient, MiroClientConfig, MiroError, MiroHttpClient, RetryPolicy};
+use serde_json::json;
+use std::time::Duration;
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn client_for(server: &MockServer) -> MiroClient {
+    let config = MiroClientConfig::default()
+        .with_base_url(server.uri())
+        .with_retry_policy(
+            RetryPolicy::default()
+                .with_backoff(Duration::from_millis(1), Duration::from_millis(50))
+                .with_jitter(false),
+        );
+    MiroClient::with_config("test-token".to_string(), config).unwrap()
+}
+
+fn board(id: &str) -> serde_json::Value {
+    json!({ "id": id, "name": format!("Board {}", id), "created_at": "2025-01-01T00:00:00Z" })
+}
+
+#[tokio::test]
+async fn test_requests_use_base_url_token_and_user_agent() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/boards"))
+        .and(header("authorization", "Bearer test-token"))
+        .and(header("user-agent", "miro-test/1.0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "data": [board("b1")] })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let config = MiroClientConfig::default()
+        .with_base_url(server.uri())
+        .with_user_agent("miro-test/1.0");
+    let client = MiroClient::with_config("test-token".to_string(), config).unwrap();
+
+    let boards = client.list_boards().await.unwrap().items;
+    assert_eq!(boards.len(), 1);
+    assert_eq!(boards[0].id, "b1");
+}
+
+#[tokio::test]
+async fn test_shared_pool_handles_carry_their_own_token() {
+    let server = MockServer::start().await;
+    for token in ["alice-token", "bob-token"] {
+        Mock::given(method("GET"))
+            .and(path("/boards"))
+            .and(header(
+                "authorization",
+                format!("Bearer {}", token).as_str(),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "data": [board(token)] })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let pool =
+        MiroHttpClient::new(MiroClientConfig::default().with_base_url(server.uri())).unwrap();
+    let alice = pool.for_token("alice-token");
+    let bob = pool.for_token("bob-token");
+
+    assert_eq!(
+        alice.list_boards().await.unwrap().items[0].id,
+        "alice-token"
+    );
+    assert_eq!(bob.list_boards().await.unwrap().items[0].id, "bob-token");
+}
+
+#[tokio::test]
+async fn test_list_items_stream_follows_cursor() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/boards/b1/items"))
+        .and(query_param("cursor", "page-2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [{ "id": "i3", "type": "text" }]
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/boards/b1/items"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [{ "id": "i1", "type": "sticky_note" }, { "id": "i2", "type": "shape" }],
+            "cursor": "page-2"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = client_for(&server);
+    let items: Vec<_> = client
+        .list_items_stream("b1", None, None, Some(2))
+        .try_collect()
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = items.iter().map(|i| i.id.as_str()).collect();
+    assert_eq!(ids, vec!["i1", "i2", "i3"]);
+}
+
+#[tokio::test]
+async fn test_rate_limited_request_is_retried() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/boards"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+        .up_to_n_times(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/boards"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "data": [board("b1")] })))
+        .mount(&server)
+        .await;
+
+    let boards = client_for(&server).list_boards().await.unwrap().items;
+    assert_eq!(boards.len(), 1);
+}
+
+#[tokio::test]
+async fn test_rate_limit_exhausted_reports_wait_and_retries() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/boards"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("X-RateLimit-Remaining", "0")
+                .insert_header("Retry-After", "0"),
+        )
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let client = client_for(&server).with_retry_policy(
+        RetryPolicy::default()
+            .with_max_retries(2)
+            .with_jitter(false),
+    );
+
+    match client.create_board("x".to_string(), None).await {
+        Err(MiroError::RateLimitExceeded(info)) => {
+            assert_eq!(info.retries, 2);
+            assert_eq!(info.remaining, Some(0));
+        }
+        other => panic!("expected rate limit error, got {:?}", other.map(|b| b.id)),
+    }
+}
+
+#[tokio::test]
+async fn test_server_error_retried_only_for_idempotent_requests() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/boards"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(4)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/boards"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client_for(&server);
+    assert!(matches!(
+        client.list_boards().await,
+        Err(MiroError::ApiError(e)) if e.status == 503
+    ));
+    assert!(matches!(
+        client.create_board("x".to_string(), None).await,
+        Err(MiroError::ApiError(e)) if e.status == 503
+    ));
+}
+
+#[tokio::test]
+async fn test_board_copy_is_not_retried() {
+    let server = MockServer::start().await;
+    // Copying is a PUT, but a repeated copy would create a second board
+    Mock::given(method("PUT"))
+        .and(path("/boards"))
+        .and(query_param("copy_from", "uXjVO="))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client_for(&server);
+    assert!(matches!(
+        client.copy_board("uXjVO=", None, None, None).await,
+        Err(MiroError::ApiError(e)) if e.status == 503
+    ));
+}
+
+#[tokio::test]
+async fn test_tool_call_against_mock_api() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/boards/b1/items/i1"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client_for(&server);
+    let result = call_tool(
+        &client,
+        "delete_item",
+        json!({ "board_id": "b1", "item_id": "i1" }),
+    )
+    .await
+    .unwrap();
+
+    assert!(!result.is_error);
+}
@@ -16,6 +16,9 @@ fn get_test_config() -> Config {
         encryption_key: [0u8; 32],
         port: 3000,
         base_url: Some("https://test.example.com".to_string()),
+        token_validation: Default::default(),
+        miro_http: Default::default(),
+        mcp: Default::default(),
     }
 }
 
@@ -148,5 +151,42 @@ async fn test_metadata_does_not_include_rfc8414_fields() {
     );
 }
 
-// NOTE: WWW-Authenticate header test removed because MCP endpoint was removed
-// in Resource Server implementation (no protected endpoints currently)
+/// Test that 401 challenges point clients at the metadata endpoint (RFC 9728 §5.1)
+#[tokio::test]
+async fn test_www_authenticate_references_metadata_endpoint() {
+    let config = Arc::new(get_test_config());
+    let token_validator = Arc::new(TokenValidator::new(
+        config
+            .base_url
+            .clone()
+            .unwrap_or_else(|| "https://test.example.com".to_string()),
+    ));
+    let app = create_app_adr002(token_validator, config);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/mcp")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let challenge = response
+        .headers()
+        .get("www-authenticate")
+        .expect("401 must carry WWW-Authenticate")
+        .to_str()
+        .unwrap();
+    assert!(challenge.starts_with("Bearer "));
+    assert!(
+        challenge.contains(
+            "resource_metadata=\"https://test.example.com/.well-known/oauth-protected-resource\""
+        ),
+        "Challenge should reference metadata URL, got: {}",
+        challenge
+    );
+}